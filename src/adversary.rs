@@ -0,0 +1,135 @@
+use std::{
+    cmp::Ordering,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    algorithms::{self, Algorithm},
+    array::Array,
+    bench::{self, Distribution},
+    state::{SharedState, State},
+};
+
+/// McIlroy's killer adversary. The array holds item ids whose values start
+/// out undecided ("gas") and are frozen one by one, always choosing the
+/// outcome that keeps the likely pivot undecided, so that it ends up being
+/// compared against everything else.
+pub struct Adversary(Mutex<Items>);
+
+struct Items {
+    values: Vec<Option<u32>>,
+    frozen: u32,
+    candidate: u32,
+}
+
+impl Items {
+    fn freeze(&mut self, item: u32) -> u32 {
+        self.frozen += 1;
+        *self.values[item as usize].insert(self.frozen)
+    }
+}
+
+impl Adversary {
+    pub fn new(len: usize) -> Self {
+        Self(Mutex::new(Items {
+            values: vec![None; len],
+            frozen: 0,
+            candidate: 0,
+        }))
+    }
+
+    /// Orders the items `x` and `y`, deciding their values if still needed.
+    pub fn compare(&self, x: u32, y: u32) -> Ordering {
+        let mut items = self.0.lock().unwrap();
+
+        if items.values[x as usize].is_none() && items.values[y as usize].is_none() {
+            let item = if x == items.candidate { x } else { y };
+            items.freeze(item);
+        }
+
+        match (items.values[x as usize], items.values[y as usize]) {
+            (Some(value_x), Some(value_y)) => value_x.cmp(&value_y),
+            // Undecided items are larger than every frozen one.
+            (None, _) => {
+                items.candidate = x;
+                Ordering::Greater
+            }
+            (_, None) => {
+                items.candidate = y;
+                Ordering::Less
+            }
+        }
+    }
+
+    /// The concrete input that replays every decision, with the items left
+    /// undecided frozen in index order.
+    pub fn input(&self) -> Vec<u32> {
+        let mut items = self.0.lock().unwrap();
+
+        (0..items.values.len())
+            .map(|item| match items.values[item] {
+                Some(value) => value,
+                None => items.freeze(item as u32),
+            })
+            .collect()
+    }
+}
+
+/// Runs `algorithm` headlessly against the adversary, returning the
+/// algorithm's name and the input that forces the comparisons it made.
+pub fn killer(algorithm: &dyn Algorithm, len: u32) -> (String, Vec<u32>) {
+    let adversary = Arc::new(Adversary::new(len as usize));
+
+    let mut state = State::new((0..len).collect());
+    state.headless = true;
+    state.adversary = Some(adversary.clone());
+
+    let state = SharedState::new(state);
+    algorithm.sort(state.clone(), Array::new(state.clone()));
+
+    (state.get_algorithm(), adversary.input())
+}
+
+/// Compares every algorithm on random input against its own killer input,
+/// printing the killer input instead for the algorithm named `name`.
+pub fn print(len: u32, name: Option<&str>) {
+    let random = Distribution::Random.generate(len, &mut rand::rng());
+
+    if name.is_none() {
+        println!(
+            "{:<30} {:>6} {:>12} {:>12} {:>9}",
+            "Algorithm", "Size", "Random", "Adversary", "Slowdown"
+        );
+    }
+
+    for algorithm in algorithms::all_variants() {
+        let (algorithm_name, input) = killer(algorithm.as_ref(), len);
+
+        match name {
+            Some(name) if name.eq_ignore_ascii_case(&algorithm_name) => {
+                let values: Vec<String> = input.iter().map(u32::to_string).collect();
+                println!("{}", values.join(" "));
+                return;
+            }
+            Some(_) => {}
+            None => {
+                let random = bench::run(algorithm.as_ref(), random.clone());
+                let killer = bench::run(algorithm.as_ref(), input);
+
+                println!(
+                    "{:<30} {:>6} {:>12} {:>12} {:>8.2}x{}",
+                    killer.algorithm,
+                    len,
+                    random.comparisons,
+                    killer.comparisons,
+                    f64::from(killer.comparisons) / f64::from(random.comparisons.max(1)),
+                    if killer.gave_up { "  (gave up)" } else { "" },
+                );
+            }
+        }
+    }
+
+    if let Some(name) = name {
+        eprintln!("No algorithm named {:?}", name);
+    }
+}
@@ -0,0 +1,63 @@
+use super::{
+    sorting_network::{self, Comparator, Network},
+    Algorithm,
+};
+use crate::{array::Array, state::SharedState};
+
+pub struct BitonicSort;
+
+/// Bitonic sorting network for any size: sort the halves in opposite
+/// directions, then merge by comparing across the largest power of two below
+/// the length.
+pub fn network(size: usize) -> Network {
+    fn comparator(i: usize, j: usize, ascending: bool) -> Comparator {
+        if ascending {
+            [i as u32, j as u32]
+        } else {
+            [j as u32, i as u32]
+        }
+    }
+
+    fn merge(comparators: &mut Vec<Comparator>, start: usize, len: usize, ascending: bool) {
+        if len < 2 {
+            return;
+        }
+
+        let half = 1 << (len - 1).ilog2();
+
+        for i in start..start + len - half {
+            comparators.push(comparator(i, i + half, ascending));
+        }
+
+        merge(comparators, start, half, ascending);
+        merge(comparators, start + half, len - half, ascending);
+    }
+
+    fn sort(comparators: &mut Vec<Comparator>, start: usize, len: usize, ascending: bool) {
+        if len < 2 {
+            return;
+        }
+
+        let half = len / 2;
+
+        sort(comparators, start, half, !ascending);
+        sort(comparators, start + half, len - half, ascending);
+        merge(comparators, start, len, ascending);
+    }
+
+    let mut comparators = vec![];
+    sort(&mut comparators, 0, size, true);
+
+    Network::new(size, comparators)
+}
+
+impl Algorithm for BitonicSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(50);
+        state.init_algorithm("Bitonic Sort".to_string());
+
+        sorting_network::sort(&state, &array, network(array.len()));
+
+        state.check();
+    }
+}
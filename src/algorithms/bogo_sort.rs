@@ -0,0 +1,52 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::Algorithm;
+use crate::{
+    array::Array,
+    state::{Budget, SharedState},
+};
+
+pub struct BogoSort {
+    pub seed: u64,
+}
+
+impl BogoSort {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+/// Compares neighbours until the first pair out of order.
+pub fn is_sorted(array: &Array) -> bool {
+    (1..array.len()).all(|i| !array.is_greater(i - 1, i))
+}
+
+impl Algorithm for BogoSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm(format!("Bogosort (seed {})", self.seed));
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut budget = Budget::default();
+        let mut shuffles = 0;
+
+        while !is_sorted(&array) {
+            shuffles += 1;
+            state.log(format!("Bogosort: not sorted yet, shuffle {}", shuffles));
+
+            for i in (1..array.len()).rev() {
+                if budget.interrupted(&state) {
+                    return;
+                }
+
+                let j = rng.random_range(0..=i);
+
+                state.set_last(u32::try_from(j).unwrap());
+                array.swap(i, j);
+            }
+
+            state.sleep(None);
+        }
+
+        state.check();
+    }
+}
@@ -0,0 +1,49 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{bogo_sort::is_sorted, Algorithm};
+use crate::{
+    array::Array,
+    state::{Budget, SharedState},
+};
+
+pub struct BozoSort {
+    pub seed: u64,
+}
+
+impl BozoSort {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl Algorithm for BozoSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm(format!("Bozosort (seed {})", self.seed));
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut budget = Budget::default();
+        let len = array.len();
+        let mut swaps = 0;
+
+        while !is_sorted(&array) {
+            if budget.interrupted(&state) {
+                return;
+            }
+
+            let (i, j) = (rng.random_range(0..len), rng.random_range(0..len));
+
+            swaps += 1;
+            state.log(format!(
+                "Bozosort: not sorted yet, swap {} exchanges {} and {}",
+                swaps, i, j
+            ));
+
+            state.set_last(u32::try_from(j).unwrap());
+            array.swap(i, j);
+
+            state.sleep(None);
+        }
+
+        state.check();
+    }
+}
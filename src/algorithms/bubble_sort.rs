@@ -1,8 +1,5 @@
 use super::Algorithm;
-use crate::{
-    array::Array,
-    state::{SharedState, Status},
-};
+use crate::{array::Array, state::SharedState};
 
 pub struct BubbleSort;
 
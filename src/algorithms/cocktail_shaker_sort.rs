@@ -0,0 +1,66 @@
+use super::Algorithm;
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+pub struct CocktailShakerSort;
+
+impl Algorithm for CocktailShakerSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm("Cocktail Shaker Sort".to_string());
+
+        let (mut start, mut end) = (0, array.len() - 1);
+
+        while start < end {
+            state.set_bands(vec![Band::new(start, end, "unsorted")]);
+            state.log(format!("Forward pass over [{}, {}]", start, end));
+
+            let mut last_swap = start;
+
+            for j in start..end {
+                if state.interrupted() {
+                    return;
+                }
+
+                if array.is_greater(j, j + 1) {
+                    last_swap = j;
+                    state.set_last(u32::try_from(j + 1).unwrap());
+                    array.swap(j, j + 1);
+                }
+
+                state.sleep(None);
+            }
+
+            // Everything after the last swap is already in its final place.
+            end = last_swap;
+
+            if start == end {
+                break;
+            }
+
+            state.set_bands(vec![Band::new(start, end, "unsorted")]);
+            state.log(format!("Backward pass over [{}, {}]", start, end));
+
+            let mut last_swap = end;
+
+            for j in (start..end).rev() {
+                if state.interrupted() {
+                    return;
+                }
+
+                if array.is_greater(j, j + 1) {
+                    last_swap = j + 1;
+                    state.set_last(u32::try_from(j).unwrap());
+                    array.swap(j, j + 1);
+                }
+
+                state.sleep(None);
+            }
+
+            start = last_swap;
+        }
+
+        state.check();
+    }
+}
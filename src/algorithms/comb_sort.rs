@@ -0,0 +1,44 @@
+use super::Algorithm;
+use crate::{array::Array, state::SharedState};
+
+/// Factor the gap is divided by after every pass.
+const SHRINK_FACTOR: f64 = 1.3;
+
+pub struct CombSort;
+
+impl Algorithm for CombSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm("Comb Sort".to_string());
+
+        let len = array.len();
+        let mut gap = len;
+        let mut sorted = false;
+
+        while !sorted {
+            gap = ((gap as f64 / SHRINK_FACTOR) as usize).max(1);
+            // A pass with gap 1 that swaps nothing proves the array sorted.
+            sorted = gap == 1;
+
+            state.log(format!(
+                "Comb Sort: gap {} (shrink factor {})",
+                gap, SHRINK_FACTOR
+            ));
+
+            for j in 0..len - gap {
+                if state.interrupted() {
+                    return;
+                }
+
+                if array.is_greater(j, j + gap) {
+                    sorted = false;
+                    state.set_last(u32::try_from(j + gap).unwrap());
+                    array.swap(j, j + gap);
+                }
+
+                state.sleep(None);
+            }
+        }
+
+        state.check();
+    }
+}
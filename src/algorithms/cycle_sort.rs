@@ -0,0 +1,83 @@
+use super::Algorithm;
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+/// Writes every value straight to its final index, so that each misplaced
+/// value is written exactly once, against up to two per swap for
+/// [`super::selection_sort::SelectionSort`].
+pub struct CycleSort;
+
+/// Final index of `item` among `start..len`: `start` plus the number of smaller
+/// values after it, moved past any equal values already placed there.
+fn final_index(state: &SharedState, array: &Array, item: u32, start: usize) -> usize {
+    let mut position = start;
+
+    for i in start + 1..array.len() {
+        state.set_comparison([start as u32, i as u32]);
+
+        if array.value_is_greater(item, array.get(i)) {
+            position += 1;
+        }
+
+        state.sleep(None);
+    }
+
+    while position != start && array.value_is_equal(item, array.get(position)) {
+        position += 1;
+    }
+
+    position
+}
+
+impl Algorithm for CycleSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(90);
+        state.init_algorithm("Cycle Sort".to_string());
+
+        let len = array.len();
+
+        for start in 0..len - 1 {
+            if state.interrupted() {
+                return;
+            }
+
+            let mut item = array.get(start);
+            let mut position = final_index(&state, &array, item, start);
+
+            if position == start {
+                continue;
+            }
+
+            state.set_bands(vec![Band::new(start, len - 1, "cycle")]);
+
+            while position != start {
+                if state.interrupted() {
+                    return;
+                }
+
+                let displaced = array.get(position);
+                state.set_last(u32::try_from(position).unwrap());
+                array.set(position, item);
+
+                state.log(format!(
+                    "Cycle from {}: wrote {} to its final index {}, {} writes in total",
+                    start,
+                    item,
+                    position,
+                    state.get_writes()
+                ));
+
+                item = displaced;
+
+                position = final_index(&state, &array, item, start);
+            }
+
+            state.set_last(u32::try_from(start).unwrap());
+            array.set(start, item);
+        }
+
+        state.check();
+    }
+}
@@ -0,0 +1,31 @@
+use super::Algorithm;
+use crate::{array::Array, state::SharedState};
+
+pub struct GnomeSort;
+
+impl Algorithm for GnomeSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm("Gnome Sort".to_string());
+
+        let len = array.len();
+        let mut position = 1;
+
+        while position < len {
+            if state.interrupted() {
+                return;
+            }
+
+            if position == 0 || !array.is_greater(position - 1, position) {
+                position += 1;
+            } else {
+                state.set_last(u32::try_from(position - 1).unwrap());
+                array.swap(position - 1, position);
+                position -= 1;
+            }
+
+            state.sleep(None);
+        }
+
+        state.check();
+    }
+}
@@ -1,40 +1,36 @@
 use super::Algorithm;
-use crate::{
-    array::Array,
-    state::{SharedState, Status},
-};
+use crate::{array::Array, state::SharedState};
 
 pub struct InsertionSort;
 
-impl Algorithm for InsertionSort {
-    fn sort(&self, state: SharedState, array: Array) {
-        state.init_algorithm("Insertion Sort".to_string());
+/// Insertion sorts the range `start..end`.
+pub fn insertion_sort(state: &SharedState, array: &Array, start: usize, end: usize) {
+    for i in start + 1..end {
+        if state.interrupted() {
+            return;
+        }
 
-        let len = array.len();
+        let mut left = i;
 
-        for i in 1..len {
-            match state.get_status() {
-                Status::Paused => state.park(),
-                Status::Interrupted => return,
-                _ => {}
-            };
+        while left > start && array.is_greater(left - 1, left) {
+            if state.interrupted() {
+                return;
+            }
 
-            let mut left = i;
+            state.set_last(u32::try_from(left).unwrap());
+            array.swap(left - 1, left);
+            left -= 1;
 
-            while left > 0 && array.is_greater(left - 1, left) {
-                match state.get_status() {
-                    Status::Paused => state.park(),
-                    Status::Interrupted => return,
-                    _ => {}
-                };
+            state.sleep(None);
+        }
+    }
+}
 
-                state.set_last(u32::try_from(left).unwrap());
-                array.swap(left - 1, left);
-                left -= 1;
+impl Algorithm for InsertionSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm("Insertion Sort".to_string());
 
-                state.sleep(None);
-            }
-        }
+        insertion_sort(&state, &array, 0, array.len());
 
         state.check();
     }
@@ -0,0 +1,79 @@
+use super::{
+    median_of_medians::select,
+    quick_select::Rank,
+    quick_sort::{lomuto, median_of_three},
+    Algorithm,
+};
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+/// Musser's introselect: quickselect with median-of-three pivots that falls
+/// back to median of medians once it stops shrinking the range fast enough.
+pub struct IntroSelect {
+    pub rank: Rank,
+}
+
+impl IntroSelect {
+    pub fn new(variant: usize) -> Self {
+        Self {
+            rank: Rank::ALL[variant % Rank::ALL.len()],
+        }
+    }
+}
+
+impl Algorithm for IntroSelect {
+    fn sort(&self, state: SharedState, array: Array) {
+        let len = array.len();
+        let k = self.rank.k(len);
+
+        state.set_speed(50);
+        state.init_algorithm(format!("Introselect (k = {})", k));
+
+        let (mut start, mut end) = (0, len - 1);
+        let mut depth_limit = 2 * len.max(2).ilog2();
+
+        while start < end {
+            if state.interrupted() {
+                return;
+            }
+
+            if depth_limit == 0 {
+                state.log(format!(
+                    "Introselect: depth limit reached, median of medians on [{}, {}]",
+                    start, end
+                ));
+                select(&state, &array, start, end + 1, k);
+                break;
+            }
+
+            depth_limit -= 1;
+
+            state.log(format!(
+                "Introselect: partitioning [{}, {}], {} partitions before median of medians",
+                start, end, depth_limit
+            ));
+            state.set_bands(vec![Band::new(start, end, "partition")]);
+
+            let pivot = median_of_three(&array, start, start + (end - start) / 2, end);
+            array.swap(pivot, end);
+
+            let pivot = lomuto(&state, &array, start, end);
+
+            if pivot == k {
+                break;
+            } else if k < pivot {
+                end = pivot - 1;
+            } else {
+                start = pivot + 1;
+            }
+        }
+
+        state.check_selected(k);
+    }
+
+    fn variants(&self) -> usize {
+        Rank::ALL.len()
+    }
+}
@@ -0,0 +1,114 @@
+use super::{
+    insertion_sort::insertion_sort,
+    quick_sort::{lomuto, median_of_three},
+    Algorithm,
+};
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+/// Ranges this short are left to insertion sort.
+const INSERTION_THRESHOLD: usize = 16;
+
+pub struct IntroSort;
+
+/// Moves the value at `root` down the max-heap stored in `start..end`.
+pub fn sift_down(state: &SharedState, array: &Array, start: usize, mut root: usize, end: usize) {
+    loop {
+        let mut child = start + 2 * (root - start) + 1;
+
+        if child >= end || state.interrupted() {
+            return;
+        }
+
+        if child + 1 < end && array.is_greater(child + 1, child) {
+            child += 1;
+        }
+
+        if !array.is_greater(child, root) {
+            return;
+        }
+
+        state.set_last(child as u32);
+        array.swap(root, child);
+        root = child;
+
+        state.sleep(None);
+    }
+}
+
+/// Heapsorts the range `start..end`.
+pub fn heap_sort(state: &SharedState, array: &Array, start: usize, end: usize) {
+    let len = end - start;
+
+    for root in (start..start + len / 2).rev() {
+        sift_down(state, array, start, root, end);
+    }
+
+    for last in (start + 1..end).rev() {
+        if state.interrupted() {
+            return;
+        }
+
+        state.set_bands(vec![
+            Band::new(start, last - 1, "heap"),
+            Band::new(last, end - 1, "sorted"),
+        ]);
+        state.set_last(last as u32);
+        array.swap(start, last);
+        sift_down(state, array, start, start, last);
+    }
+}
+
+impl Algorithm for IntroSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(75);
+        state.init_algorithm("Introsort".to_string());
+
+        fn intro_sort(state: &SharedState, array: &Array, start: usize, end: usize, depth: u32) {
+            if end - start <= 1 || state.interrupted() {
+                return;
+            }
+
+            if end - start <= INSERTION_THRESHOLD {
+                state.log(format!("Insertion sort of [{}, {}]", start, end - 1));
+                state.set_bands(vec![Band::new(start, end - 1, "insertion sort")]);
+                insertion_sort(state, array, start, end);
+                return;
+            }
+
+            if depth == 0 {
+                state.log(format!(
+                    "Depth limit reached, heapsort of [{}, {}]",
+                    start,
+                    end - 1
+                ));
+                heap_sort(state, array, start, end);
+                return;
+            }
+
+            state.log(format!(
+                "Quicksort partition of [{}, {}], {} levels left before heapsort",
+                start,
+                end - 1,
+                depth
+            ));
+            state.set_bands(vec![Band::new(start, end - 1, "quicksort")]);
+
+            let pivot = median_of_three(array, start, start + (end - start) / 2, end - 1);
+            array.swap(pivot, end - 1);
+
+            let pivot = lomuto(state, array, start, end - 1);
+
+            intro_sort(state, array, start, pivot, depth - 1);
+            intro_sort(state, array, pivot + 1, end, depth - 1);
+        }
+
+        let len = array.len();
+
+        intro_sort(&state, &array, 0, len, 2 * len.max(1).ilog2());
+
+        state.check();
+    }
+}
@@ -0,0 +1,113 @@
+use super::{insertion_sort::insertion_sort, quick_select::Rank, quick_sort::lomuto, Algorithm};
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+/// Size of the groups whose medians are taken, and of the ranges that are
+/// insertion sorted instead of partitioned.
+const GROUP_SIZE: usize = 5;
+
+/// Blum, Floyd, Pratt, Rivest and Tarjan's linear-time selection.
+pub struct MedianOfMedians {
+    pub rank: Rank,
+}
+
+impl MedianOfMedians {
+    pub fn new(variant: usize) -> Self {
+        Self {
+            rank: Rank::ALL[variant % Rank::ALL.len()],
+        }
+    }
+}
+
+/// Moves the median of every group of five in `start..end` to the front and
+/// selects the median of those medians, returning its index.
+fn pivot(state: &SharedState, array: &Array, start: usize, end: usize) -> usize {
+    let groups = (end - start).div_ceil(GROUP_SIZE);
+
+    state.set_bands(
+        (0..groups)
+            .map(|group| {
+                let first = start + group * GROUP_SIZE;
+                Band::new(first, (first + GROUP_SIZE).min(end) - 1, "group of five")
+            })
+            .collect(),
+    );
+    state.log(format!(
+        "Median of Medians: sorting {} groups of five in [{}, {}]",
+        groups,
+        start,
+        end - 1
+    ));
+
+    for group in 0..groups {
+        let first = start + group * GROUP_SIZE;
+        let last = (first + GROUP_SIZE).min(end);
+
+        insertion_sort(state, array, first, last);
+
+        // Every earlier group has already given up its median.
+        state.set_last((start + group) as u32);
+        array.swap(first + (last - first - 1) / 2, start + group);
+    }
+
+    let median = start + (groups - 1) / 2;
+
+    state.set_bands(vec![Band::new(start, start + groups - 1, "medians")]);
+    select(state, array, start, start + groups, median);
+
+    median
+}
+
+/// Moves the k-th smallest value of `start..end` to index `k`, with smaller
+/// values before it and larger ones after it.
+pub fn select(state: &SharedState, array: &Array, mut start: usize, mut end: usize, k: usize) {
+    loop {
+        if state.interrupted() {
+            return;
+        }
+
+        if end - start <= GROUP_SIZE {
+            insertion_sort(state, array, start, end);
+            return;
+        }
+
+        let pivot = pivot(state, array, start, end);
+
+        state.log(format!(
+            "Median of Medians: partitioning [{}, {}] around the median of medians",
+            start,
+            end - 1
+        ));
+        state.set_bands(vec![Band::new(start, end - 1, "partition")]);
+
+        array.swap(pivot, end - 1);
+        let pivot = lomuto(state, array, start, end - 1);
+
+        if pivot == k {
+            return;
+        } else if k < pivot {
+            end = pivot;
+        } else {
+            start = pivot + 1;
+        }
+    }
+}
+
+impl Algorithm for MedianOfMedians {
+    fn sort(&self, state: SharedState, array: Array) {
+        let k = self.rank.k(array.len());
+
+        state.set_speed(50);
+        state.init_algorithm(format!("Median of Medians (k = {})", k));
+
+        select(&state, &array, 0, array.len(), k);
+
+        state.check_selected(k);
+    }
+
+    fn variants(&self) -> usize {
+        Rank::ALL.len()
+    }
+}
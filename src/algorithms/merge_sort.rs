@@ -1,59 +1,327 @@
 use super::Algorithm;
 use crate::{
-    array::Array,
-    state::{SharedState, Status},
+    array::{Array, Buffer},
+    state::{Band, SharedState},
 };
 
-pub struct MergeSort;
+#[derive(Clone, Copy)]
+pub enum Merge {
+    Shifting,
+    Buffered,
+    BottomUp,
+    Natural,
+    Rotation,
+}
+
+impl Merge {
+    pub const ALL: [Merge; 5] = [
+        Merge::Shifting,
+        Merge::Buffered,
+        Merge::BottomUp,
+        Merge::Natural,
+        Merge::Rotation,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Merge::Shifting => "Shifting",
+            Merge::Buffered => "Buffered",
+            Merge::BottomUp => "Bottom-Up",
+            Merge::Natural => "Natural",
+            Merge::Rotation => "In-Place Rotation",
+        }
+    }
+}
+
+pub struct MergeSort {
+    pub merge: Merge,
+}
+
+impl MergeSort {
+    pub fn new(variant: usize) -> Self {
+        Self {
+            merge: Merge::ALL[variant % Merge::ALL.len()],
+        }
+    }
+}
+
+/// Merges `start..=mid` with `mid + 1..=end` by shifting every element of
+/// the right run that is smaller than the left one into place one by one.
+fn shifting_merge(state: &SharedState, array: &Array, start: usize, mut mid: usize, end: usize) {
+    let (mut left, mut right) = (start, mid + 1);
+
+    while left <= mid && right <= end {
+        state.sleep(None);
+
+        if state.interrupted() {
+            return;
+        }
+
+        if array.is_greater(left, right) {
+            let mut idx = right;
+            let temp = array.get(idx);
+
+            while idx > left {
+                array.set(idx, array.get(idx - 1));
+                idx -= 1;
+            }
+
+            state.set_last(left as u32);
+            array.set(left, temp);
+
+            left += 1;
+            mid += 1;
+            right += 1;
+        } else {
+            left += 1;
+        }
+    }
+}
+
+/// Textbook merge: copies the left run `start..=mid` into `buffer` and merges
+/// it back with the right run `mid + 1..=end`.
+pub fn buffered_merge(
+    state: &SharedState,
+    array: &Array,
+    buffer: &mut Buffer,
+    start: usize,
+    mid: usize,
+    end: usize,
+) {
+    for i in start..=mid {
+        buffer.set(i - start, array.get(i));
+    }
+
+    let (mut left, mut right, mut k) = (0, mid + 1, start);
+    let left_len = mid - start + 1;
+
+    while left < left_len && right <= end {
+        state.sleep(None);
+
+        if state.interrupted() {
+            return;
+        }
+
+        let (value1, value2) = (buffer.get(left), array.get(right));
+
+        let value = if array.value_is_greater_at([start + left, right], value1, value2) {
+            right += 1;
+            array.get(right - 1)
+        } else {
+            left += 1;
+            buffer.get(left - 1)
+        };
+
+        state.set_last(k as u32);
+        array.set(k, value);
+        k += 1;
+    }
+
+    while left < left_len {
+        state.set_last(k as u32);
+        array.set(k, buffer.get(left));
+        left += 1;
+        k += 1;
+        state.sleep(None);
+    }
+}
+
+/// Reverses `start..end` with swaps.
+pub fn reverse(array: &Array, mut start: usize, mut end: usize) {
+    while start + 1 < end {
+        end -= 1;
+        array.swap(start, end);
+        start += 1;
+    }
+}
+
+/// Rotates `start..end` left so that `mid` becomes its first element.
+pub fn rotate(array: &Array, start: usize, mid: usize, end: usize) {
+    reverse(array, start, mid);
+    reverse(array, mid, end);
+    reverse(array, start, end);
+}
+
+/// Buffer-free merge of `start..mid` with `mid..end`: split the longer run in
+/// half, binary search the matching cut in the other run, rotate the two
+/// middle blocks past each other and recurse on both sides.
+fn rotation_merge(state: &SharedState, array: &Array, start: usize, mid: usize, end: usize) {
+    if start >= mid || mid >= end || state.interrupted() {
+        return;
+    }
+
+    if end - start == 2 {
+        if array.is_greater(start, mid) {
+            state.set_last(start as u32);
+            array.swap(start, mid);
+        }
+
+        return;
+    }
+
+    let (cut1, cut2) = if mid - start > end - mid {
+        let cut1 = start + (mid - start) / 2;
+        let (mut low, mut high) = (mid, end);
+
+        while low < high {
+            let probe = low + (high - low) / 2;
+
+            if array.is_greater(cut1, probe) {
+                low = probe + 1;
+            } else {
+                high = probe;
+            }
+        }
+
+        (cut1, low)
+    } else {
+        let cut2 = mid + (end - mid) / 2;
+        let (mut low, mut high) = (start, mid);
+
+        while low < high {
+            let probe = low + (high - low) / 2;
+
+            if array.is_greater(probe, cut2) {
+                high = probe;
+            } else {
+                low = probe + 1;
+            }
+        }
+
+        (low, cut2)
+    };
+
+    state.set_bands(vec![
+        Band::new(cut1, mid.max(cut1 + 1) - 1, "rotate left block"),
+        Band::new(mid, cut2.max(mid + 1) - 1, "rotate right block"),
+    ]);
+
+    rotate(array, cut1, mid, cut2);
+    state.set_last(cut1 as u32);
+    state.sleep(None);
+
+    let new_mid = cut1 + (cut2 - mid);
+
+    rotation_merge(state, array, start, cut1, new_mid);
+    rotation_merge(state, array, new_mid, cut2, end);
+}
 
 impl Algorithm for MergeSort {
     fn sort(&self, state: SharedState, array: Array) {
         state.set_speed(75);
-        state.init_algorithm("Merge Sort".to_string());
+        state.init_algorithm(format!("Merge Sort ({})", self.merge.name()));
+
+        let len = array.len();
+        let mut buffer = Buffer::new(state.clone(), len);
+
+        fn merge(
+            state: &SharedState,
+            array: &Array,
+            buffer: &mut Buffer,
+            strategy: Merge,
+            start: usize,
+            mid: usize,
+            end: usize,
+        ) {
+            state.set_bands(vec![
+                Band::new(start, mid, "left run"),
+                Band::new(mid + 1, end, "right run"),
+            ]);
+            state.log(format!(
+                "{} merge of [{}, {}] and [{}, {}]",
+                strategy.name(),
+                start,
+                mid,
+                mid + 1,
+                end
+            ));
+
+            match strategy {
+                Merge::Shifting => shifting_merge(state, array, start, mid, end),
+                Merge::Rotation => rotation_merge(state, array, start, mid + 1, end + 1),
+                _ => buffered_merge(state, array, buffer, start, mid, end),
+            }
+        }
 
-        fn merge_sort(state: &SharedState, array: &Array, start: u32, end: u32) -> () {
-            if start >= end {
+        fn merge_sort(
+            state: &SharedState,
+            array: &Array,
+            buffer: &mut Buffer,
+            strategy: Merge,
+            start: usize,
+            end: usize,
+        ) {
+            if start >= end || state.interrupted() {
                 return;
             }
 
-            let mut mid = (start + end) / 2;
+            let mid = (start + end) / 2;
 
-            merge_sort(state, &array, start, mid);
-            merge_sort(state, &array, mid + 1, end);
+            merge_sort(state, array, buffer, strategy, start, mid);
+            merge_sort(state, array, buffer, strategy, mid + 1, end);
 
-            let (mut left, mut right) = (start, mid + 1);
+            merge(state, array, buffer, strategy, start, mid, end);
+        }
 
-            while left <= mid && right <= end {
-                state.sleep(None);
-                match state.get_status() {
-                    Status::Paused => state.park(),
-                    Status::Interrupted => return,
-                    _ => {}
-                };
+        match self.merge {
+            Merge::BottomUp => {
+                let mut width = 1;
 
-                if array.is_greater(left as usize, right as usize) {
-                    let mut idx: usize = right as usize;
-                    let temp = array.get(idx);
+                while width < len && !state.interrupted() {
+                    let mut start = 0;
 
-                    while idx > left as usize {
-                        array.set(idx, array.get(idx - 1));
-                        idx -= 1;
+                    while start + width < len {
+                        let end = (start + 2 * width - 1).min(len - 1);
+                        merge(
+                            &state,
+                            &array,
+                            &mut buffer,
+                            self.merge,
+                            start,
+                            start + width - 1,
+                            end,
+                        );
+                        start += 2 * width;
                     }
 
-                    state.set_last(left as u32);
-                    array.set(left as usize, temp);
-
-                    left += 1;
-                    mid += 1;
-                    right += 1;
-                } else {
-                    left += 1;
+                    width *= 2;
                 }
             }
-        }
+            Merge::Natural => loop {
+                let mut runs = vec![0];
 
-        merge_sort(&state, &array, 0, array.len() as u32 - 1);
+                for i in 1..len {
+                    if array.is_greater(i - 1, i) {
+                        runs.push(i);
+                    }
+                }
+
+                if runs.len() == 1 || state.interrupted() {
+                    break;
+                }
+
+                state.log(format!("Found {} natural runs", runs.len()));
+                runs.push(len);
+
+                for pair in runs.windows(3).step_by(2) {
+                    merge(
+                        &state,
+                        &array,
+                        &mut buffer,
+                        self.merge,
+                        pair[0],
+                        pair[1] - 1,
+                        pair[2] - 1,
+                    );
+                }
+            },
+            _ => merge_sort(&state, &array, &mut buffer, self.merge, 0, len - 1),
+        }
 
         state.check();
     }
+
+    fn variants(&self) -> usize {
+        Merge::ALL.len()
+    }
 }
@@ -1,11 +1,90 @@
 use crate::{array::Array, state::SharedState};
 
+pub mod bitonic_sort;
+pub mod bogo_sort;
+pub mod bozo_sort;
 pub mod bubble_sort;
+pub mod cocktail_shaker_sort;
+pub mod comb_sort;
+pub mod cycle_sort;
+pub mod gnome_sort;
 pub mod insertion_sort;
+pub mod intro_select;
+pub mod intro_sort;
+pub mod median_of_medians;
 pub mod merge_sort;
+pub mod odd_even_merge_sort;
+pub mod odd_even_sort;
+pub mod odd_even_transposition_sort;
+pub mod pancake_sort;
+pub mod parallel_merge_sort;
+pub mod parallel_quick_sort;
+pub mod partial_sort;
+pub mod pdq_sort;
+pub mod quick_select;
 pub mod quick_sort;
 pub mod selection_sort;
+pub mod shell_sort;
+pub mod slow_sort;
+pub mod sorting_network;
+pub mod stooge_sort;
+pub mod tim_sort;
 
 pub trait Algorithm {
     fn sort(&self, state: SharedState, array: Array);
+
+    /// Number of runtime-selectable variants, see [`all`].
+    fn variants(&self) -> usize {
+        1
+    }
+}
+
+/// Seed of the randomised algorithms in reports, so that they repeat.
+pub const SEED: u64 = 42;
+
+/// Every algorithm, configured with its `variant`-th variant when it has several
+/// and `seed` when it makes random choices.
+pub fn all(variant: usize, seed: u64) -> Vec<Box<dyn Algorithm + Send + Sync>> {
+    vec![
+        Box::new(bubble_sort::BubbleSort),
+        Box::new(selection_sort::SelectionSort),
+        Box::new(insertion_sort::InsertionSort),
+        Box::new(cocktail_shaker_sort::CocktailShakerSort),
+        Box::new(comb_sort::CombSort),
+        Box::new(gnome_sort::GnomeSort),
+        Box::new(odd_even_sort::OddEvenSort),
+        Box::new(cycle_sort::CycleSort),
+        Box::new(shell_sort::ShellSort::new(variant)),
+        Box::new(merge_sort::MergeSort::new(variant)),
+        Box::new(quick_sort::QuickSort::new(variant, seed)),
+        Box::new(tim_sort::TimSort),
+        Box::new(intro_sort::IntroSort),
+        Box::new(pdq_sort::PdqSort),
+        Box::new(bitonic_sort::BitonicSort),
+        Box::new(odd_even_merge_sort::OddEvenMergeSort),
+        Box::new(odd_even_transposition_sort::OddEvenTranspositionSort),
+        Box::new(parallel_merge_sort::ParallelMergeSort),
+        Box::new(parallel_quick_sort::ParallelQuickSort),
+        Box::new(quick_select::QuickSelect::new(variant)),
+        Box::new(intro_select::IntroSelect::new(variant)),
+        Box::new(median_of_medians::MedianOfMedians::new(variant)),
+        Box::new(partial_sort::PartialSort::new(variant)),
+        Box::new(pancake_sort::PancakeSort),
+        Box::new(stooge_sort::StoogeSort),
+        Box::new(slow_sort::SlowSort),
+        Box::new(bogo_sort::BogoSort::new(seed)),
+        Box::new(bozo_sort::BozoSort::new(seed)),
+    ]
+}
+
+/// Every algorithm in every one of its variants.
+pub fn all_variants() -> Vec<Box<dyn Algorithm + Send + Sync>> {
+    let defaults = all(0, SEED);
+
+    (0..defaults.len())
+        .flat_map(|index| {
+            (0..defaults[index].variants())
+                .map(move |variant| all(variant, SEED).swap_remove(index))
+        })
+        .collect()
 }
@@ -0,0 +1,49 @@
+use super::{
+    sorting_network::{self, Network},
+    Algorithm,
+};
+use crate::{array::Array, state::SharedState};
+
+pub struct OddEvenMergeSort;
+
+/// Batcher's odd-even merge sorting network, in the iterative form that works
+/// for sizes other than powers of two.
+pub fn network(size: usize) -> Network {
+    let mut comparators = vec![];
+    let mut p = 1;
+
+    while p < size {
+        let mut k = p;
+
+        while k >= 1 {
+            let mut j = k % p;
+
+            while j + k < size {
+                for i in 0..k.min(size - j - k) {
+                    if (i + j) / (p * 2) == (i + j + k) / (p * 2) {
+                        comparators.push([(i + j) as u32, (i + j + k) as u32]);
+                    }
+                }
+
+                j += 2 * k;
+            }
+
+            k /= 2;
+        }
+
+        p *= 2;
+    }
+
+    Network::new(size, comparators)
+}
+
+impl Algorithm for OddEvenMergeSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(50);
+        state.init_algorithm("Odd-Even Merge Sort".to_string());
+
+        sorting_network::sort(&state, &array, network(array.len()));
+
+        state.check();
+    }
+}
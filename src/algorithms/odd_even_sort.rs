@@ -0,0 +1,42 @@
+use super::Algorithm;
+use crate::{array::Array, state::SharedState};
+
+/// Sequential brick sort: unlike [`super::odd_even_transposition_sort`], which
+/// always runs all of its rounds, it stops after the first even and odd pair
+/// of phases that swaps nothing.
+pub struct OddEvenSort;
+
+impl Algorithm for OddEvenSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm("Odd-Even Sort".to_string());
+
+        let len = array.len();
+        let mut sorted = false;
+        let mut round = 0;
+
+        while !sorted {
+            sorted = true;
+            round += 1;
+
+            for (phase, first) in [("even", 0), ("odd", 1)] {
+                state.log(format!("Round {}: comparing {} pairs", round, phase));
+
+                for j in (first..len - 1).step_by(2) {
+                    if state.interrupted() {
+                        return;
+                    }
+
+                    if array.is_greater(j, j + 1) {
+                        sorted = false;
+                        state.set_last(u32::try_from(j + 1).unwrap());
+                        array.swap(j, j + 1);
+                    }
+
+                    state.sleep(None);
+                }
+            }
+        }
+
+        state.check();
+    }
+}
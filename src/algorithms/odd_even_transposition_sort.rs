@@ -0,0 +1,31 @@
+use super::{
+    sorting_network::{self, Network},
+    Algorithm,
+};
+use crate::{array::Array, state::SharedState};
+
+pub struct OddEvenTranspositionSort;
+
+/// `size` alternating rounds comparing every even and then every odd pair of neighbours.
+pub fn network(size: usize) -> Network {
+    let mut comparators = vec![];
+
+    for round in 0..size {
+        for i in (round % 2..size.saturating_sub(1)).step_by(2) {
+            comparators.push([i as u32, (i + 1) as u32]);
+        }
+    }
+
+    Network::new(size, comparators)
+}
+
+impl Algorithm for OddEvenTranspositionSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(50);
+        state.init_algorithm("Odd-Even Transposition Sort".to_string());
+
+        sorting_network::sort(&state, &array, network(array.len()));
+
+        state.check();
+    }
+}
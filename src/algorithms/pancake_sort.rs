@@ -0,0 +1,65 @@
+use super::Algorithm;
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+pub struct PancakeSort;
+
+/// Reverses the prefix `0..=end` one swap per step, like flipping a stack of pancakes.
+fn flip(state: &SharedState, array: &Array, end: usize) {
+    state.set_bands(vec![Band::new(0, end, "flip")]);
+    state.log(format!(
+        "Pancake Sort: flipping the top {} pancakes",
+        end + 1
+    ));
+
+    let (mut start, mut end) = (0, end);
+
+    while start < end {
+        if state.interrupted() {
+            return;
+        }
+
+        state.set_last(u32::try_from(start).unwrap());
+        array.swap(start, end);
+        start += 1;
+        end -= 1;
+
+        state.sleep(None);
+    }
+}
+
+impl Algorithm for PancakeSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(90);
+        state.init_algorithm("Pancake Sort".to_string());
+
+        for size in (2..=array.len()).rev() {
+            let mut max = 0;
+
+            for i in 1..size {
+                if state.interrupted() {
+                    return;
+                }
+
+                if array.is_greater(i, max) {
+                    max = i;
+                }
+            }
+
+            if max == size - 1 {
+                continue;
+            }
+
+            // Brings the largest pancake to the top, then flips it to the bottom.
+            if max > 0 {
+                flip(&state, &array, max);
+            }
+
+            flip(&state, &array, size - 1);
+        }
+
+        state.check();
+    }
+}
@@ -0,0 +1,68 @@
+use std::{ops::Range, thread};
+
+use super::{merge_sort::buffered_merge, Algorithm};
+use crate::{
+    array::{Array, Buffer},
+    state::SharedState,
+};
+
+/// Number of worker threads the array is split between.
+const THREADS: usize = 4;
+
+pub struct ParallelMergeSort;
+
+impl Algorithm for ParallelMergeSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(75);
+        state.init_algorithm("Parallel Merge Sort".to_string());
+        state.set_workers(THREADS);
+
+        /// Sorts `start..=end` with the worker threads in `workers`, the first
+        /// of which is the calling thread. The upper half of the range goes to
+        /// a new thread as long as more than one worker is available.
+        fn merge_sort(
+            state: &SharedState,
+            array: &Array,
+            start: usize,
+            end: usize,
+            workers: Range<usize>,
+        ) {
+            if start >= end || state.interrupted() {
+                return;
+            }
+
+            let mid = (start + end) / 2;
+
+            if workers.len() > 1 {
+                let split = workers.start + workers.len() / 2;
+
+                thread::scope(|scope| {
+                    scope.spawn(|| merge_sort(state, array, mid + 1, end, split..workers.end));
+                    merge_sort(state, array, start, mid, workers.start..split);
+                });
+            } else {
+                merge_sort(state, array, start, mid, workers.clone());
+                merge_sort(state, array, mid + 1, end, workers.clone());
+            }
+
+            let worker = array.worker(workers.start);
+            let mut buffer = Buffer::new(state.clone(), mid - start + 1);
+
+            state.log(format!(
+                "Thread {} merging [{}, {}] and [{}, {}]",
+                workers.start + 1,
+                start,
+                mid,
+                mid + 1,
+                end
+            ));
+
+            buffered_merge(state, &worker, &mut buffer, start, mid, end);
+            state.worker_finished(workers.start);
+        }
+
+        merge_sort(&state, &array, 0, array.len() - 1, 0..THREADS);
+
+        state.check();
+    }
+}
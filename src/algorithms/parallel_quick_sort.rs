@@ -0,0 +1,89 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use super::{
+    quick_sort::{lomuto, median_of_three},
+    Algorithm,
+};
+use crate::{array::Array, state::SharedState};
+
+/// Number of worker threads partitions are handed to.
+const THREADS: usize = 4;
+/// Partitions shorter than this are not worth a thread of their own.
+const SPAWN_THRESHOLD: usize = 16;
+
+pub struct ParallelQuickSort;
+
+impl Algorithm for ParallelQuickSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(75);
+        state.init_algorithm("Parallel Quick Sort".to_string());
+        state.set_workers(THREADS);
+
+        /// Sorts `start..=end` on `worker`, handing the right partition to a
+        /// new worker thread while there are workers left.
+        fn quick_sort(
+            state: &SharedState,
+            array: &Array,
+            next: &AtomicUsize,
+            worker: usize,
+            start: usize,
+            end: usize,
+        ) {
+            if end <= start || state.interrupted() {
+                return;
+            }
+
+            let tagged = array.worker(worker);
+
+            state.log(format!(
+                "Thread {} partitioning [{}, {}]",
+                worker + 1,
+                start,
+                end
+            ));
+
+            let pivot = median_of_three(&tagged, start, start + (end - start) / 2, end);
+            tagged.swap(pivot, end);
+
+            let pivot = lomuto(state, &tagged, start, end);
+
+            let spawned = if end - pivot > SPAWN_THRESHOLD {
+                Some(next.fetch_add(1, Ordering::Relaxed)).filter(|&id| id < THREADS)
+            } else {
+                None
+            };
+
+            match spawned {
+                Some(id) => {
+                    thread::scope(|scope| {
+                        scope.spawn(|| {
+                            quick_sort(state, array, next, id, pivot + 1, end);
+                            state.worker_finished(id);
+                        });
+
+                        if pivot > 0 {
+                            quick_sort(state, array, next, worker, start, pivot - 1);
+                        }
+                    });
+                }
+                None => {
+                    if pivot > 0 {
+                        quick_sort(state, array, next, worker, start, pivot - 1);
+                    }
+
+                    quick_sort(state, array, next, worker, pivot + 1, end);
+                }
+            }
+        }
+
+        let next = AtomicUsize::new(1);
+
+        quick_sort(&state, &array, &next, 0, 0, array.len() - 1);
+        state.worker_finished(0);
+
+        state.check();
+    }
+}
@@ -0,0 +1,78 @@
+use super::{
+    intro_sort::{heap_sort, sift_down},
+    quick_select::Rank,
+    Algorithm,
+};
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+/// Ranks in the order the variants cycle through them, starting with the
+/// smallest fifth of the values.
+const RANKS: [Rank; 4] = [Rank::Quintile, Rank::Minimum, Rank::Median, Rank::Maximum];
+
+/// Sorts only the values up to a rank into the front, keeping them in a
+/// max-heap while scanning the rest.
+pub struct PartialSort {
+    pub rank: Rank,
+}
+
+impl PartialSort {
+    pub fn new(variant: usize) -> Self {
+        Self {
+            rank: RANKS[variant % RANKS.len()],
+        }
+    }
+}
+
+impl Algorithm for PartialSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        let len = array.len();
+        let k = self.rank.k(len) + 1;
+
+        state.set_speed(75);
+        state.init_algorithm(format!("Heap Partial Sort (k = {})", k));
+
+        for root in (0..k / 2).rev() {
+            sift_down(&state, &array, 0, root, k);
+        }
+
+        state.log(format!(
+            "Heap Partial Sort: keeping the {} smallest values in a max-heap",
+            k
+        ));
+
+        for i in k..len {
+            if state.interrupted() {
+                return;
+            }
+
+            state.set_bands(vec![
+                Band::new(0, k - 1, "heap"),
+                Band::new(i, len - 1, "unscanned"),
+            ]);
+
+            // Smaller than the largest value kept, so it replaces it.
+            if array.is_greater(0, i) {
+                state.set_last(0);
+                array.swap(0, i);
+                sift_down(&state, &array, 0, 0, k);
+            }
+
+            state.sleep(None);
+        }
+
+        state.log(format!(
+            "Heap Partial Sort: sorting the {} smallest values",
+            k
+        ));
+        heap_sort(&state, &array, 0, k);
+
+        state.check_partial(k);
+    }
+
+    fn variants(&self) -> usize {
+        RANKS.len()
+    }
+}
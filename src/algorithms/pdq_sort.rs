@@ -0,0 +1,231 @@
+use super::{
+    insertion_sort::insertion_sort,
+    intro_sort::heap_sort,
+    quick_sort::{median_of_three, ninther},
+    Algorithm,
+};
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+/// Ranges this short are left to insertion sort.
+const INSERTION_THRESHOLD: usize = 24;
+/// Ranges longer than this pick their pivot with Tukey's ninther.
+const NINTHER_THRESHOLD: usize = 128;
+/// Moves a partial insertion sort may make before giving up.
+const PARTIAL_INSERTION_LIMIT: usize = 8;
+
+pub struct PdqSort;
+
+/// Partitions `start..end` around the value at `start`, sending keys equal to
+/// the pivot right. Returns the pivot's final index and whether the range was
+/// already partitioned.
+fn partition_right(state: &SharedState, array: &Array, start: usize, end: usize) -> (usize, bool) {
+    let (mut left, mut right) = (start + 1, end - 1);
+    let mut already_partitioned = true;
+
+    loop {
+        while left <= right && array.is_greater(start, left) {
+            left += 1;
+        }
+
+        while left <= right && !array.is_greater(start, right) {
+            right -= 1;
+        }
+
+        if left >= right || state.interrupted() {
+            break;
+        }
+
+        already_partitioned = false;
+        state.set_last(right as u32);
+        array.swap(left, right);
+        left += 1;
+        right -= 1;
+
+        state.sleep(None);
+    }
+
+    array.swap(start, right);
+
+    (right, already_partitioned)
+}
+
+/// Partitions `start..end` around the value at `start`, sending keys equal to
+/// the pivot left, which leaves nothing to sort on that side when the pivot
+/// equals the element before the range.
+fn partition_left(state: &SharedState, array: &Array, start: usize, end: usize) -> usize {
+    let (mut left, mut right) = (start + 1, end - 1);
+
+    loop {
+        while left <= right && !array.is_greater(left, start) {
+            left += 1;
+        }
+
+        while left <= right && array.is_greater(right, start) {
+            right -= 1;
+        }
+
+        if left >= right || state.interrupted() {
+            break;
+        }
+
+        state.set_last(right as u32);
+        array.swap(left, right);
+        left += 1;
+        right -= 1;
+
+        state.sleep(None);
+    }
+
+    array.swap(start, right);
+
+    right
+}
+
+/// Insertion sorts `start..end` unless that takes more than
+/// [`PARTIAL_INSERTION_LIMIT`] moves, reporting whether it finished.
+fn partial_insertion_sort(state: &SharedState, array: &Array, start: usize, end: usize) -> bool {
+    let mut moves = 0;
+
+    for i in start + 1..end {
+        let mut left = i;
+
+        while left > start && array.is_greater(left - 1, left) {
+            state.set_last(left as u32);
+            array.swap(left - 1, left);
+            left -= 1;
+            moves += 1;
+
+            state.sleep(None);
+        }
+
+        if moves > PARTIAL_INSERTION_LIMIT || state.interrupted() {
+            return false;
+        }
+    }
+
+    true
+}
+
+impl Algorithm for PdqSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(75);
+        state.init_algorithm("Pattern-Defeating Quicksort".to_string());
+
+        fn pdq_sort(
+            state: &SharedState,
+            array: &Array,
+            mut start: usize,
+            end: usize,
+            mut bad_allowed: u32,
+            mut leftmost: bool,
+        ) {
+            loop {
+                let size = end - start;
+
+                if state.interrupted() {
+                    return;
+                }
+
+                if size < INSERTION_THRESHOLD {
+                    if size > 1 {
+                        state.log(format!("Insertion sort of [{}, {}]", start, end - 1));
+                        state.set_bands(vec![Band::new(start, end - 1, "insertion sort")]);
+                        insertion_sort(state, array, start, end);
+                    }
+
+                    return;
+                }
+
+                let pivot = if size > NINTHER_THRESHOLD {
+                    ninther(array, start, end - 1)
+                } else {
+                    median_of_three(array, start, start + size / 2, end - 1)
+                };
+
+                array.swap(start, pivot);
+
+                if !leftmost && !array.is_greater(start, start - 1) {
+                    state.log(format!(
+                        "Pivot equals its predecessor, partitioning equal keys left in [{}, {}]",
+                        start,
+                        end - 1
+                    ));
+                    state.set_bands(vec![Band::new(start, end - 1, "partition left")]);
+
+                    start = partition_left(state, array, start, end) + 1;
+                    continue;
+                }
+
+                state.log(format!(
+                    "Partitioning [{}, {}], {} bad partitions allowed before heapsort",
+                    start,
+                    end - 1,
+                    bad_allowed
+                ));
+                state.set_bands(vec![Band::new(start, end - 1, "partition right")]);
+
+                let (pivot, already_partitioned) = partition_right(state, array, start, end);
+                let (left_size, right_size) = (pivot - start, end - pivot - 1);
+
+                if left_size < size / 8 || right_size < size / 8 {
+                    bad_allowed -= 1;
+
+                    if bad_allowed == 0 {
+                        state.log(format!(
+                            "Too many bad partitions, heapsort of [{}, {}]",
+                            start,
+                            end - 1
+                        ));
+                        heap_sort(state, array, start, end);
+                        return;
+                    }
+
+                    state.log(format!(
+                        "Unbalanced partition ({} | {}), breaking patterns",
+                        left_size, right_size
+                    ));
+
+                    if left_size >= INSERTION_THRESHOLD {
+                        array.swap(start, start + left_size / 4);
+                        array.swap(pivot - 1, pivot - left_size / 4);
+                    }
+
+                    if right_size >= INSERTION_THRESHOLD {
+                        array.swap(pivot + 1, pivot + 1 + right_size / 4);
+                        array.swap(end - 1, end - right_size / 4);
+                    }
+                } else if already_partitioned {
+                    state.log(format!(
+                        "Range [{}, {}] was already partitioned, trying partial insertion sort",
+                        start,
+                        end - 1
+                    ));
+                    state.set_bands(vec![
+                        Band::new(start, pivot, "partial insertion sort"),
+                        Band::new(pivot + 1, end - 1, "partial insertion sort"),
+                    ]);
+
+                    if partial_insertion_sort(state, array, start, pivot)
+                        && partial_insertion_sort(state, array, pivot + 1, end)
+                    {
+                        return;
+                    }
+                }
+
+                pdq_sort(state, array, start, pivot, bad_allowed, leftmost);
+
+                start = pivot + 1;
+                leftmost = false;
+            }
+        }
+
+        let len = array.len();
+
+        pdq_sort(&state, &array, 0, len, len.max(2).ilog2(), true);
+
+        state.check();
+    }
+}
@@ -0,0 +1,80 @@
+use super::{quick_sort::lomuto, Algorithm};
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+/// The index `k` a selection algorithm puts in place, a runtime-selectable
+/// variant of each of them.
+#[derive(Clone, Copy)]
+pub enum Rank {
+    Median,
+    Quintile,
+    Minimum,
+    Maximum,
+}
+
+impl Rank {
+    pub const ALL: [Rank; 4] = [Rank::Median, Rank::Quintile, Rank::Minimum, Rank::Maximum];
+
+    pub fn k(&self, len: usize) -> usize {
+        match self {
+            Rank::Median => len / 2,
+            Rank::Quintile => len / 5,
+            Rank::Minimum => 0,
+            Rank::Maximum => len - 1,
+        }
+    }
+}
+
+/// Hoare's quickselect: quicksort that only recurses into the side holding `k`.
+pub struct QuickSelect {
+    pub rank: Rank,
+}
+
+impl QuickSelect {
+    pub fn new(variant: usize) -> Self {
+        Self {
+            rank: Rank::ALL[variant % Rank::ALL.len()],
+        }
+    }
+}
+
+impl Algorithm for QuickSelect {
+    fn sort(&self, state: SharedState, array: Array) {
+        let k = self.rank.k(array.len());
+
+        state.set_speed(50);
+        state.init_algorithm(format!("Quickselect (k = {})", k));
+
+        let (mut start, mut end) = (0, array.len() - 1);
+
+        while start < end {
+            if state.interrupted() {
+                return;
+            }
+
+            state.log(format!(
+                "Quickselect: partitioning [{}, {}] around its last value to find index {}",
+                start, end, k
+            ));
+            state.set_bands(vec![Band::new(start, end, "partition")]);
+
+            let pivot = lomuto(&state, &array, start, end);
+
+            if pivot == k {
+                break;
+            } else if k < pivot {
+                end = pivot - 1;
+            } else {
+                start = pivot + 1;
+            }
+        }
+
+        state.check_selected(k);
+    }
+
+    fn variants(&self) -> usize {
+        Rank::ALL.len()
+    }
+}
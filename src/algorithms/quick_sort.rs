@@ -1,60 +1,314 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
 use super::Algorithm;
-use crate::{
-    array::Array,
-    state::{SharedState, Status},
-};
+use crate::{array::Array, state::SharedState};
 
-pub struct QuickSort;
+#[derive(Clone, Copy)]
+pub enum Pivot {
+    Lomuto,
+    Hoare,
+    MedianOfThree,
+    Random,
+    Ninther,
+    ThreeWay,
+    DualPivot,
+}
 
-impl Algorithm for QuickSort {
-    fn sort(&self, state: SharedState, array: Array) {
-        state.set_speed(75);
-        state.init_algorithm("Quick Sort".to_string());
+impl Pivot {
+    pub const ALL: [Pivot; 7] = [
+        Pivot::Lomuto,
+        Pivot::Hoare,
+        Pivot::MedianOfThree,
+        Pivot::Random,
+        Pivot::Ninther,
+        Pivot::ThreeWay,
+        Pivot::DualPivot,
+    ];
 
-        fn partition(state: &SharedState, array: &Array, start: usize, end: usize) -> usize {
-            let (mut i, mut j) = (start, start as isize - 1);
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pivot::Lomuto => "Lomuto",
+            Pivot::Hoare => "Hoare",
+            Pivot::MedianOfThree => "Median of Three",
+            Pivot::Random => "Random Pivot",
+            Pivot::Ninther => "Ninther",
+            Pivot::ThreeWay => "Three-Way",
+            Pivot::DualPivot => "Dual Pivot",
+        }
+    }
+}
 
-            while end > i {
-                match state.get_status() {
-                    Status::Paused => state.park(),
-                    _ => {}
-                };
+pub struct QuickSort {
+    pub pivot: Pivot,
+    pub seed: u64,
+}
 
-                if array.is_greater_equal(end, i) {
-                    j += 1;
+impl QuickSort {
+    pub fn new(variant: usize, seed: u64) -> Self {
+        Self {
+            pivot: Pivot::ALL[variant % Pivot::ALL.len()],
+            seed,
+        }
+    }
+}
 
-                    if i as isize != j {
-                        state.set_last(j as u32);
-                        array.swap(i, j as usize);
-                    }
-                }
+/// Index holding the median of the values at `a`, `b` and `c`.
+pub fn median_of_three(array: &Array, a: usize, b: usize, c: usize) -> usize {
+    if array.is_greater(a, b) {
+        if array.is_greater(b, c) {
+            b
+        } else if array.is_greater(a, c) {
+            c
+        } else {
+            a
+        }
+    } else if array.is_greater(a, c) {
+        a
+    } else if array.is_greater(b, c) {
+        c
+    } else {
+        b
+    }
+}
 
-                i += 1;
-                state.sleep(None);
-            }
+/// Tukey's ninther: the median of three medians of three, sampled evenly over `start..=end`.
+pub fn ninther(array: &Array, start: usize, end: usize) -> usize {
+    let len = end - start + 1;
+
+    if len < 9 {
+        return median_of_three(array, start, start + len / 2, end);
+    }
 
+    let step = len / 8;
+    let mid = start + len / 2;
+
+    median_of_three(
+        array,
+        median_of_three(array, start, start + step, start + 2 * step),
+        median_of_three(array, mid - step, mid, mid + step),
+        median_of_three(array, end - 2 * step, end - step, end),
+    )
+}
+
+/// Partitions `start..=end` around the value at `end`, returning its final index.
+pub fn lomuto(state: &SharedState, array: &Array, start: usize, end: usize) -> usize {
+    let (mut i, mut j) = (start, start as isize - 1);
+
+    while end > i {
+        if state.interrupted() {
+            return end;
+        }
+
+        if array.is_greater_equal(end, i) {
             j += 1;
 
-            state.set_last(j as u32);
-            array.swap(j as usize, end);
+            if i as isize != j {
+                state.set_last(j as u32);
+                array.swap(i, j as usize);
+            }
+        }
+
+        i += 1;
+        state.sleep(None);
+    }
+
+    j += 1;
+
+    state.set_last(j as u32);
+    array.swap(j as usize, end);
+
+    j as usize
+}
+
+/// Partitions `start..=end` around the value at `start` with two converging
+/// scans that both stop on equal keys, returning the pivot's final index.
+fn hoare(state: &SharedState, array: &Array, start: usize, end: usize) -> usize {
+    let (mut i, mut j) = (start + 1, end);
+
+    loop {
+        while i <= j && array.is_greater(start, i) {
+            i += 1;
+            state.sleep(None);
+        }
+
+        while i <= j && array.is_greater(j, start) {
+            j -= 1;
+            state.sleep(None);
+        }
+
+        if i >= j || state.interrupted() {
+            break;
+        }
+
+        state.set_last(j as u32);
+        array.swap(i, j);
+
+        i += 1;
+        j -= 1;
+        state.sleep(None);
+    }
+
+    state.set_last(j as u32);
+    array.swap(start, j);
+
+    j
+}
+
+/// Dijkstra's Dutch national flag partition around the value at `start`,
+/// returning the range holding every key equal to the pivot.
+fn three_way(state: &SharedState, array: &Array, start: usize, end: usize) -> (usize, usize) {
+    let (mut lt, mut i, mut gt) = (start, start + 1, end);
+
+    while i <= gt {
+        if state.interrupted() {
+            break;
+        }
 
-            return j as usize;
+        if array.is_greater(lt, i) {
+            state.set_last(lt as u32);
+            array.swap(lt, i);
+            lt += 1;
+            i += 1;
+        } else if array.is_greater(i, lt) {
+            state.set_last(gt as u32);
+            array.swap(i, gt);
+            gt -= 1;
+        } else {
+            i += 1;
         }
 
-        fn quick_sort(state: &SharedState, array: &Array, start: usize, end: usize) {
-            if end > start {
-                let pivot = partition(state, array, start, end);
+        state.sleep(None);
+    }
+
+    (lt, gt)
+}
+
+/// Yaroslavskiy's dual-pivot partition around the values at `start` and
+/// `end`, returning the final indexes of both pivots.
+fn dual_pivot(state: &SharedState, array: &Array, start: usize, end: usize) -> (usize, usize) {
+    if array.is_greater(start, end) {
+        array.swap(start, end);
+    }
+
+    let (mut less, mut great, mut k) = (start + 1, end - 1, start + 1);
+
+    while k <= great {
+        if state.interrupted() {
+            break;
+        }
+
+        if array.is_greater(start, k) {
+            state.set_last(less as u32);
+            array.swap(k, less);
+            less += 1;
+        } else if array.is_greater_equal(k, end) {
+            while k < great && array.is_greater(great, end) {
+                great -= 1;
+                state.sleep(None);
+            }
+
+            state.set_last(great as u32);
+            array.swap(k, great);
+            great -= 1;
+
+            if array.is_greater(start, k) {
+                array.swap(k, less);
+                less += 1;
+            }
+        }
+
+        k += 1;
+        state.sleep(None);
+    }
 
-                if pivot > 0 {
-                    quick_sort(state, array, start, pivot - 1);
+    less -= 1;
+    great += 1;
+
+    array.swap(start, less);
+    array.swap(end, great);
+
+    (less, great)
+}
+
+impl Algorithm for QuickSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(75);
+        state.init_algorithm(match self.pivot {
+            Pivot::Random => format!("Quick Sort ({}, seed {})", self.pivot.name(), self.seed),
+            _ => format!("Quick Sort ({})", self.pivot.name()),
+        });
+
+        fn quick_sort(
+            state: &SharedState,
+            array: &Array,
+            pivot: Pivot,
+            rng: &mut StdRng,
+            start: usize,
+            end: usize,
+        ) {
+            if end <= start || state.interrupted() {
+                return;
+            }
+
+            let (left, right) = match pivot {
+                Pivot::Lomuto => {
+                    let p = lomuto(state, array, start, end);
+                    (p, p)
+                }
+                Pivot::Hoare => {
+                    array.swap(start, start + (end - start) / 2);
+                    let p = hoare(state, array, start, end);
+                    (p, p)
                 }
+                Pivot::MedianOfThree | Pivot::Random | Pivot::Ninther => {
+                    let index = match pivot {
+                        Pivot::MedianOfThree => {
+                            median_of_three(array, start, start + (end - start) / 2, end)
+                        }
+                        Pivot::Ninther => ninther(array, start, end),
+                        _ => rng.random_range(start..=end),
+                    };
 
-                quick_sort(state, array, pivot + 1, end);
+                    array.swap(index, end);
+                    let p = lomuto(state, array, start, end);
+                    (p, p)
+                }
+                Pivot::ThreeWay => three_way(state, array, start, end),
+                Pivot::DualPivot => {
+                    let (less, great) = dual_pivot(state, array, start, end);
+
+                    if less + 1 < great {
+                        quick_sort(state, array, pivot, rng, less + 1, great - 1);
+                    }
+
+                    (less, great)
+                }
+            };
+
+            state.log(format!(
+                "{} partition of [{}, {}] placed the pivot at [{}, {}]",
+                pivot.name(),
+                start,
+                end,
+                left,
+                right
+            ));
+
+            if left > 0 {
+                quick_sort(state, array, pivot, rng, start, left - 1);
             }
+
+            quick_sort(state, array, pivot, rng, right + 1, end);
         }
 
-        quick_sort(&state, &array, 0, array.len() - 1);
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        quick_sort(&state, &array, self.pivot, &mut rng, 0, array.len() - 1);
 
         state.check();
     }
+
+    fn variants(&self) -> usize {
+        Pivot::ALL.len()
+    }
 }
@@ -1,8 +1,5 @@
 use super::Algorithm;
-use crate::{
-    array::Array,
-    state::{SharedState, Status},
-};
+use crate::{array::Array, state::SharedState};
 
 pub struct SelectionSort;
 
@@ -16,11 +13,9 @@ impl Algorithm for SelectionSort {
             let mut min_index = i;
 
             for j in (i + 1)..len {
-                match state.get_status() {
-                    Status::Paused => state.park(),
-                    Status::Interrupted => return,
-                    _ => {}
-                };
+                if state.interrupted() {
+                    return;
+                }
 
                 if array.is_greater(min_index, j) {
                     min_index = j;
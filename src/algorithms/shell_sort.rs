@@ -0,0 +1,160 @@
+use super::Algorithm;
+use crate::{
+    array::Array,
+    state::{Band, SharedState},
+};
+
+#[derive(Clone, Copy)]
+pub enum Gaps {
+    Shell,
+    Knuth,
+    Hibbard,
+    Sedgewick,
+    Tokuda,
+    Ciura,
+}
+
+impl Gaps {
+    pub const ALL: [Gaps; 6] = [
+        Gaps::Shell,
+        Gaps::Knuth,
+        Gaps::Hibbard,
+        Gaps::Sedgewick,
+        Gaps::Tokuda,
+        Gaps::Ciura,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Gaps::Shell => "Shell",
+            Gaps::Knuth => "Knuth",
+            Gaps::Hibbard => "Hibbard",
+            Gaps::Sedgewick => "Sedgewick",
+            Gaps::Tokuda => "Tokuda",
+            Gaps::Ciura => "Ciura",
+        }
+    }
+
+    /// The gaps used for an array of `len` values, largest first and ending in 1.
+    pub fn sequence(&self, len: usize) -> Vec<usize> {
+        let mut gaps = match self {
+            Gaps::Shell => {
+                let mut gaps = vec![];
+                let mut gap = len / 2;
+
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+
+                gaps.reverse();
+                gaps
+            }
+            Gaps::Knuth => (0..)
+                .map(|k| (3usize.pow(k) - 1) / 2)
+                .skip(1)
+                .take_while(|&gap| gap <= len.div_ceil(3).max(1))
+                .collect(),
+            Gaps::Hibbard => (1..)
+                .map(|k| (1 << k) - 1)
+                .take_while(|&gap| gap < len)
+                .collect(),
+            Gaps::Sedgewick => std::iter::once(1)
+                .chain((1..).map(|k| 4usize.pow(k) + 3 * (1 << (k - 1)) + 1))
+                .take_while(|&gap| gap < len)
+                .collect(),
+            Gaps::Tokuda => (0..)
+                .map(|k| ((9.0 * 2.25f64.powi(k) - 4.0) / 5.0).ceil() as usize)
+                .take_while(|&gap| gap < len)
+                .collect(),
+            Gaps::Ciura => {
+                // Empirically best known gaps, extended by a factor of 2.25.
+                let mut gaps = vec![1, 4, 10, 23, 57, 132, 301, 701, 1750];
+
+                while gaps[gaps.len() - 1] < len {
+                    gaps.push((gaps[gaps.len() - 1] as f64 * 2.25) as usize);
+                }
+
+                gaps.retain(|&gap| gap < len);
+                gaps
+            }
+        };
+
+        if gaps.is_empty() {
+            gaps.push(1);
+        }
+
+        gaps.reverse();
+        gaps
+    }
+}
+
+pub struct ShellSort {
+    pub gaps: Gaps,
+}
+
+impl ShellSort {
+    pub fn new(variant: usize) -> Self {
+        Self {
+            gaps: Gaps::ALL[variant % Gaps::ALL.len()],
+        }
+    }
+}
+
+impl Algorithm for ShellSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(90);
+        state.init_algorithm(format!("Shell Sort ({})", self.gaps.name()));
+
+        let len = array.len();
+        let gaps = self.gaps.sequence(len);
+
+        for &gap in &gaps {
+            // Insertion sorts each of the `gap` interleaved subsequences in turn.
+            for offset in 0..gap.min(len) {
+                state.set_bands(vec![Band::strided(
+                    offset,
+                    offset + (len - 1 - offset) / gap * gap,
+                    gap,
+                    "h-subsequence",
+                )]);
+                state.log(format!(
+                    "Shell Sort ({}): gap {} of {:?}, sorting subsequence {} of {}",
+                    self.gaps.name(),
+                    gap,
+                    gaps,
+                    offset + 1,
+                    gap
+                ));
+
+                for i in (offset + gap..len).step_by(gap) {
+                    let mut left = i;
+
+                    while left >= offset + gap && array.is_greater(left - gap, left) {
+                        if state.interrupted() {
+                            return;
+                        }
+
+                        state.set_last(u32::try_from(left - gap).unwrap());
+                        array.swap(left - gap, left);
+                        left -= gap;
+
+                        state.sleep(None);
+                    }
+
+                    if state.interrupted() {
+                        return;
+                    }
+
+                    state.sleep(None);
+                }
+            }
+        }
+
+        state.check();
+    }
+
+    fn variants(&self) -> usize {
+        Gaps::ALL.len()
+    }
+}
@@ -0,0 +1,44 @@
+use super::Algorithm;
+use crate::{
+    array::Array,
+    state::{Band, Budget, SharedState},
+};
+
+pub struct SlowSort;
+
+/// Multiply and surrender: sorts both halves of `start..=end` to find its
+/// maximum, moves it to `end`, then sorts everything before it from scratch.
+fn slow_sort(state: &SharedState, array: &Array, budget: &mut Budget, start: usize, end: usize) {
+    if start >= end || budget.interrupted(state) {
+        return;
+    }
+
+    let mid = start + (end - start) / 2;
+
+    slow_sort(state, array, budget, start, mid);
+    slow_sort(state, array, budget, mid + 1, end);
+
+    state.set_bands(vec![
+        Band::new(start, mid, "left half"),
+        Band::new(mid + 1, end, "right half"),
+    ]);
+
+    if array.is_greater(mid, end) {
+        state.set_last(u32::try_from(end).unwrap());
+        array.swap(mid, end);
+    }
+
+    state.sleep(None);
+
+    slow_sort(state, array, budget, start, end - 1);
+}
+
+impl Algorithm for SlowSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm("Slowsort".to_string());
+
+        slow_sort(&state, &array, &mut Budget::default(), 0, array.len() - 1);
+
+        state.check();
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use crate::{array::Array, state::SharedState};
+
+/// A comparator `[low, high]` leaves the smaller value at `low`, which may be
+/// the larger of the two indexes when it sorts that pair in descending order.
+pub type Comparator = [u32; 2];
+
+/// Comparators grouped into layers that touch disjoint indexes, so that every
+/// layer could run as one parallel step.
+pub struct Network {
+    pub size: usize,
+    pub layers: Vec<Vec<Comparator>>,
+}
+
+impl Network {
+    /// Places each comparator in the earliest layer after the last one touching either of its indexes.
+    pub fn new(size: usize, comparators: Vec<Comparator>) -> Self {
+        let mut depth = vec![0; size];
+        let mut layers: Vec<Vec<Comparator>> = vec![];
+
+        for comparator in comparators {
+            let [low, high] = comparator.map(|index| index as usize);
+            let layer = depth[low].max(depth[high]);
+
+            if layer == layers.len() {
+                layers.push(vec![]);
+            }
+
+            layers[layer].push(comparator);
+            depth[low] = layer + 1;
+            depth[high] = layer + 1;
+        }
+
+        Self { size, layers }
+    }
+
+    pub fn comparators(&self) -> usize {
+        self.layers.iter().map(Vec::len).sum()
+    }
+}
+
+/// Runs `network` over `array` one layer per step.
+pub fn sort(state: &SharedState, array: &Array, network: Network) {
+    let network = Arc::new(network);
+    state.set_network(Some(network.clone()));
+
+    for (depth, layer) in network.layers.iter().enumerate() {
+        if state.interrupted() {
+            return;
+        }
+
+        state.set_comparators(layer.clone());
+        state.set_depth(u32::try_from(depth + 1).unwrap());
+        state.log(format!(
+            "Layer {} of {}: {} comparators in parallel, {} in total",
+            depth + 1,
+            network.layers.len(),
+            layer.len(),
+            network.comparators()
+        ));
+
+        for &[low, high] in layer {
+            let (low, high) = (low as usize, high as usize);
+
+            if array.is_greater(low, high) {
+                state.set_last(low as u32);
+                array.swap(low, high);
+            }
+        }
+
+        state.sleep(None);
+    }
+
+    state.set_comparators(vec![]);
+}
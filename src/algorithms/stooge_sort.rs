@@ -0,0 +1,47 @@
+use super::Algorithm;
+use crate::{
+    array::Array,
+    state::{Band, Budget, SharedState},
+};
+
+pub struct StoogeSort;
+
+/// Orders the ends of `start..=end`, then sorts its first two thirds, its
+/// last two thirds and its first two thirds again.
+fn stooge_sort(state: &SharedState, array: &Array, budget: &mut Budget, start: usize, end: usize) {
+    if budget.interrupted(state) {
+        return;
+    }
+
+    if array.is_greater(start, end) {
+        state.set_last(u32::try_from(start).unwrap());
+        array.swap(start, end);
+    }
+
+    state.sleep(None);
+
+    let len = end - start + 1;
+
+    if len > 2 {
+        let third = len / 3;
+
+        for (first, last) in [
+            (start, end - third),
+            (start + third, end),
+            (start, end - third),
+        ] {
+            state.set_bands(vec![Band::new(first, last, "two thirds")]);
+            stooge_sort(state, array, budget, first, last);
+        }
+    }
+}
+
+impl Algorithm for StoogeSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.init_algorithm("Stooge Sort".to_string());
+
+        stooge_sort(&state, &array, &mut Budget::default(), 0, array.len() - 1);
+
+        state.check();
+    }
+}
@@ -0,0 +1,371 @@
+use super::{merge_sort::reverse, Algorithm};
+use crate::{
+    array::{Array, Buffer},
+    state::{Band, SharedState},
+};
+
+/// Arrays shorter than this are sorted with a single binary insertion sort.
+const MIN_MERGE: usize = 64;
+/// Consecutive wins that switch a merge into galloping mode.
+const MIN_GALLOP: usize = 7;
+
+pub struct TimSort;
+
+/// Smallest run length worth merging, so that `len / min_run` is close to a power of two.
+fn min_run(mut len: usize) -> usize {
+    let mut remainder = 0;
+
+    while len >= MIN_MERGE {
+        remainder |= len & 1;
+        len >>= 1;
+    }
+
+    len + remainder
+}
+
+struct Run {
+    start: usize,
+    len: usize,
+}
+
+struct Merger<'a> {
+    state: &'a SharedState,
+    array: &'a Array,
+    buffer: Buffer,
+    min_gallop: usize,
+}
+
+impl Merger<'_> {
+    /// Length of the run starting at `start`, reversing it in place if it is strictly descending.
+    fn count_run(&self, start: usize, end: usize) -> usize {
+        let mut run_end = start + 1;
+
+        if run_end == end {
+            return 1;
+        }
+
+        if self.array.is_greater(start, run_end) {
+            while run_end + 1 < end && self.array.is_greater(run_end, run_end + 1) {
+                run_end += 1;
+            }
+
+            reverse(self.array, start, run_end + 1);
+        } else {
+            while run_end + 1 < end && !self.array.is_greater(run_end, run_end + 1) {
+                run_end += 1;
+            }
+        }
+
+        run_end + 1 - start
+    }
+
+    /// Binary insertion sort of `start..end` where `start..sorted` is already sorted.
+    fn binary_insertion_sort(&self, start: usize, sorted: usize, end: usize) {
+        for i in sorted..end {
+            if self.state.interrupted() {
+                return;
+            }
+
+            let pivot = self.array.get(i);
+            let (mut low, mut high) = (start, i);
+
+            while low < high {
+                let mid = low + (high - low) / 2;
+
+                self.state.set_comparison([mid as u32, i as u32]);
+
+                if self.array.value_is_greater(self.array.get(mid), pivot) {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+
+            for j in (low..i).rev() {
+                self.array.set(j + 1, self.array.get(j));
+            }
+
+            self.state.set_last(low as u32);
+            self.array.set(low, pivot);
+            self.state.sleep(None);
+        }
+    }
+
+    /// Number of the `len` values yielded by `get` that are less than `key`,
+    /// or less than or equal to it when `right` is set, found by galloping
+    /// from the start and then binary searching.
+    fn gallop(&self, key: u32, len: usize, right: bool, get: impl Fn(usize) -> u32) -> usize {
+        let before = |index: usize| {
+            let value = get(index);
+
+            if right {
+                !self.array.value_is_greater(value, key)
+            } else {
+                self.array.value_is_greater(key, value)
+            }
+        };
+
+        let (mut low, mut high) = (0, 1);
+
+        while high <= len && before(high - 1) {
+            low = high;
+            high = high * 2 + 1;
+        }
+
+        let mut high = high.min(len);
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            if before(mid) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        low
+    }
+
+    /// Merges two adjacent runs from their low end, galloping when one run keeps winning.
+    fn merge(&mut self, first: &Run, second: &Run) {
+        let (state, array) = (self.state, self.array);
+
+        state.set_bands(vec![
+            Band::new(first.start, first.start + first.len - 1, "run"),
+            Band::new(second.start, second.start + second.len - 1, "run"),
+        ]);
+
+        let skip = self.gallop(array.get(second.start), first.len, true, |i| {
+            array.get(first.start + i)
+        });
+
+        let (start, mut len1) = (first.start + skip, first.len - skip);
+
+        if len1 == 0 {
+            return;
+        }
+
+        let mut len2 = self.gallop(array.get(start + len1 - 1), second.len, false, |i| {
+            array.get(second.start + i)
+        });
+
+        if len2 == 0 {
+            return;
+        }
+
+        state.log(format!(
+            "Timsort: merging runs [{}, {}] and [{}, {}]",
+            start,
+            start + len1 - 1,
+            second.start,
+            second.start + len2 - 1
+        ));
+
+        for i in 0..len1 {
+            self.buffer.set(i, array.get(start + i));
+        }
+
+        let (mut cursor1, mut cursor2, mut dest) = (0, second.start, start);
+
+        'merge: while len1 > 0 && len2 > 0 {
+            let (mut wins1, mut wins2) = (0, 0);
+
+            while wins1 < self.min_gallop && wins2 < self.min_gallop {
+                if state.interrupted() {
+                    return;
+                }
+
+                state.set_comparison([(start + cursor1) as u32, cursor2 as u32]);
+
+                if array.value_is_greater(self.buffer.get(cursor1), array.get(cursor2)) {
+                    array.set(dest, array.get(cursor2));
+                    cursor2 += 1;
+                    len2 -= 1;
+                    wins2 += 1;
+                    wins1 = 0;
+                } else {
+                    array.set(dest, self.buffer.get(cursor1));
+                    cursor1 += 1;
+                    len1 -= 1;
+                    wins1 += 1;
+                    wins2 = 0;
+                }
+
+                state.set_last(dest as u32);
+                dest += 1;
+                state.sleep(None);
+
+                if len1 == 0 || len2 == 0 {
+                    break 'merge;
+                }
+            }
+
+            state.log(format!(
+                "Timsort: galloping (min gallop {}) in [{}, {}]",
+                self.min_gallop,
+                start,
+                second.start + second.len - 1
+            ));
+
+            loop {
+                let buffer = &self.buffer;
+                let count1 =
+                    self.gallop(array.get(cursor2), len1, true, |i| buffer.get(cursor1 + i));
+
+                for _ in 0..count1 {
+                    array.set(dest, self.buffer.get(cursor1));
+                    cursor1 += 1;
+                    dest += 1;
+                }
+
+                len1 -= count1;
+
+                if len1 == 0 {
+                    break 'merge;
+                }
+
+                array.set(dest, array.get(cursor2));
+                cursor2 += 1;
+                dest += 1;
+                len2 -= 1;
+
+                if len2 == 0 {
+                    break 'merge;
+                }
+
+                let key = self.buffer.get(cursor1);
+                let count2 = self.gallop(key, len2, false, |i| array.get(cursor2 + i));
+
+                for _ in 0..count2 {
+                    array.set(dest, array.get(cursor2));
+                    cursor2 += 1;
+                    dest += 1;
+                }
+
+                len2 -= count2;
+
+                if len2 == 0 {
+                    break 'merge;
+                }
+
+                array.set(dest, self.buffer.get(cursor1));
+                cursor1 += 1;
+                dest += 1;
+                len1 -= 1;
+
+                state.set_last(dest as u32);
+                state.sleep(None);
+
+                if len1 == 0 || state.interrupted() {
+                    break 'merge;
+                }
+
+                self.min_gallop = self.min_gallop.saturating_sub(1).max(1);
+
+                if count1 < MIN_GALLOP && count2 < MIN_GALLOP {
+                    break;
+                }
+            }
+
+            self.min_gallop += 2;
+        }
+
+        for _ in 0..len1 {
+            array.set(dest, self.buffer.get(cursor1));
+            cursor1 += 1;
+            dest += 1;
+        }
+    }
+
+    /// Merges the runs at `index` and `index + 1` of the stack.
+    fn merge_at(&mut self, runs: &mut Vec<Run>, index: usize) {
+        let second = runs.remove(index + 1);
+        self.merge(&runs[index], &second);
+        runs[index].len += second.len;
+    }
+
+    /// Restores the stack invariants `|Z| > |Y| + |X|` and `|Y| > |X|`.
+    fn merge_collapse(&mut self, runs: &mut Vec<Run>) {
+        while runs.len() > 1 {
+            let mut n = runs.len() - 2;
+
+            if (n > 0 && runs[n - 1].len <= runs[n].len + runs[n + 1].len)
+                || (n > 1 && runs[n - 2].len <= runs[n - 1].len + runs[n].len)
+            {
+                if runs[n - 1].len < runs[n + 1].len {
+                    n -= 1;
+                }
+            } else if runs[n].len > runs[n + 1].len {
+                break;
+            }
+
+            self.merge_at(runs, n);
+        }
+    }
+}
+
+impl Algorithm for TimSort {
+    fn sort(&self, state: SharedState, array: Array) {
+        state.set_speed(75);
+        state.init_algorithm("Timsort".to_string());
+
+        let len = array.len();
+        let min_run = min_run(len);
+
+        let mut merger = Merger {
+            state: &state,
+            array: &array,
+            buffer: Buffer::new(state.clone(), len),
+            min_gallop: MIN_GALLOP,
+        };
+
+        let mut runs: Vec<Run> = vec![];
+        let mut start = 0;
+
+        while start < len {
+            if state.interrupted() {
+                return;
+            }
+
+            let mut run_len = merger.count_run(start, len);
+
+            if run_len < min_run {
+                let forced = min_run.min(len - start);
+
+                state.log(format!(
+                    "Timsort: extending natural run of {} at {} to min run {} with binary insertion",
+                    run_len, start, forced
+                ));
+                state.set_bands(vec![Band::new(
+                    start,
+                    start + forced - 1,
+                    "binary insertion",
+                )]);
+
+                merger.binary_insertion_sort(start, start + run_len, start + forced);
+                run_len = forced;
+            }
+
+            runs.push(Run {
+                start,
+                len: run_len,
+            });
+            merger.merge_collapse(&mut runs);
+
+            start += run_len;
+        }
+
+        while runs.len() > 1 {
+            let mut n = runs.len() - 2;
+
+            if n > 0 && runs[n - 1].len < runs[n + 1].len {
+                n -= 1;
+            }
+
+            merger.merge_at(&mut runs, n);
+        }
+
+        state.check();
+    }
+}
@@ -1,37 +1,70 @@
 use std::{
+    cmp::Ordering,
     sync::mpsc,
     thread::{self, JoinHandle},
 };
 
-use rand::prelude::*;
-
 use crossterm::{event, style::Color};
+use rand::Rng;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Style, Stylize},
-    text::Line,
-    widgets::{Bar, BarChart, BarGroup, Block, BorderType, Borders, List, Paragraph, Wrap},
+    symbols,
+    text::{Line, Span},
+    widgets::{
+        canvas::{self, Canvas},
+        Axis, Bar, BarChart, BarGroup, Block, BorderType, Borders, Chart, Dataset, GraphType, List,
+        Paragraph, Wrap,
+    },
     Frame,
 };
 
 use crate::{
-    algorithms::{
-        bubble_sort::BubbleSort, insertion_sort::InsertionSort, merge_sort::MergeSort,
-        quick_sort::QuickSort, selection_sort::SelectionSort, Algorithm,
-    },
+    algorithms::{self, sorting_network::Network, Algorithm},
     array::Array,
-    state::{SharedState, State, Status},
+    bench::Distribution,
+    complexity::{self, SharedAnalysis},
+    metrics::Metrics,
+    modes::{self, Mode},
+    search::Search,
+    shuffle::{self, SharedBias, Shuffle},
+    state::{Band, SharedState, State, Status},
 };
 
 pub enum Event {
     Input(event::KeyEvent),
 }
 
+pub const ARRAY_LEN: u32 = 150;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum View {
+    Array,
+    Complexity,
+    Network,
+    Bias,
+}
+
 pub struct App {
     pub exit: bool,
     pub state: SharedState,
+    pub view: View,
+    pub distribution: Distribution,
+    analysis: SharedAnalysis,
+    bias: SharedBias,
     algorithm_handle: Option<JoinHandle<()>>,
     algorithm_index: i8,
+    variant_index: usize,
+    /// Seed of the algorithms that make random choices, such as a random pivot.
+    seed: u64,
+    search: Search,
+    target: u32,
+    shuffle: Shuffle,
+    modes: Vec<Box<dyn Mode>>,
+    /// Index into `modes` plus one, sorting being mode zero.
+    mode_index: usize,
+    /// Sortedness of the array it was worked out for, redone once that changes.
+    metrics: (Vec<u32>, Metrics),
 }
 
 impl App {
@@ -39,8 +72,20 @@ impl App {
         Self {
             exit: false,
             state: SharedState::new(State::new(array)),
+            view: View::Array,
+            distribution: Distribution::Random,
+            analysis: SharedAnalysis::default(),
+            bias: SharedBias::default(),
             algorithm_handle: None,
             algorithm_index: 0,
+            variant_index: 0,
+            seed: u64::from(rand::rng().random::<u32>()),
+            search: Search::Binary,
+            target: ARRAY_LEN / 2,
+            shuffle: Shuffle::FisherYates,
+            modes: modes::all(),
+            mode_index: 0,
+            metrics: (vec![], Metrics::default()),
         }
     }
 
@@ -49,9 +94,8 @@ impl App {
         let mut state = App::new(array);
 
         thread::spawn(move || loop {
-            match event::read().unwrap() {
-                event::Event::Key(key_event) => tx.send(Event::Input(key_event)).unwrap(),
-                _ => {}
+            if let event::Event::Key(key_event) = event::read().unwrap() {
+                tx.send(Event::Input(key_event)).unwrap();
             }
         });
 
@@ -62,7 +106,25 @@ impl App {
 
     pub fn handle_input(&mut self, key_event: event::KeyEvent) {
         if key_event.kind == event::KeyEventKind::Press {
+            let editing = self.current_mode().is_some_and(|mode| mode.editing());
+            let global = !editing
+                && matches!(
+                    key_event.code,
+                    event::KeyCode::Char('q' | 'p' | 'k' | 'j') | event::KeyCode::Tab
+                );
+
+            if let Some(mode) = self.current_mode() {
+                if !global {
+                    if mode.handle_key(key_event.code) {
+                        self.start_mode();
+                    }
+
+                    return;
+                }
+            }
+
             match key_event.code {
+                event::KeyCode::Tab => self.handle_modes(),
                 event::KeyCode::Char('k') => {
                     self.state.increment_speed();
                 }
@@ -71,29 +133,106 @@ impl App {
                 }
                 event::KeyCode::Char('h') => self.handle_algorithms(-1),
                 event::KeyCode::Char('l') => self.handle_algorithms(1),
+                event::KeyCode::Char('v') => self.handle_variants(),
+                event::KeyCode::Char('g') => {
+                    self.seed = u64::from(rand::rng().random::<u32>());
+                    self.handle_algorithms(0);
+                }
+                event::KeyCode::Char('d') => self.handle_distributions(),
                 event::KeyCode::Char('r') => self.handle_algorithms(0),
                 event::KeyCode::Char('q') => self.exit = true,
-                event::KeyCode::Char('p') => {
-                    if let Some(ref handle) = self.algorithm_handle {
-                        let status = self.state.get_status();
-
-                        if let Status::Paused = status {
-                            self.state.set_status(Status::Running);
-                            handle.thread().unpark();
-                        };
-
-                        if let Status::Running = status {
-                            self.state.set_status(Status::Paused);
-                        };
-                    }
+                event::KeyCode::Char('c') => self.toggle_view(View::Complexity),
+                event::KeyCode::Char('n') => self.toggle_view(View::Network),
+                event::KeyCode::Char('b') => self.toggle_view(View::Bias),
+                event::KeyCode::Char('u') => self.start_shuffle(),
+                event::KeyCode::Char('y') => self.handle_shuffles(),
+                event::KeyCode::Char('s') => self.start_search(),
+                event::KeyCode::Char('f') => self.handle_searches(),
+                event::KeyCode::Char('+') | event::KeyCode::Char('=') => {
+                    self.target = (self.target + 1).min(ARRAY_LEN);
+                }
+                event::KeyCode::Char('-') => self.target = self.target.saturating_sub(1).max(1),
+                event::KeyCode::Char('p') if self.algorithm_handle.is_some() => {
+                    let status = self.state.get_status();
+
+                    if let Status::Paused = status {
+                        self.state.set_status(Status::Running);
+                    };
+
+                    if let Status::Running = status {
+                        self.state.set_status(Status::Paused);
+                    };
                 }
                 _ => {}
             }
         }
     }
 
+    fn current_mode(&mut self) -> Option<&mut Box<dyn Mode>> {
+        self.mode_index
+            .checked_sub(1)
+            .map(|index| &mut self.modes[index])
+    }
+
+    pub fn handle_modes(&mut self) {
+        if self.view != View::Array {
+            self.toggle_view(self.view);
+        }
+
+        if self.mode_index == 0 {
+            self.interrupt_algorithm();
+        }
+
+        self.mode_index = (self.mode_index + 1) % (self.modes.len() + 1);
+
+        match self.mode_index {
+            0 => self.handle_algorithms(0),
+            _ => {
+                let speed = self.modes[self.mode_index - 1].speed();
+                self.state.set_speed(speed);
+                self.start_mode();
+            }
+        }
+    }
+
+    /// Restarts the current mode's algorithm once the previous one has
+    /// stopped, since both would share the mode's data, at the same speed.
+    pub fn start_mode(&mut self) {
+        let speed = self.state.get_speed();
+
+        let panicked = match self.algorithm_handle.take() {
+            Some(handle) => {
+                self.state.set_status(Status::Interrupted);
+                handle.join().is_err()
+            }
+            None => false,
+        };
+
+        self.state = SharedState::new(State {
+            speed,
+            ..State::new(vec![])
+        });
+
+        // A run that panicked only takes itself down, not the app.
+        if panicked {
+            self.state
+                .log("The previous run stopped after a panic".to_string());
+        }
+
+        let state = self.state.clone();
+
+        if let Some(mode) = self.current_mode() {
+            let handle = mode.start(state);
+            self.algorithm_handle = Some(handle);
+        }
+    }
+
     pub fn handle_algorithms(&mut self, increment: i8) {
-        let algorithm_count = 5;
+        let algorithm_count = algorithms::all(0, 0).len() as i8;
+
+        if increment != 0 {
+            self.variant_index = 0;
+        }
 
         if increment <= 0 && self.algorithm_index == 0 {
             self.algorithm_index = algorithm_count;
@@ -101,19 +240,56 @@ impl App {
             self.algorithm_index += increment;
         }
 
-        match self.algorithm_index % algorithm_count {
-            1 => self.start_algorithm(Box::new(SelectionSort)),
-            2 => self.start_algorithm(Box::new(InsertionSort)),
-            3 => self.start_algorithm(Box::new(MergeSort)),
-            4 => self.start_algorithm(Box::new(QuickSort)),
-            _ => self.start_algorithm(Box::new(BubbleSort)),
+        self.start_algorithm(self.current_algorithm());
+
+        if let View::Complexity = self.view {
+            self.start_analysis();
         }
     }
 
+    pub fn handle_variants(&mut self) {
+        let variants = self.current_algorithm().variants();
+        self.variant_index = (self.variant_index + 1) % variants;
+        self.handle_algorithms(0);
+    }
+
+    pub fn current_algorithm(&self) -> Box<dyn Algorithm + Send + Sync> {
+        let mut algorithms = algorithms::all(self.variant_index, self.seed);
+        let index = self.algorithm_index % algorithms.len() as i8;
+        algorithms.swap_remove(index as usize)
+    }
+
+    pub fn toggle_view(&mut self, view: View) {
+        match self.view {
+            View::Complexity => self.analysis.cancel(),
+            View::Bias => self.bias.cancel(),
+            _ => {}
+        }
+
+        self.view = if self.view == view { View::Array } else { view };
+
+        match self.view {
+            View::Complexity => self.start_analysis(),
+            View::Bias => self.start_bias(),
+            _ => {}
+        }
+    }
+
+    pub fn start_analysis(&mut self) {
+        let analysis = SharedAnalysis::default();
+        let algorithm = self.current_algorithm();
+
+        self.analysis.cancel();
+        self.analysis = analysis.clone();
+        thread::spawn(move || analysis.run(algorithm.as_ref()));
+    }
+
     pub fn interrupt_algorithm(&mut self) {
-        if let Some(_) = self.algorithm_handle {
+        if self.algorithm_handle.is_some() {
             self.state.set_status(Status::Interrupted);
-            self.state = SharedState::new(State::new(App::generate_array()));
+            self.state = SharedState::new(State::new(
+                self.distribution.generate(ARRAY_LEN, &mut rand::rng()),
+            ));
             self.algorithm_handle = None;
         }
     }
@@ -130,35 +306,125 @@ impl App {
         self.algorithm_handle = Some(algorithm_handle);
     }
 
+    /// Searches the sorted array for the target, once the sort has completed.
+    pub fn start_search(&mut self) {
+        if !matches!(self.state.get_status(), Status::Completed) {
+            return;
+        }
+
+        let (search, target) = (self.search, self.target);
+        let state = self.state.clone();
+
+        self.algorithm_handle = Some(thread::spawn(move || {
+            search.run(state.clone(), Array::new(state), target)
+        }));
+    }
+
+    pub fn handle_searches(&mut self) {
+        let index = Search::ALL
+            .iter()
+            .position(|search| *search == self.search)
+            .unwrap_or(0);
+
+        self.search = Search::ALL[(index + 1) % Search::ALL.len()];
+    }
+
+    /// Shuffles a sorted array with the selected shuffle, then sorts it again.
+    pub fn start_shuffle(&mut self) {
+        if self.algorithm_handle.is_some() {
+            self.state.set_status(Status::Interrupted);
+        }
+
+        self.state = SharedState::new(State::new((1..=ARRAY_LEN).collect()));
+
+        let (shuffle, algorithm, state) =
+            (self.shuffle, self.current_algorithm(), self.state.clone());
+
+        self.algorithm_handle = Some(thread::spawn(move || {
+            let array = Array::new(state.clone());
+            let speed = state.get_speed();
+
+            state.set_algorithm(format!("{} Shuffle", shuffle.name()));
+            state.set_speed(60);
+            shuffle.run(&state, &array, &mut rand::rng());
+
+            if state.interrupted() {
+                return;
+            }
+
+            state.set_speed(speed);
+            state.reset_counters();
+            algorithm.sort(state, array);
+        }));
+    }
+
+    pub fn handle_shuffles(&mut self) {
+        let index = Shuffle::ALL
+            .iter()
+            .position(|shuffle| *shuffle == self.shuffle)
+            .unwrap_or(0);
+
+        self.shuffle = Shuffle::ALL[(index + 1) % Shuffle::ALL.len()];
+
+        if let View::Bias = self.view {
+            self.start_bias();
+        }
+    }
+
+    pub fn start_bias(&mut self) {
+        let bias = SharedBias::default();
+        let shuffle = self.shuffle;
+
+        self.bias.cancel();
+        self.bias = bias.clone();
+        thread::spawn(move || bias.run(shuffle));
+    }
+
     pub fn generate_array() -> Vec<u32> {
-        let mut rng = rand::rng();
-        let mut nums: Vec<u32> = (1..151).collect();
-        nums.shuffle(&mut rng);
-        nums
+        Distribution::Random.generate(ARRAY_LEN, &mut rand::rng())
+    }
+
+    pub fn handle_distributions(&mut self) {
+        let index = Distribution::ALL
+            .iter()
+            .position(|d| *d == self.distribution)
+            .unwrap_or(0);
+
+        self.distribution = Distribution::ALL[(index + 1) % Distribution::ALL.len()];
+        self.handle_algorithms(0);
     }
 
-    pub fn draw(&self, frame: &mut Frame) {
+    pub fn draw(&mut self, frame: &mut Frame) {
+        if self.mode_index > 0 {
+            return self.draw_mode(frame);
+        }
+
         let State {
             array,
             last_swapped,
             comparison,
+            comparators,
+            network,
+            depth,
             checked,
             array_accesses,
             comparisons,
+            writes,
+            probes,
+            bands,
+            workers,
             status,
             algorithm,
             log,
             speed,
-        } = self.state.get().clone();
+            ..
+        } = self.state.snapshot();
 
-        let layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(vec![
-                Constraint::Percentage(20),
-                Constraint::Percentage(60),
-                Constraint::Percentage(20),
-            ])
-            .split(frame.area());
+        if self.metrics.0 != array {
+            self.metrics = (array.clone(), Metrics::compute(&array));
+        }
+
+        let metrics = self.metrics.1.clone();
 
         let graph_layout = centered_rect(80, 55, frame.area());
 
@@ -169,6 +435,8 @@ impl App {
         let completed_style = Style::new().green();
         let comparison_style = Style::new().red();
         let normal_style = Style::new().white();
+        let band_colors = [Color::Blue, Color::Magenta, Color::Cyan, Color::Yellow];
+        let worker_colors = [Color::Cyan, Color::Magenta, Color::Yellow, Color::Blue];
 
         let style = match status {
             Status::Completed => completed_style,
@@ -194,10 +462,36 @@ impl App {
                 } else if i == last_swapped as usize || checked.contains(&u32::try_from(i).unwrap())
                 {
                     completed_bar
-                } else if comparison.contains(&u32::try_from(i).unwrap()) {
+                } else if let Some(worker) = workers.iter().position(|worker| {
+                    worker
+                        .comparison
+                        .is_some_and(|pair| pair.contains(&u32::try_from(i).unwrap()))
+                }) {
+                    let worker_style =
+                        Style::new().fg(worker_colors[worker % worker_colors.len()].into());
+
+                    Bar::default()
+                        .style(worker_style)
+                        .value_style(worker_style.reversed())
+                        .value(u64::from(*n))
+                } else if comparison.contains(&u32::try_from(i).unwrap())
+                    || comparators
+                        .iter()
+                        .any(|comparator| comparator.contains(&u32::try_from(i).unwrap()))
+                {
                     comparison_bar
                 } else if let Status::Failed = status {
                     comparison_bar
+                } else if let Some(band) = bands
+                    .iter()
+                    .position(|band| band.contains(u32::try_from(i).unwrap()))
+                {
+                    let band_style = Style::new().fg(band_colors[band % band_colors.len()].into());
+
+                    Bar::default()
+                        .style(band_style)
+                        .value_style(band_style.reversed())
+                        .value(u64::from(*n))
                 } else {
                     Bar::default()
                         .style(style)
@@ -207,8 +501,47 @@ impl App {
             })
             .collect();
 
+        // Labels shared by more bands than there are colours are listed once with a count.
+        let shared = |band: &Band| {
+            bands
+                .iter()
+                .filter(|other| other.label == band.label)
+                .count()
+        };
+
+        let legend: Vec<Span> = bands
+            .iter()
+            .enumerate()
+            .filter(|(i, band)| {
+                shared(band) <= band_colors.len()
+                    || !bands[..*i].iter().any(|other| other.label == band.label)
+            })
+            .map(|(i, band)| {
+                let stride = match band.stride {
+                    1 => String::new(),
+                    stride => format!(" step {}", stride),
+                };
+
+                let text = match shared(band) {
+                    count if count > band_colors.len() => format!(" {} x{} ", band.label, count),
+                    _ => format!(" {} [{}, {}]{} ", band.label, band.start, band.end, stride),
+                };
+
+                text.fg(band_colors[i % band_colors.len()])
+            })
+            .chain(workers.iter().enumerate().map(|(i, worker)| {
+                format!(
+                    " Thread {}: {} comparisons, {} writes ",
+                    i + 1,
+                    worker.comparisons,
+                    worker.writes
+                )
+                .fg(worker_colors[i % worker_colors.len()])
+            }))
+            .collect();
+
         let barchart = BarChart::default()
-            .block(block)
+            .block(block.title_bottom(Line::from(legend).centered()))
             .bar_width(1)
             .bar_gap(0)
             .bar_style(style)
@@ -216,68 +549,433 @@ impl App {
             .label_style(style)
             .data(BarGroup::default().bars(bars.as_slice()));
 
-        let layout_inner = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(vec![
-                Constraint::Percentage(1),
-                Constraint::Percentage(15),
-                Constraint::Percentage(73),
-                Constraint::Percentage(10),
-                Constraint::Percentage(1),
-            ])
-            .split(layout[0]);
-
-        let overview_block = Block::new()
-            .title(Line::raw(" Overview ").centered())
-            .borders(Borders::TOP);
-        let overview_rect = overview_block.inner(layout_inner[1]);
-
-        let help_block = Block::new()
-            .title(Line::raw(" Help ").centered())
-            .borders(Borders::TOP);
-        let help_rect = help_block.inner(layout_inner[3]);
-
-        let title_block = Block::new()
-            .title(Line::raw(" algorithm-tui ").bold().centered())
-            .borders(Borders::TOP);
-        let title_rect = overview_block.inner(layout_inner[2]);
-
-        let (status_text, status_color) = match status {
-            Status::Completed => ("Completed", Color::Green),
-            Status::Paused => ("Paused", Color::Yellow),
-            Status::Running => ("Running", Color::White),
-            Status::Interrupted => ("Interrupted", Color::Red),
-            Status::Checking => ("Checking", Color::Yellow),
-            Status::Failed => ("Failed", Color::Red),
-        };
+        let (status_text, status_color) = status_display(status);
 
-        let overview = List::new(Line::from(vec![
-            format!("Algorithm: {}", algorithm).fg(Color::Green).into(),
+        let mut overview: Vec<Line> = Line::from(vec![
+            format!("Algorithm: {}", algorithm).fg(Color::Green),
             format!("Total Numbers: {}", array.len()).into(),
+            format!("Input: {}", self.distribution.name()).into(),
+            format!("Seed: {}", self.seed).into(),
             format!("Array Accesses: {}", array_accesses).into(),
             format!("Comparisons: {}", comparisons).into(),
+            format!("Writes: {}", writes).into(),
+            format!("Probes: {}", probes).into(),
+            format!("Search: {} for {}", self.search.name(), self.target).into(),
+            format!("Shuffle: {}", self.shuffle.name()).into(),
+            format!("Depth: {}", depth).into(),
             format!("Speed: {}%", speed).into(),
-            format!("Status: {}", status_text).fg(status_color).into(),
-        ]));
-
-        let help = List::new(Line::from(vec![
+            format!("Status: {}", status_text).fg(status_color),
+        ])
+        .into_iter()
+        .map(Line::from)
+        .collect();
+
+        overview.extend(
+            [
+                format!("Inversions: {}", metrics.inversions),
+                format!("Runs: {}", metrics.runs),
+                format!("LIS: {}", metrics.longest_increasing),
+                format!("Max Displacement: {}", metrics.max_displacement),
+                format!("Avg Displacement: {:.2}", metrics.average_displacement),
+                format!("Spearman: {:.3}", metrics.spearman),
+            ]
+            .map(Line::from),
+        );
+
+        let help: Vec<Line> = Line::from(vec![
             "Quit: <Q>".into(),
             "Pause/Resume: <P>".into(),
+            "Mode: <Tab>".into(),
             "Reset: <R>".into(),
             "Next: <L>".into(),
             "Previous: <H>".into(),
+            "Variant: <V>".into(),
+            "New Seed: <G>".into(),
+            "Input: <D>".into(),
             "Increase Speed: <K>".into(),
             "Decrease Speed: <J>".into(),
-        ]));
+            "Complexity: <C>".into(),
+            "Network: <N>".into(),
+            "Search: <S>".into(),
+            "Search Algorithm: <F>".into(),
+            "Search Value: <+/->".into(),
+            "Shuffle: <U>".into(),
+            "Shuffle Algorithm: <Y>".into(),
+            "Shuffle Bias: <B>".into(),
+        ])
+        .into_iter()
+        .map(Line::from)
+        .collect();
+
+        match self.view {
+            View::Array => frame.render_widget(barchart, graph_layout),
+            View::Complexity => self.draw_complexity(frame, graph_layout),
+            View::Network => draw_network(frame, graph_layout, network.as_deref(), depth),
+            View::Bias => self.draw_bias(frame, graph_layout),
+        }
+
+        draw_panels(frame, overview, help, log);
+    }
+
+    fn draw_mode(&self, frame: &mut Frame) {
+        let mode = &self.modes[self.mode_index - 1];
+        let state = self.state.snapshot();
+        let (status_text, status_color) = status_display(state.status);
+
+        let mut overview = vec![
+            Line::from(format!("Mode: {}", mode.name())),
+            Line::from(format!("Algorithm: {}", state.algorithm).fg(Color::Green)),
+        ];
+        overview.extend(mode.overview(&state));
+        overview.extend([
+            Line::from(format!("Speed: {}%", state.speed)),
+            Line::from(format!("Status: {}", status_text).fg(status_color)),
+        ]);
+
+        let mut help: Vec<Line> = [
+            "Quit: <Q>",
+            "Pause/Resume: <P>",
+            "Mode: <Tab>",
+            "Increase Speed: <K>",
+            "Decrease Speed: <J>",
+        ]
+        .map(Line::from)
+        .into();
+        help.extend(mode.help());
+
+        mode.draw(frame, centered_rect(80, 55, frame.area()), &state);
+        draw_panels(frame, overview, help, state.log.clone());
+    }
+
+    fn draw_complexity(&self, frame: &mut Frame, area: Rect) {
+        let analysis = self.analysis.get();
+
+        let colors = [
+            Color::Red,
+            Color::Yellow,
+            Color::Cyan,
+            Color::Green,
+            Color::Magenta,
+        ];
 
-        frame.render_widget(barchart, graph_layout);
-        frame.render_widget(overview.block(overview_block), overview_rect);
-        frame.render_widget(help.block(help_block), help_rect);
-        frame.render_widget(title_block, title_rect);
+        let points: Vec<Vec<(f64, f64)>> = analysis
+            .series
+            .iter()
+            .map(|series| {
+                series
+                    .comparisons()
+                    .iter()
+                    .filter(|(_, y)| *y > 0.0)
+                    .map(|(n, y)| (n.log2(), y.log2()))
+                    .collect()
+            })
+            .collect();
 
-        if let Some(text) = log {
-            frame.render_widget(Paragraph::new(text).wrap(Wrap { trim: true }), layout[2]);
+        let max_y = points
+            .iter()
+            .flatten()
+            .map(|(_, y)| y.ceil())
+            .fold(1.0, f64::max);
+
+        let datasets: Vec<Dataset> = analysis
+            .series
+            .iter()
+            .zip(&points)
+            .zip(colors)
+            .map(|((series, points), color)| {
+                Dataset::default()
+                    .name(series.distribution.name())
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::new().fg(color.into()))
+                    .data(points)
+            })
+            .collect();
+
+        let min_x = f64::from(complexity::SIZES[0]).log2();
+        let max_x = f64::from(complexity::SIZES[complexity::SIZES.len() - 1]).log2();
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::new()
+                    .title(Line::raw(" Comparisons (log-log) ").centered())
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Thick),
+            )
+            .x_axis(
+                Axis::default()
+                    .title("n")
+                    .bounds([min_x, max_x])
+                    .labels([min_x, (min_x + max_x) / 2.0, max_x].map(|x| format!("2^{}", x))),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("cost")
+                    .bounds([0.0, max_y])
+                    .labels([0.0, max_y / 2.0, max_y].map(|y| format!("2^{}", y))),
+            );
+
+        let mut fits = vec![Line::from(
+            format!(
+                "{}{}",
+                analysis.algorithm,
+                if analysis.done { "" } else { " (measuring...)" }
+            )
+            .fg(Color::Green),
+        )];
+
+        for (series, color) in analysis.series.iter().zip(colors) {
+            fits.push(Line::from(series.distribution.name().fg(color).bold()));
+
+            for (label, points) in [
+                ("Comparisons", series.comparisons()),
+                ("Accesses", series.array_accesses()),
+            ] {
+                if let Some(best) = complexity::fit(&points).first() {
+                    let exponent = complexity::power_law(&points)
+                        .map(|law| format!(", n^{:.2}", law.exponent))
+                        .unwrap_or_default();
+
+                    fits.push(Line::from(format!(
+                        "  {}: {:.2}·{} (±{:.2}{})",
+                        label,
+                        best.coefficient,
+                        best.model.name(),
+                        best.error,
+                        exponent
+                    )));
+                }
+            }
         }
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(area);
+
+        frame.render_widget(chart, columns[0]);
+        frame.render_widget(
+            Paragraph::new(fits).block(
+                Block::new()
+                    .title(Line::raw(" Best Fit ").centered())
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Thick),
+            ),
+            columns[1],
+        );
+    }
+
+    fn draw_bias(&self, frame: &mut Frame, area: Rect) {
+        let bias = self.bias.get();
+        let len = bias.counts.len();
+        let expected = f64::from(bias.trials) / len.max(1) as f64;
+
+        let block = Block::new()
+            .title(
+                Line::raw(format!(
+                    " Shuffle Bias: {} ({} of {} shuffles{}) ",
+                    bias.shuffle.map_or("", |shuffle| shuffle.name()),
+                    bias.trials,
+                    shuffle::BIAS_TRIALS,
+                    if bias.done { "" } else { ", measuring..." }
+                ))
+                .centered(),
+            )
+            .title_bottom(
+                Line::from(vec![
+                    " rows: position, columns: value ".into(),
+                    " below expected ".fg(Color::Blue),
+                    " as expected ".fg(Color::White),
+                    " above expected ".fg(Color::Red),
+                ])
+                .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if len == 0 || bias.trials == 0 {
+            return;
+        }
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Ratio(1, len as u32); len])
+            .split(inner);
+
+        for (counts, row) in bias.counts.iter().zip(rows.iter()) {
+            let cells = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(vec![Constraint::Ratio(1, len as u32); len])
+                .split(*row);
+
+            for (&count, cell) in counts.iter().zip(cells.iter()) {
+                // Deviation from the uniform count, saturating at ±50%.
+                let deviation = ((f64::from(count) / expected - 1.0) * 2.0).clamp(-1.0, 1.0);
+                let fade = (255.0 * (1.0 - deviation.abs())) as u8;
+                let background = if deviation < 0.0 {
+                    Color::Rgb {
+                        r: fade,
+                        g: fade,
+                        b: 255,
+                    }
+                } else {
+                    Color::Rgb {
+                        r: 255,
+                        g: fade,
+                        b: fade,
+                    }
+                };
+
+                frame.render_widget(
+                    Paragraph::new(format!(
+                        "{:.1}%",
+                        100.0 * f64::from(count) / f64::from(bias.trials)
+                    ))
+                    .centered()
+                    .style(Style::new().bg(background.into()).fg(Color::Black.into())),
+                    *cell,
+                );
+            }
+        }
+    }
+}
+
+fn draw_network(frame: &mut Frame, area: Rect, network: Option<&Network>, depth: u32) {
+    let block = Block::new()
+        .title(Line::raw(" Sorting Network ").centered())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Thick);
+
+    let Some(network) = network else {
+        frame.render_widget(
+            Paragraph::new("The current algorithm is not a sorting network").block(block),
+            area,
+        );
+        return;
+    };
+
+    let layers = network.layers.len() as f64;
+    let size = network.size as f64;
+    let current = depth as usize;
+
+    let canvas = Canvas::default()
+        .block(block)
+        .marker(symbols::Marker::Braille)
+        .x_bounds([0.0, layers + 1.0])
+        .y_bounds([0.0, size])
+        .paint(move |ctx| {
+            for wire in 0..network.size {
+                let y = size - wire as f64 - 0.5;
+
+                ctx.draw(&canvas::Line {
+                    x1: 0.0,
+                    y1: y,
+                    x2: layers + 1.0,
+                    y2: y,
+                    color: Color::DarkGrey.into(),
+                });
+            }
+
+            ctx.layer();
+
+            for (layer, comparators) in network.layers.iter().enumerate() {
+                let color = match (layer + 1).cmp(&current) {
+                    Ordering::Less => Color::Green,
+                    Ordering::Equal => Color::Red,
+                    Ordering::Greater => Color::White,
+                };
+
+                for &[low, high] in comparators {
+                    ctx.draw(&canvas::Line {
+                        x1: layer as f64 + 1.0,
+                        y1: size - f64::from(low) - 0.5,
+                        x2: layer as f64 + 1.0,
+                        y2: size - f64::from(high) - 0.5,
+                        color: color.into(),
+                    });
+                }
+            }
+        });
+
+    frame.render_widget(canvas, area);
+}
+
+/// Label and colour a status is shown with.
+fn status_display(status: Status) -> (&'static str, Color) {
+    match status {
+        Status::Completed => ("Completed", Color::Green),
+        Status::Paused => ("Paused", Color::Yellow),
+        Status::Running => ("Running", Color::White),
+        Status::Interrupted => ("Interrupted", Color::Red),
+        Status::Checking => ("Checking", Color::Yellow),
+        Status::Failed => ("Failed", Color::Red),
+        Status::GaveUp => ("Gave up", Color::Magenta),
+    }
+}
+
+/// Draws the overview, title and help across the top and the log along the bottom.
+fn draw_panels(frame: &mut Frame, overview: Vec<Line>, help: Vec<Line>, log: Option<String>) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![
+            Constraint::Percentage(20),
+            Constraint::Percentage(60),
+            Constraint::Percentage(20),
+        ])
+        .split(frame.area());
+
+    let layout_inner = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Percentage(1),
+            Constraint::Percentage(44),
+            Constraint::Percentage(24),
+            Constraint::Percentage(30),
+            Constraint::Percentage(1),
+        ])
+        .split(layout[0]);
+
+    let overview_block = Block::new()
+        .title(Line::raw(" Overview ").centered())
+        .borders(Borders::TOP);
+    let overview_rect = overview_block.inner(layout_inner[1]);
+
+    let help_block = Block::new()
+        .title(Line::raw(" Help ").centered())
+        .borders(Borders::TOP);
+    let help_rect = help_block.inner(layout_inner[3]);
+
+    let title_block = Block::new()
+        .title(Line::raw(" algorithm-tui ").bold().centered())
+        .borders(Borders::TOP);
+    let title_rect = overview_block.inner(layout_inner[2]);
+
+    frame.render_widget(overview_block, layout_inner[1]);
+    render_columns(frame, overview_rect, overview);
+    frame.render_widget(help_block, layout_inner[3]);
+    render_columns(frame, help_rect, help);
+    frame.render_widget(title_block, title_rect);
+
+    if let Some(text) = log {
+        frame.render_widget(Paragraph::new(text).wrap(Wrap { trim: true }), layout[2]);
+    }
+}
+
+/// Lays `lines` out top to bottom in as many equal columns as `area` needs.
+pub fn render_columns(frame: &mut Frame, area: Rect, lines: Vec<Line>) {
+    let rows = usize::from(area.height.max(1));
+    let columns = lines.len().div_ceil(rows).max(1);
+
+    let rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+        .split(area);
+
+    for (chunk, rect) in lines.chunks(rows).zip(rects.iter()) {
+        frame.render_widget(List::new(chunk.to_vec()), *rect);
     }
 }
 
@@ -1,61 +1,169 @@
-use std::ops::Index;
+use std::cmp::Ordering;
 
 use crate::state::SharedState;
 
+/// Instrumented view of the state's array. Views handed to the threads of a
+/// parallel algorithm carry a worker index so that their operations are also
+/// counted per thread.
 #[derive(Clone)]
-pub struct Array(SharedState);
+pub struct Array(SharedState, Option<usize>);
 
 impl Array {
     pub fn new(state: SharedState) -> Self {
-        Self(state)
+        Self(state, None)
+    }
+
+    pub fn worker(&self, worker: usize) -> Self {
+        Self(self.0.clone(), Some(worker))
+    }
+
+    pub fn get_worker(&self) -> Option<usize> {
+        self.1
     }
 
     pub fn get(&self, index: usize) -> u32 {
         self.0.increment_accesses(1);
-        *self.0.get().array.index(index)
+        self.0.load(index)
     }
 
     pub fn get_all(&self) -> Vec<u32> {
-        let state = self.0.get();
-        state.array.to_vec()
+        self.0.get_all()
     }
 
     pub fn set(&self, index: usize, value: u32) -> u32 {
         self.0.increment_accesses(1);
-        let mut state = self.0.get();
-        state.array[index] = value;
+        self.0.increment_writes(1);
+
+        if let Some(worker) = self.1 {
+            self.0.worker_wrote(worker, 1);
+        }
+
+        self.0.store(index, value);
         value
     }
 
     pub fn len(&self) -> usize {
-        self.0.get().array.len()
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     pub fn compare(&self, index1: usize, index2: usize) {
-        self.0.increment_comparisons();
-        self.0.set_comparison([
+        let indexes = [
             u32::try_from(index1).unwrap(),
             u32::try_from(index2).unwrap(),
-        ]);
+        ];
+
+        self.0.increment_comparisons();
+
+        match self.1 {
+            Some(worker) => self.0.worker_compared(worker, Some(indexes)),
+            None => {
+                self.0.set_comparison(indexes);
+            }
+        }
+    }
+
+    /// Orders two values, or lets the state's adversary decide their order.
+    fn order(&self, value1: u32, value2: u32) -> Ordering {
+        match self.0.get_adversary() {
+            Some(adversary) => adversary.compare(value1, value2),
+            None => value1.cmp(&value2),
+        }
     }
 
     pub fn is_greater(&self, index1: usize, index2: usize) -> bool {
         self.compare(index1, index2);
-        self.get(index1) > self.get(index2)
+        self.order(self.get(index1), self.get(index2)).is_gt()
     }
 
     pub fn is_greater_equal(&self, index1: usize, index2: usize) -> bool {
         self.compare(index1, index2);
-        self.get(index1) >= self.get(index2)
+        self.order(self.get(index1), self.get(index2)).is_ge()
     }
 
     pub fn is_equal(&self, index1: usize, index2: usize) -> bool {
         self.compare(index1, index2);
-        self.get(index1) == self.get(index2)
+        self.order(self.get(index1), self.get(index2)).is_eq()
     }
 
     pub fn swap(&self, index1: usize, index2: usize) {
-        self.0.get().array.swap(index1, index2);
+        self.0.write(|| {
+            let value1 = self.0.load(index1);
+            self.0.store(index1, self.0.load(index2));
+            self.0.store(index2, value1);
+        });
         self.0.increment_accesses(4);
+        self.0.increment_writes(2);
+
+        if let Some(worker) = self.1 {
+            self.0.worker_wrote(worker, 2);
+        }
+    }
+
+    /// Compares two values already read from the array or a [`Buffer`].
+    pub fn value_is_greater(&self, value1: u32, value2: u32) -> bool {
+        self.0.increment_comparisons();
+
+        if let Some(worker) = self.1 {
+            self.0.worker_compared(worker, None);
+        }
+
+        self.order(value1, value2).is_gt()
+    }
+
+    /// Like [`Array::value_is_greater`], highlighting `indexes` as the values'
+    /// places, for this view's worker if it has one.
+    pub fn value_is_greater_at(&self, indexes: [usize; 2], value1: u32, value2: u32) -> bool {
+        self.compare(indexes[0], indexes[1]);
+        self.order(value1, value2).is_gt()
+    }
+
+    /// Like [`Array::value_is_greater`], for equality.
+    pub fn value_is_equal(&self, value1: u32, value2: u32) -> bool {
+        self.0.increment_comparisons();
+
+        if let Some(worker) = self.1 {
+            self.0.worker_compared(worker, None);
+        }
+
+        self.order(value1, value2).is_eq()
+    }
+}
+
+/// Auxiliary storage whose reads and writes are counted like the array's.
+pub struct Buffer {
+    state: SharedState,
+    values: Vec<u32>,
+}
+
+impl Buffer {
+    pub fn new(state: SharedState, len: usize) -> Self {
+        Self {
+            state,
+            values: vec![0; len],
+        }
+    }
+
+    pub fn get(&self, index: usize) -> u32 {
+        self.state.increment_accesses(1);
+        self.values[index]
+    }
+
+    pub fn set(&mut self, index: usize, value: u32) -> u32 {
+        self.state.increment_accesses(1);
+        self.state.increment_writes(1);
+        self.values[index] = value;
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
     }
 }
@@ -0,0 +1,148 @@
+use rand::prelude::*;
+
+use crate::{
+    algorithms::{self, Algorithm},
+    array::Array,
+    metrics::Metrics,
+    state::{SharedState, State, Status},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Distribution {
+    Random,
+    NearlySorted,
+    Reversed,
+    Sorted,
+    FewUnique,
+}
+
+impl Distribution {
+    pub const ALL: [Distribution; 5] = [
+        Distribution::Random,
+        Distribution::NearlySorted,
+        Distribution::Reversed,
+        Distribution::Sorted,
+        Distribution::FewUnique,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Distribution::Random => "Random",
+            Distribution::NearlySorted => "Nearly Sorted",
+            Distribution::Reversed => "Reversed",
+            Distribution::Sorted => "Sorted",
+            Distribution::FewUnique => "Few Unique",
+        }
+    }
+
+    pub fn generate(&self, len: u32, rng: &mut impl Rng) -> Vec<u32> {
+        let mut nums: Vec<u32> = (1..len + 1).collect();
+
+        match self {
+            Distribution::Random => nums.shuffle(rng),
+            Distribution::NearlySorted => {
+                for _ in 0..len / 20 + 1 {
+                    let i = rng.random_range(0..nums.len());
+                    let j = rng.random_range(0..nums.len());
+                    nums.swap(i, j);
+                }
+            }
+            Distribution::Reversed => nums.reverse(),
+            Distribution::Sorted => {}
+            Distribution::FewUnique => {
+                let step = (len / 5).max(1);
+
+                for num in nums.iter_mut() {
+                    *num = (*num - 1) / step * step + step;
+                }
+
+                nums.shuffle(rng);
+            }
+        }
+
+        nums
+    }
+}
+
+pub struct Report {
+    pub algorithm: String,
+    pub input: Metrics,
+    pub comparisons: u32,
+    pub array_accesses: u32,
+    pub writes: u32,
+    /// Whether the algorithm's own check, e.g. sortedness, passed.
+    pub passed: bool,
+    pub gave_up: bool,
+}
+
+/// Sorts `array` on the current thread without delays or pausing.
+pub fn run(algorithm: &dyn Algorithm, array: Vec<u32>) -> Report {
+    let input = Metrics::compute(&array);
+
+    let mut state = State::new(array);
+    state.headless = true;
+
+    let state = SharedState::new(state);
+    algorithm.sort(state.clone(), Array::new(state.clone()));
+
+    let result = state.snapshot();
+
+    Report {
+        algorithm: result.algorithm,
+        input,
+        comparisons: result.comparisons,
+        array_accesses: result.array_accesses,
+        writes: result.writes,
+        passed: matches!(result.status, Status::Completed),
+        gave_up: matches!(result.status, Status::GaveUp),
+    }
+}
+
+pub fn print(len: u32) {
+    let mut rng = rand::rng();
+
+    println!(
+        "{:<30} {:<14} {:>6} {:>12} {:>14} {:>9} {:>11} {:>5} {:>5} {:>9} {:>9} {:>9}",
+        "Algorithm",
+        "Input",
+        "Size",
+        "Comparisons",
+        "Array Accesses",
+        "Writes",
+        "Inversions",
+        "Runs",
+        "LIS",
+        "Max Disp",
+        "Avg Disp",
+        "Spearman",
+    );
+
+    for distribution in Distribution::ALL {
+        let array = distribution.generate(len, &mut rng);
+
+        for algorithm in algorithms::all_variants() {
+            let report = run(algorithm.as_ref(), array.clone());
+
+            println!(
+                "{:<30} {:<14} {:>6} {:>12} {:>14} {:>9} {:>11} {:>5} {:>5} {:>9} {:>9.2} {:>9.3}{}",
+                report.algorithm,
+                distribution.name(),
+                len,
+                report.comparisons,
+                report.array_accesses,
+                report.writes,
+                report.input.inversions,
+                report.input.runs,
+                report.input.longest_increasing,
+                report.input.max_displacement,
+                report.input.average_displacement,
+                report.input.spearman,
+                match (report.gave_up, report.passed) {
+                    (true, _) => "  (gave up)",
+                    (false, true) => "",
+                    (false, false) => "  (check failed)",
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    algorithms::Algorithm,
+    bench::{self, Distribution},
+};
+
+pub const SIZES: [u32; 7] = [16, 32, 64, 128, 256, 512, 1024];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Model {
+    Linear,
+    Linearithmic,
+    SubQuadratic,
+    Quadratic,
+}
+
+impl Model {
+    pub const ALL: [Model; 4] = [
+        Model::Linear,
+        Model::Linearithmic,
+        Model::SubQuadratic,
+        Model::Quadratic,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Model::Linear => "n",
+            Model::Linearithmic => "n log n",
+            Model::SubQuadratic => "n^1.5",
+            Model::Quadratic => "n²",
+        }
+    }
+
+    pub fn eval(&self, n: f64) -> f64 {
+        match self {
+            Model::Linear => n,
+            Model::Linearithmic => n * n.log2(),
+            Model::SubQuadratic => n.powf(1.5),
+            Model::Quadratic => n * n,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Sample {
+    pub len: u32,
+    pub comparisons: u32,
+    pub array_accesses: u32,
+}
+
+/// A model `cost ≈ coefficient · model(n)` together with the spread of the
+/// log residuals, so that the best model is the one with the smallest error.
+#[derive(Clone, Copy)]
+pub struct Fit {
+    pub model: Model,
+    pub coefficient: f64,
+    pub error: f64,
+}
+
+/// Free power law `cost ≈ constant · n^exponent` from a log-log regression.
+#[derive(Clone, Copy)]
+pub struct PowerLaw {
+    pub constant: f64,
+    pub exponent: f64,
+}
+
+pub fn fit(points: &[(f64, f64)]) -> Vec<Fit> {
+    let points: Vec<(f64, f64)> = points.iter().copied().filter(|(_, y)| *y > 0.0).collect();
+
+    if points.is_empty() {
+        return vec![];
+    }
+
+    let mut fits: Vec<Fit> = Model::ALL
+        .iter()
+        .map(|&model| {
+            let residuals: Vec<f64> = points
+                .iter()
+                .map(|(n, y)| y.ln() - model.eval(*n).ln())
+                .collect();
+
+            let mean = residuals.iter().sum::<f64>() / residuals.len() as f64;
+            let error = (residuals.iter().map(|r| (r - mean).powi(2)).sum::<f64>()
+                / residuals.len() as f64)
+                .sqrt();
+
+            Fit {
+                model,
+                coefficient: mean.exp(),
+                error,
+            }
+        })
+        .collect();
+
+    fits.sort_by(|a, b| a.error.total_cmp(&b.error));
+    fits
+}
+
+pub fn power_law(points: &[(f64, f64)]) -> Option<PowerLaw> {
+    let logs: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|(n, y)| *n > 0.0 && *y > 0.0)
+        .map(|(n, y)| (n.ln(), y.ln()))
+        .collect();
+
+    if logs.len() < 2 {
+        return None;
+    }
+
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+    let covariance: f64 = logs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    if variance == 0.0 {
+        return None;
+    }
+
+    let exponent = covariance / variance;
+
+    Some(PowerLaw {
+        constant: (mean_y - exponent * mean_x).exp(),
+        exponent,
+    })
+}
+
+pub struct Series {
+    pub distribution: Distribution,
+    pub samples: Vec<Sample>,
+}
+
+impl Series {
+    pub fn comparisons(&self) -> Vec<(f64, f64)> {
+        self.samples
+            .iter()
+            .map(|s| (f64::from(s.len), f64::from(s.comparisons)))
+            .collect()
+    }
+
+    pub fn array_accesses(&self) -> Vec<(f64, f64)> {
+        self.samples
+            .iter()
+            .map(|s| (f64::from(s.len), f64::from(s.array_accesses)))
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct Analysis {
+    pub algorithm: String,
+    pub series: Vec<Series>,
+    pub done: bool,
+    pub cancelled: bool,
+}
+
+#[derive(Clone, Default)]
+pub struct SharedAnalysis(Arc<Mutex<Analysis>>);
+
+impl SharedAnalysis {
+    pub fn get(&self) -> std::sync::MutexGuard<'_, Analysis> {
+        self.0.lock().unwrap()
+    }
+
+    pub fn cancel(&self) {
+        self.get().cancelled = true;
+    }
+
+    /// Runs `algorithm` headlessly over every size in [`SIZES`] for every
+    /// distribution, publishing each sample as soon as it is measured.
+    pub fn run(&self, algorithm: &dyn Algorithm) {
+        let mut rng = rand::rng();
+
+        for distribution in Distribution::ALL {
+            self.get().series.push(Series {
+                distribution,
+                samples: vec![],
+            });
+
+            for len in SIZES {
+                if self.get().cancelled {
+                    return;
+                }
+
+                let report = bench::run(algorithm, distribution.generate(len, &mut rng));
+
+                let mut analysis = self.get();
+                analysis.algorithm = report.algorithm;
+
+                // Larger inputs would only exhaust the budget again.
+                if report.gave_up {
+                    break;
+                }
+
+                analysis.series.last_mut().unwrap().samples.push(Sample {
+                    len,
+                    comparisons: report.comparisons,
+                    array_accesses: report.array_accesses,
+                });
+            }
+        }
+
+        self.get().done = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `coefficient · model(n)` at every size the analysis samples.
+    fn series(model: Model, coefficient: f64) -> Vec<(f64, f64)> {
+        SIZES
+            .iter()
+            .map(|&len| (f64::from(len), coefficient * model.eval(f64::from(len))))
+            .collect()
+    }
+
+    #[test]
+    fn fit_picks_the_model_a_series_follows() {
+        for (model, coefficient) in Model::ALL.into_iter().zip([3.0, 0.5, 2.0, 0.25]) {
+            let best = fit(&series(model, coefficient))[0];
+
+            assert!(
+                best.model == model,
+                "{} fitted as {}",
+                model.name(),
+                best.model.name()
+            );
+            assert!((best.coefficient - coefficient).abs() < 1e-9);
+            assert!(best.error < 1e-9);
+        }
+    }
+
+    #[test]
+    fn fit_skips_zero_costs() {
+        assert!(fit(&[]).is_empty());
+        assert!(fit(&[(16.0, 0.0), (32.0, 0.0)]).is_empty());
+        assert_eq!(fit(&[(16.0, 0.0), (32.0, 64.0)]).len(), Model::ALL.len());
+    }
+
+    #[test]
+    fn power_law_recovers_the_exponent() {
+        let law = power_law(&series(Model::SubQuadratic, 4.0)).unwrap();
+
+        assert!((law.exponent - 1.5).abs() < 1e-9);
+        assert!((law.constant - 4.0).abs() < 1e-9);
+        assert!(power_law(&[(16.0, 5.0)]).is_none());
+        assert!(power_law(&[(16.0, 5.0), (16.0, 7.0)]).is_none());
+    }
+}
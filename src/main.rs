@@ -1,13 +1,50 @@
 use std::{io::Result, sync::mpsc, thread, time};
 
+pub mod adversary;
 pub mod algorithms;
 pub mod app;
 pub mod array;
+pub mod bench;
+pub mod complexity;
+pub mod metrics;
+pub mod modes;
+pub mod search;
+pub mod shuffle;
 pub mod state;
 
 use app::App;
 
+/// Array length given to a subcommand, 150 when left out. Exits on anything
+/// but a positive number.
+fn length(argument: Option<&String>) -> u32 {
+    let Some(argument) = argument else {
+        return 150;
+    };
+
+    match argument.parse() {
+        Ok(0) | Err(_) => {
+            eprintln!("invalid length '{}', expected a positive number", argument);
+            std::process::exit(2);
+        }
+        Ok(len) => len,
+    }
+}
+
 fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+
+    match args.get(1).map(String::as_str) {
+        Some("bench") => {
+            bench::print(length(args.get(2)));
+            return Ok(());
+        }
+        Some("adversary") => {
+            adversary::print(length(args.get(2)), args.get(3).map(String::as_str));
+            return Ok(());
+        }
+        _ => {}
+    }
+
     let mut terminal = ratatui::init();
 
     let (tx, rx) = mpsc::channel::<app::Event>();
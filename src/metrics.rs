@@ -0,0 +1,182 @@
+/// Measures of how far an array is from being sorted in ascending order.
+#[derive(Clone, Default)]
+pub struct Metrics {
+    pub inversions: u64,
+    pub runs: usize,
+    pub longest_increasing: usize,
+    pub max_displacement: usize,
+    pub average_displacement: f64,
+    pub spearman: f64,
+}
+
+impl Metrics {
+    pub fn compute(array: &[u32]) -> Self {
+        if array.is_empty() {
+            return Metrics::default();
+        }
+
+        let displacements: Vec<usize> = final_positions(array)
+            .iter()
+            .enumerate()
+            .map(|(i, position)| i.abs_diff(*position))
+            .collect();
+
+        Metrics {
+            inversions: inversions(array),
+            runs: runs(array),
+            longest_increasing: longest_increasing(array),
+            max_displacement: displacements.iter().copied().max().unwrap_or(0),
+            average_displacement: displacements.iter().sum::<usize>() as f64 / array.len() as f64,
+            spearman: spearman(array),
+        }
+    }
+}
+
+/// Number of pairs `i < j` with `array[i] > array[j]`, counted with a merge sort.
+pub fn inversions(array: &[u32]) -> u64 {
+    fn count(array: &mut [u32], buffer: &mut Vec<u32>) -> u64 {
+        let len = array.len();
+
+        if len < 2 {
+            return 0;
+        }
+
+        let mid = len / 2;
+        let mut total = count(&mut array[..mid], buffer) + count(&mut array[mid..], buffer);
+
+        buffer.clear();
+        let (mut left, mut right) = (0, mid);
+
+        while left < mid && right < len {
+            if array[left] > array[right] {
+                total += (mid - left) as u64;
+                buffer.push(array[right]);
+                right += 1;
+            } else {
+                buffer.push(array[left]);
+                left += 1;
+            }
+        }
+
+        buffer.extend_from_slice(&array[left..mid]);
+        buffer.extend_from_slice(&array[right..]);
+        array.copy_from_slice(buffer);
+
+        total
+    }
+
+    count(&mut array.to_vec(), &mut Vec::with_capacity(array.len()))
+}
+
+/// Number of maximal non-decreasing runs.
+pub fn runs(array: &[u32]) -> usize {
+    if array.is_empty() {
+        return 0;
+    }
+
+    1 + array.windows(2).filter(|pair| pair[0] > pair[1]).count()
+}
+
+/// Length of the longest strictly increasing subsequence, using patience sorting.
+pub fn longest_increasing(array: &[u32]) -> usize {
+    let mut tails: Vec<u32> = vec![];
+
+    for &value in array {
+        let position = tails.partition_point(|&tail| tail < value);
+
+        if position == tails.len() {
+            tails.push(value);
+        } else {
+            tails[position] = value;
+        }
+    }
+
+    tails.len()
+}
+
+/// Index every element ends up at after a stable sort.
+pub fn final_positions(array: &[u32]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..array.len()).collect();
+    order.sort_by_key(|&i| array[i]);
+
+    let mut positions = vec![0; array.len()];
+
+    for (position, index) in order.into_iter().enumerate() {
+        positions[index] = position;
+    }
+
+    positions
+}
+
+/// Spearman rank correlation between the current and the sorted order, from -1 (reversed) to 1 (sorted).
+pub fn spearman(array: &[u32]) -> f64 {
+    let n = array.len() as f64;
+
+    if array.len() < 2 {
+        return 1.0;
+    }
+
+    let squared: f64 = final_positions(array)
+        .iter()
+        .enumerate()
+        .map(|(i, position)| (i.abs_diff(*position) as f64).powi(2))
+        .sum();
+
+    1.0 - 6.0 * squared / (n * (n * n - 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inversions_count_out_of_order_pairs() {
+        assert_eq!(inversions(&[]), 0);
+        assert_eq!(inversions(&[1, 2, 3, 4]), 0);
+        assert_eq!(inversions(&[3, 1, 2]), 2);
+        assert_eq!(inversions(&[2, 2, 1]), 2);
+        assert_eq!(inversions(&[5, 4, 3, 2, 1]), 10);
+    }
+
+    #[test]
+    fn runs_split_where_values_drop() {
+        assert_eq!(runs(&[]), 0);
+        assert_eq!(runs(&[7]), 1);
+        assert_eq!(runs(&[1, 2, 2, 1, 3]), 2);
+        assert_eq!(runs(&[3, 2, 1]), 3);
+    }
+
+    #[test]
+    fn longest_increasing_is_strict() {
+        assert_eq!(longest_increasing(&[]), 0);
+        assert_eq!(longest_increasing(&[2, 2, 2]), 1);
+        assert_eq!(longest_increasing(&[3, 1, 2, 2, 4]), 3);
+        assert_eq!(longest_increasing(&[5, 4, 3, 2, 1]), 1);
+    }
+
+    #[test]
+    fn final_positions_are_stable() {
+        assert_eq!(final_positions(&[30, 10, 20]), vec![2, 0, 1]);
+        assert_eq!(final_positions(&[2, 1, 2]), vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn spearman_runs_from_reversed_to_sorted() {
+        assert_eq!(spearman(&[4]), 1.0);
+        assert_eq!(spearman(&[1, 2, 3, 4]), 1.0);
+        assert_eq!(spearman(&[4, 3, 2, 1]), -1.0);
+        assert_eq!(spearman(&[1, 3, 2]), 0.5);
+    }
+
+    #[test]
+    fn compute_combines_them() {
+        let metrics = Metrics::compute(&[3, 1, 2]);
+
+        assert_eq!(metrics.inversions, 2);
+        assert_eq!(metrics.runs, 2);
+        assert_eq!(metrics.longest_increasing, 2);
+        assert_eq!(metrics.max_displacement, 2);
+        assert!((metrics.average_displacement - 4.0 / 3.0).abs() < 1e-9);
+        assert_eq!(metrics.spearman, -0.5);
+    }
+}
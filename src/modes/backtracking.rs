@@ -0,0 +1,506 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::state::{SharedState, Status};
+
+/// Moves a knight can make, as column and row offsets.
+const KNIGHT_MOVES: [(isize, isize); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Puzzle {
+    NQueens,
+    Sudoku,
+    KnightsTour,
+    SubsetSum,
+}
+
+impl Puzzle {
+    pub const ALL: [Puzzle; 4] = [
+        Puzzle::NQueens,
+        Puzzle::Sudoku,
+        Puzzle::KnightsTour,
+        Puzzle::SubsetSum,
+    ];
+
+    /// Sudoku tries the most placements before it is solved.
+    pub fn speed(&self) -> u32 {
+        match self {
+            Puzzle::Sudoku => 95,
+            _ => 85,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Puzzle::NQueens => "N-Queens",
+            Puzzle::Sudoku => "Sudoku",
+            Puzzle::KnightsTour => "Knight's Tour (Warnsdorff)",
+            Puzzle::SubsetSum => "Subset Sum",
+        }
+    }
+
+    /// Smallest, default and largest size, which is the board's side or the
+    /// amount of numbers. Sudoku is always 9.
+    pub fn sizes(&self) -> (usize, usize, usize) {
+        match self {
+            Puzzle::NQueens => (4, 8, 12),
+            Puzzle::Sudoku => (9, 9, 9),
+            Puzzle::KnightsTour => (5, 8, 10),
+            Puzzle::SubsetSum => (4, 10, 16),
+        }
+    }
+
+    /// Solves `board` in place, one placement or backtrack at a time.
+    pub fn run(&self, state: &SharedState, board: &SharedBoard, initial: Board) {
+        *board.get() = initial;
+
+        state.init_algorithm(self.name().to_string());
+
+        let runner = Runner { state, board };
+
+        let solved = match self {
+            Puzzle::NQueens => runner.queens(0),
+            Puzzle::Sudoku => runner.sudoku(),
+            Puzzle::KnightsTour => runner.knights_tour(),
+            Puzzle::SubsetSum => runner.subset_sum(),
+        };
+
+        // Interrupted, so the next run starts from its own board.
+        let Some(solved) = solved else {
+            return;
+        };
+
+        let summary = {
+            let mut board = board.get();
+            board.current = None;
+            board.rejected = None;
+
+            match solved {
+                true => format!("solved with {} backtracks", board.backtracks),
+                false => format!("no solution after {} backtracks", board.backtracks),
+            }
+        };
+
+        state.set_depth(0);
+        state.log(format!(
+            "{}: {}, {} nodes explored",
+            self.name(),
+            summary,
+            state.get_nodes()
+        ));
+        state.set_status(Status::Completed);
+    }
+}
+
+/// Cells read row by row. Queens are 1, a knight's squares hold the move that
+/// reached them, sudoku cells their digit and subset-sum cells are 1 when the
+/// number is taken and 2 once left out. Zero is always empty.
+#[derive(Clone)]
+pub struct Board {
+    pub puzzle: Puzzle,
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<u32>,
+    /// Sudoku givens, which are never changed.
+    pub fixed: Vec<bool>,
+    /// The numbers to pick from for subset sum.
+    pub values: Vec<u32>,
+    pub target: u32,
+    pub current: Option<usize>,
+    /// A candidate that was just ruled out.
+    pub rejected: Option<usize>,
+    pub backtracks: u32,
+    pub deepest: u32,
+}
+
+impl Board {
+    fn new(puzzle: Puzzle, width: usize, height: usize) -> Self {
+        Self {
+            puzzle,
+            width,
+            height,
+            cells: vec![0; width * height],
+            fixed: vec![false; width * height],
+            values: vec![],
+            target: 0,
+            current: None,
+            rejected: None,
+            backtracks: 0,
+            deepest: 0,
+        }
+    }
+
+    pub fn queens(size: usize) -> Self {
+        Self::new(Puzzle::NQueens, size, size)
+    }
+
+    pub fn knights_tour(size: usize) -> Self {
+        Self::new(Puzzle::KnightsTour, size, size)
+    }
+
+    pub fn sudoku(givens: &[u32]) -> Self {
+        let mut board = Self::new(Puzzle::Sudoku, 9, 9);
+        board.cells = givens.to_vec();
+        board.fixed = givens.iter().map(|&digit| digit != 0).collect();
+        board
+    }
+
+    pub fn subset_sum(values: Vec<u32>, target: u32) -> Self {
+        let mut board = Self::new(Puzzle::SubsetSum, values.len(), 1);
+        board.values = values;
+        board.target = target;
+        board
+    }
+
+    /// Sum of the subset-sum numbers taken so far.
+    pub fn sum(&self) -> u32 {
+        self.values
+            .iter()
+            .zip(&self.cells)
+            .filter(|&(_, &cell)| cell == 1)
+            .map(|(&value, _)| value)
+            .sum()
+    }
+
+    /// Whether `digit` may go at `index` without repeating in its row,
+    /// column or box.
+    fn allows(&self, index: usize, digit: u32) -> bool {
+        let (column, row) = (index % 9, index / 9);
+        let (box_column, box_row) = (column / 3 * 3, row / 3 * 3);
+
+        (0..9).all(|i| {
+            let peers = [
+                row * 9 + i,
+                i * 9 + column,
+                (box_row + i / 3) * 9 + box_column + i % 3,
+            ];
+
+            peers
+                .iter()
+                .all(|&peer| peer == index || self.cells[peer] != digit)
+        })
+    }
+}
+
+/// Reads a sudoku as 81 cells, digits for givens and `0` or `.` for blanks.
+/// Whitespace is ignored and lines starting with `#` are comments.
+pub fn parse_sudoku(text: &str) -> Result<Vec<u32>, String> {
+    let mut cells = vec![];
+
+    for line in text
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+    {
+        for character in line.chars().filter(|character| !character.is_whitespace()) {
+            match character {
+                '.' => cells.push(0),
+                digit @ '0'..='9' => cells.push(digit.to_digit(10).unwrap()),
+                other => return Err(format!("unexpected '{}'", other)),
+            }
+        }
+    }
+
+    if cells.len() != 81 {
+        return Err(format!("expected 81 cells, found {}", cells.len()));
+    }
+
+    let board = Board::sudoku(&cells);
+
+    if let Some(index) = (0..81).find(|&i| cells[i] != 0 && !board.allows(i, cells[i])) {
+        return Err(format!(
+            "{} repeats at row {}, column {}",
+            cells[index],
+            index / 9 + 1,
+            index % 9 + 1
+        ));
+    }
+
+    Ok(cells)
+}
+
+#[derive(Clone)]
+pub struct SharedBoard(Arc<Mutex<Board>>);
+
+impl SharedBoard {
+    pub fn new(board: Board) -> Self {
+        Self(Arc::new(Mutex::new(board)))
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, Board> {
+        self.0.lock().unwrap()
+    }
+}
+
+struct Runner<'a> {
+    state: &'a SharedState,
+    board: &'a SharedBoard,
+}
+
+impl Runner<'_> {
+    /// Counts a node for trying `value` at `index` at recursion `depth`. An
+    /// accepted value is placed, a rejected one only flashed. Returns `None`
+    /// once interrupted.
+    fn attempt(&self, index: usize, value: u32, depth: u32, accepted: bool) -> Option<()> {
+        if self.state.interrupted() {
+            return None;
+        }
+
+        self.state.increment_nodes();
+        self.state.set_depth(depth);
+
+        {
+            let mut board = self.board.get();
+            board.current = Some(index);
+            board.deepest = board.deepest.max(depth);
+
+            match accepted {
+                true => {
+                    board.cells[index] = value;
+                    board.rejected = None;
+                }
+                false => board.rejected = Some(index),
+            }
+        }
+
+        self.state.sleep(None);
+        Some(())
+    }
+
+    /// Takes back whatever was placed at `index`, or returns `None` once interrupted.
+    fn backtrack(&self, index: usize, depth: u32) -> Option<()> {
+        if self.state.interrupted() {
+            return None;
+        }
+
+        self.state.set_depth(depth);
+
+        {
+            let mut board = self.board.get();
+            board.cells[index] = 0;
+            board.current = Some(index);
+            board.rejected = None;
+            board.backtracks += 1;
+        }
+
+        self.state.sleep(None);
+        Some(())
+    }
+
+    /// Places a queen in every row from `row` on, one column at a time.
+    fn queens(&self, row: usize) -> Option<bool> {
+        let size = self.board.get().width;
+
+        if row == size {
+            return Some(true);
+        }
+
+        for column in 0..size {
+            let safe = {
+                let board = self.board.get();
+
+                (0..row).all(|other| {
+                    let distance = row - other;
+
+                    [
+                        Some(column),
+                        column.checked_sub(distance),
+                        Some(column + distance),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .filter(|&attacked| attacked < size)
+                    .all(|attacked| board.cells[other * size + attacked] == 0)
+                })
+            };
+
+            let index = row * size + column;
+            self.attempt(index, 1, row as u32 + 1, safe)?;
+
+            if !safe {
+                continue;
+            }
+
+            if self.queens(row + 1)? {
+                return Some(true);
+            }
+
+            self.backtrack(index, row as u32)?;
+        }
+
+        Some(false)
+    }
+
+    /// Fills the empty cell with the fewest digits left, trying each digit
+    /// its row, column and box allow.
+    fn sudoku(&self) -> Option<bool> {
+        let (index, depth) = {
+            let board = self.board.get();
+            let filled = board.cells.iter().filter(|&&digit| digit != 0).count();
+            let given = board.fixed.iter().filter(|&&fixed| fixed).count();
+
+            let cell = (0..81)
+                .filter(|&index| board.cells[index] == 0)
+                .min_by_key(|&index| (1..=9).filter(|&digit| board.allows(index, digit)).count());
+
+            match cell {
+                Some(index) => (index, (filled - given) as u32 + 1),
+                None => return Some(true),
+            }
+        };
+
+        for digit in 1..=9 {
+            let allowed = self.board.get().allows(index, digit);
+            self.attempt(index, digit, depth, allowed)?;
+
+            if !allowed {
+                continue;
+            }
+
+            if self.sudoku()? {
+                return Some(true);
+            }
+
+            self.backtrack(index, depth - 1)?;
+        }
+
+        Some(false)
+    }
+
+    /// Squares a knight on `index` can move to that haven't been visited.
+    fn knight_moves(&self, index: usize) -> Vec<usize> {
+        let board = self.board.get();
+        let size = board.width as isize;
+        let (column, row) = (index as isize % size, index as isize / size);
+
+        KNIGHT_MOVES
+            .iter()
+            .map(|&(x, y)| (column + x, row + y))
+            .filter(|&(x, y)| (0..size).contains(&x) && (0..size).contains(&y))
+            .map(|(x, y)| (y * size + x) as usize)
+            .filter(|&next| board.cells[next] == 0)
+            .collect()
+    }
+
+    fn knights_tour(&self) -> Option<bool> {
+        self.attempt(0, 1, 1, true)?;
+        self.tour(0, 1)
+    }
+
+    /// Moves on from `index`, trying squares with the fewest onward moves
+    /// first, as Warnsdorff suggested, and backtracking from dead ends.
+    fn tour(&self, index: usize, step: u32) -> Option<bool> {
+        let squares = self.board.get().cells.len() as u32;
+
+        if step == squares {
+            return Some(true);
+        }
+
+        let mut moves = self.knight_moves(index);
+        moves.sort_by_key(|&next| self.knight_moves(next).len());
+
+        for next in moves {
+            self.attempt(next, step + 1, step + 1, true)?;
+
+            if self.tour(next, step + 1)? {
+                return Some(true);
+            }
+
+            self.backtrack(next, step)?;
+        }
+
+        Some(false)
+    }
+
+    fn subset_sum(&self) -> Option<bool> {
+        let remaining = self.board.get().values.iter().sum();
+        self.pick(0, 0, remaining)
+    }
+
+    /// Takes or leaves the number at `index`, pruning once the sum overshoots
+    /// or the numbers left can't reach the target.
+    fn pick(&self, index: usize, sum: u32, remaining: u32) -> Option<bool> {
+        let (target, value) = {
+            let board = self.board.get();
+            (board.target, board.values.get(index).copied())
+        };
+
+        if sum == target {
+            return Some(true);
+        }
+
+        let Some(value) = value else {
+            return Some(false);
+        };
+
+        let depth = index as u32 + 1;
+        let fits = sum + value <= target;
+
+        self.attempt(index, 1, depth, fits)?;
+
+        if fits && self.pick(index + 1, sum + value, remaining - value)? {
+            return Some(true);
+        }
+
+        let reachable = sum + remaining - value >= target;
+        self.attempt(index, 2, depth, reachable)?;
+
+        if reachable && self.pick(index + 1, sum, remaining - value)? {
+            return Some(true);
+        }
+
+        self.backtrack(index, depth - 1)?;
+        Some(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "
+        # A comment, then the rows with spaces between some of them.
+        53..7....
+        6..195...
+        .98....6.
+        8.. .6. ..3
+        4..8.3..1
+        7...2...6
+        .6....28.
+        ...419..5
+        ....8..79
+    ";
+
+    #[test]
+    fn parse_sudoku_reads_givens_and_blanks() {
+        let cells = parse_sudoku(PUZZLE).unwrap();
+
+        assert_eq!(cells.len(), 81);
+        assert_eq!(&cells[..9], &[5, 3, 0, 0, 7, 0, 0, 0, 0]);
+        assert_eq!(&cells[27..36], &[8, 0, 0, 0, 6, 0, 0, 0, 3]);
+        assert_eq!(parse_sudoku(&"0".repeat(81)), Ok(vec![0; 81]));
+    }
+
+    #[test]
+    fn parse_sudoku_rejects_bad_input() {
+        assert_eq!(
+            parse_sudoku(&"0".repeat(80)),
+            Err("expected 81 cells, found 80".to_string())
+        );
+        assert_eq!(
+            parse_sudoku(&PUZZLE.replace('7', "x")),
+            Err("unexpected 'x'".to_string())
+        );
+        assert_eq!(
+            parse_sudoku(&PUZZLE.replacen("53.", "535", 1)),
+            Err("5 repeats at row 1, column 1".to_string())
+        );
+    }
+}
@@ -0,0 +1,382 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::state::{SharedState, Status};
+
+pub const WIDTH: usize = 80;
+pub const HEIGHT: usize = 40;
+/// Share of live cells in a random Life soup.
+const SOUP_DENSITY: f64 = 0.3;
+/// Up, right, down and left, as column and row offsets.
+const DIRECTIONS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Automaton {
+    Life,
+    Elementary,
+    LangtonsAnt,
+}
+
+impl Automaton {
+    pub const ALL: [Automaton; 3] = [
+        Automaton::Life,
+        Automaton::Elementary,
+        Automaton::LangtonsAnt,
+    ];
+
+    /// The ant needs thousands of steps before it builds its highway.
+    pub fn speed(&self) -> u32 {
+        match self {
+            Automaton::LangtonsAnt => 95,
+            _ => 50,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Automaton::Life => "Conway's Game of Life",
+            Automaton::Elementary => "Elementary Cellular Automaton",
+            Automaton::LangtonsAnt => "Langton's Ant",
+        }
+    }
+
+    /// Steps `world` one generation at a time until interrupted, or until
+    /// Life dies out or stops changing.
+    pub fn run(&self, state: &SharedState, world: &SharedWorld, initial: World) {
+        *world.get() = initial;
+
+        state.init_algorithm(self.name().to_string());
+
+        loop {
+            if state.interrupted() {
+                return;
+            }
+
+            let (changed, generation, population) = {
+                let mut world = world.get();
+                let changed = world.step();
+                (changed, world.generation, world.population)
+            };
+
+            let outcome = match self {
+                Automaton::Life if population == 0 => "died out",
+                Automaton::Life if !changed => "settled",
+                _ => {
+                    state.sleep(None);
+                    continue;
+                }
+            };
+
+            state.log(format!(
+                "{}: {} after {} generations",
+                self.name(),
+                outcome,
+                generation
+            ));
+            state.set_status(Status::Completed);
+            return;
+        }
+    }
+}
+
+/// Cells read row by row on a grid whose edges wrap around to the other side.
+pub struct World {
+    pub automaton: Automaton,
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<bool>,
+    pub generation: u64,
+    pub population: usize,
+    /// Wolfram's number for the elementary automaton, whose bits give the
+    /// next state for every neighbourhood.
+    pub rule: u8,
+    /// Rows of the elementary automaton filled so far, after which it scrolls.
+    pub rows: usize,
+    /// The ant's cell and direction, an index into [`DIRECTIONS`].
+    pub ant: (usize, usize),
+}
+
+impl World {
+    fn new(automaton: Automaton) -> Self {
+        Self {
+            automaton,
+            width: WIDTH,
+            height: HEIGHT,
+            cells: vec![false; WIDTH * HEIGHT],
+            generation: 0,
+            population: 0,
+            rule: 0,
+            rows: 0,
+            ant: (0, 0),
+        }
+    }
+
+    /// Life with `pattern`'s live cells centred on the grid.
+    pub fn life(pattern: &[(usize, usize)]) -> Self {
+        let mut world = Self::new(Automaton::Life);
+        let width = pattern.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let height = pattern.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        let (left, top) = ((WIDTH - width) / 2, (HEIGHT - height) / 2);
+
+        for &(x, y) in pattern {
+            world.cells[(top + y) * WIDTH + left + x] = true;
+        }
+
+        world.count();
+        world
+    }
+
+    pub fn soup(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut world = Self::new(Automaton::Life);
+        world.cells = (0..WIDTH * HEIGHT)
+            .map(|_| rng.random_bool(SOUP_DENSITY))
+            .collect();
+
+        world.count();
+        world
+    }
+
+    /// `rule` starting from one live cell in the middle of the top row, or
+    /// from a random row with a seed.
+    pub fn elementary(rule: u8, seed: Option<u64>) -> Self {
+        let mut world = Self::new(Automaton::Elementary);
+        world.rule = rule;
+        world.rows = 1;
+
+        match seed {
+            Some(seed) => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                (0..WIDTH).for_each(|x| world.cells[x] = rng.random_bool(0.5));
+            }
+            None => world.cells[WIDTH / 2] = true,
+        }
+
+        world.count();
+        world
+    }
+
+    /// The ant in the middle of an empty grid, facing up.
+    pub fn langtons_ant() -> Self {
+        let mut world = Self::new(Automaton::LangtonsAnt);
+        world.ant = ((HEIGHT / 2) * WIDTH + WIDTH / 2, 0);
+        world
+    }
+
+    /// Index of the cell `dx` columns and `dy` rows away, wrapping around.
+    fn neighbour(&self, index: usize, dx: isize, dy: isize) -> usize {
+        let (x, y) = ((index % self.width) as isize, (index / self.width) as isize);
+        let x = (x + dx).rem_euclid(self.width as isize) as usize;
+        let y = (y + dy).rem_euclid(self.height as isize) as usize;
+
+        y * self.width + x
+    }
+
+    /// Live cells, or only those in the newest row of the elementary automaton.
+    fn count(&mut self) {
+        let cells = match self.automaton {
+            Automaton::Elementary => {
+                let row = (self.rows - 1) * self.width;
+                &self.cells[row..row + self.width]
+            }
+            _ => &self.cells[..],
+        };
+
+        self.population = cells.iter().filter(|&&alive| alive).count();
+    }
+
+    /// Advances one generation, returning whether any cell changed.
+    fn step(&mut self) -> bool {
+        let changed = match self.automaton {
+            Automaton::Life => self.life_step(),
+            Automaton::Elementary => self.elementary_step(),
+            Automaton::LangtonsAnt => self.ant_step(),
+        };
+
+        self.generation += 1;
+        self.count();
+        changed
+    }
+
+    /// Births on exactly three live neighbours, survival on two or three.
+    fn life_step(&mut self) -> bool {
+        let next: Vec<bool> = (0..self.cells.len())
+            .map(|index| {
+                let neighbours = (-1..=1)
+                    .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+                    .filter(|&offset| offset != (0, 0))
+                    .filter(|&(dx, dy)| self.cells[self.neighbour(index, dx, dy)])
+                    .count();
+
+                matches!((self.cells[index], neighbours), (true, 2) | (_, 3))
+            })
+            .collect();
+
+        let changed = next != self.cells;
+        self.cells = next;
+        changed
+    }
+
+    /// Writes the next row below the newest one, scrolling once the grid is full.
+    fn elementary_step(&mut self) -> bool {
+        let last = (self.rows - 1) * self.width;
+
+        let next: Vec<bool> = (0..self.width)
+            .map(|x| {
+                let pattern = [-1, 0, 1].iter().fold(0, |pattern, &dx| {
+                    pattern << 1 | u8::from(self.cells[self.neighbour(last + x, dx, 0)])
+                });
+
+                self.rule >> pattern & 1 == 1
+            })
+            .collect();
+
+        if self.rows == self.height {
+            self.cells.drain(..self.width);
+            self.cells.extend(next);
+        } else {
+            let row = self.rows * self.width;
+            self.cells[row..row + self.width].copy_from_slice(&next);
+            self.rows += 1;
+        }
+
+        true
+    }
+
+    /// Turns right on a white cell and left on a black one, flips the cell
+    /// and moves forward.
+    fn ant_step(&mut self) -> bool {
+        let (index, direction) = self.ant;
+        let direction = match self.cells[index] {
+            true => (direction + 3) % 4,
+            false => (direction + 1) % 4,
+        };
+
+        self.cells[index] = !self.cells[index];
+
+        let (dx, dy) = DIRECTIONS[direction];
+        self.ant = (self.neighbour(index, dx, dy), direction);
+        true
+    }
+}
+
+/// Reads a Life pattern in run length encoding: an optional `x = .., y = ..`
+/// header, then runs of `b` for dead and `o` for live cells with `$` ending
+/// a row and `!` the pattern. Lines starting with `#` are comments.
+pub fn parse_rle(text: &str) -> Result<Vec<(usize, usize)>, String> {
+    let mut cells = vec![];
+    let (mut x, mut y, mut run) = (0_usize, 0_usize, None::<usize>);
+    let too_big = || Err(format!("patterns fit in {}x{}", WIDTH, HEIGHT));
+
+    'lines: for line in text.lines().map(str::trim) {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('x') {
+            let header = line.replace(' ', "");
+
+            match header
+                .split(',')
+                .find_map(|field| field.strip_prefix("rule="))
+            {
+                Some(rule) if !rule.eq_ignore_ascii_case("B3/S23") => {
+                    return Err(format!("only B3/S23 is supported, not {}", rule));
+                }
+                _ => continue,
+            }
+        }
+
+        for character in line.chars() {
+            match character {
+                '0'..='9' => {
+                    let digit = character.to_digit(10).unwrap() as usize;
+                    run = Some(run.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+                    continue;
+                }
+                'b' | '.' => x = x.saturating_add(run.unwrap_or(1)),
+                'o' => {
+                    for _ in 0..run.unwrap_or(1) {
+                        if x >= WIDTH || y >= HEIGHT {
+                            return too_big();
+                        }
+
+                        cells.push((x, y));
+                        x += 1;
+                    }
+                }
+                '$' => (x, y) = (0, y.saturating_add(run.unwrap_or(1))),
+                '!' => break 'lines,
+                character if character.is_whitespace() => {}
+                other => return Err(format!("unexpected '{}'", other)),
+            }
+
+            // Past the edge even before a live cell lands there.
+            if x > WIDTH || y > HEIGHT {
+                return too_big();
+            }
+
+            run = None;
+        }
+    }
+
+    if cells.is_empty() {
+        return Err("the pattern has no live cells".to_string());
+    }
+
+    Ok(cells)
+}
+
+#[derive(Clone)]
+pub struct SharedWorld(Arc<Mutex<World>>);
+
+impl SharedWorld {
+    pub fn new(world: World) -> Self {
+        Self(Arc::new(Mutex::new(world)))
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, World> {
+        self.0.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rle_reads_runs_rows_and_comments() {
+        let glider = "#N Glider\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!";
+
+        assert_eq!(
+            parse_rle(glider),
+            Ok(vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)])
+        );
+        assert_eq!(parse_rle("o2$b.o!ignored"), Ok(vec![(0, 0), (2, 2)]));
+    }
+
+    #[test]
+    fn parse_rle_rejects_bad_patterns() {
+        assert_eq!(
+            parse_rle("x = 1, y = 1, rule = B36/S23\no!"),
+            Err("only B3/S23 is supported, not B36/S23".to_string())
+        );
+        assert_eq!(parse_rle("oxo!"), Err("unexpected 'x'".to_string()));
+        assert_eq!(
+            parse_rle("3b!"),
+            Err("the pattern has no live cells".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_rle_rejects_patterns_past_the_edge() {
+        let too_big = Err(format!("patterns fit in {}x{}", WIDTH, HEIGHT));
+
+        assert_eq!(parse_rle(&format!("{}o!", WIDTH + 1)), too_big);
+        assert_eq!(parse_rle(&format!("{}$o!", HEIGHT)), too_big);
+        assert_eq!(parse_rle("2b99999999999999999999b$o!"), too_big);
+        assert_eq!(parse_rle("99999999999999999999$o!"), too_big);
+        assert!(parse_rle(&format!("{}bo!", WIDTH - 1)).is_ok());
+    }
+}
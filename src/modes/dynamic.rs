@@ -0,0 +1,250 @@
+use std::thread::{self, JoinHandle};
+
+use crossterm::{event::KeyCode, style::Color};
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+use super::{
+    dynamic_programming::{Problem, SharedTable, Table, INFINITY},
+    Mode,
+};
+use crate::state::{SharedState, State};
+
+pub struct DynamicMode {
+    table: SharedTable,
+    problem_index: usize,
+    /// Every problem's inputs, kept while switching between them.
+    inputs: Vec<Vec<String>>,
+    field: usize,
+    /// Text typed into `field` so far, replacing it once confirmed.
+    editing: Option<String>,
+}
+
+impl Default for DynamicMode {
+    fn default() -> Self {
+        Self {
+            table: SharedTable::new(Table::default()),
+            problem_index: 0,
+            inputs: Problem::ALL.iter().map(Problem::defaults).collect(),
+            field: 0,
+            editing: None,
+        }
+    }
+}
+
+impl DynamicMode {
+    fn problem(&self) -> Problem {
+        Problem::ALL[self.problem_index % Problem::ALL.len()]
+    }
+
+    fn switch_problem(&mut self, increment: usize) -> bool {
+        self.problem_index = (self.problem_index + increment) % Problem::ALL.len();
+        self.field = 0;
+        true
+    }
+
+    fn handle_editing(&mut self, key: KeyCode, mut text: String) -> bool {
+        match key {
+            KeyCode::Enter => {
+                self.inputs[self.problem_index][self.field] = text;
+                return true;
+            }
+            KeyCode::Esc => return false,
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Char(character) if self.problem().accepts(character) => {
+                text.push(character);
+            }
+            _ => {}
+        }
+
+        self.editing = Some(text);
+        false
+    }
+}
+
+impl Mode for DynamicMode {
+    fn name(&self) -> &'static str {
+        "Dynamic Programming"
+    }
+
+    fn speed(&self) -> u32 {
+        60
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        let (problem, table) = (self.problem(), self.table.clone());
+        let inputs = self.inputs[self.problem_index].clone();
+
+        thread::spawn(move || problem.run(&state, &table, inputs))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        if let Some(text) = self.editing.take() {
+            return self.handle_editing(key, text);
+        }
+
+        match key {
+            KeyCode::Char('l') => self.switch_problem(1),
+            KeyCode::Char('h') => self.switch_problem(Problem::ALL.len() - 1),
+            KeyCode::Char('f') => {
+                self.field = (self.field + 1) % self.problem().fields().len();
+                false
+            }
+            KeyCode::Char('e') => {
+                self.editing = Some(self.inputs[self.problem_index][self.field].clone());
+                false
+            }
+            KeyCode::Char('d') => {
+                self.inputs[self.problem_index] = self.problem().defaults();
+                true
+            }
+            KeyCode::Char('r') => true,
+            _ => false,
+        }
+    }
+
+    fn editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    fn overview(&self, state: &State) -> Vec<Line<'static>> {
+        let table = self.table.get();
+        let mut lines: Vec<Line> = self
+            .problem()
+            .fields()
+            .iter()
+            .zip(&self.inputs[self.problem_index])
+            .enumerate()
+            .map(
+                |(index, (field, input))| match (index == self.field, &self.editing) {
+                    (true, Some(text)) => format!("{}: {}_", field, text).fg(Color::Yellow).into(),
+                    (true, None) => format!("{}: {}", field, input).bold().into(),
+                    _ => format!("{}: {}", field, input).into(),
+                },
+            )
+            .collect();
+
+        lines.extend([
+            format!("Cells Filled: {}", state.writes).into(),
+            format!("Dependencies Read: {}", state.array_accesses).into(),
+            format!("Result: {}", table.result.as_deref().unwrap_or("-")).into(),
+        ]);
+
+        lines
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        match self.editing {
+            Some(_) => vec![
+                "Type Input: <A-Z 0-9 Space>".into(),
+                "Confirm: <Enter>".into(),
+                "Cancel: <Esc>".into(),
+            ],
+            None => vec![
+                "Next Field: <F>".into(),
+                "Edit Field: <E>".into(),
+                "Default Inputs: <D>".into(),
+                "Replay: <R>".into(),
+                "Next Problem: <L>".into(),
+                "Previous Problem: <H>".into(),
+            ],
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, _state: &State) {
+        let table = self.table.get();
+        let problem = self.problem();
+
+        let text = |(row, column): (usize, usize)| match table.cells[row][column] {
+            Some(INFINITY) => "∞".to_string(),
+            Some(value) => value.to_string(),
+            None => "·".to_string(),
+        };
+
+        let cells = (0..table.row_labels.len())
+            .flat_map(|row| (0..table.column_labels.len()).map(move |column| (row, column)));
+
+        let width = cells
+            .clone()
+            .map(|cell| text(cell).chars().count())
+            .chain(
+                table
+                    .column_labels
+                    .iter()
+                    .map(|label| label.chars().count()),
+            )
+            .max()
+            .unwrap_or(0)
+            + 2;
+        let label_width = table
+            .row_labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let header =
+            std::iter::once(Span::raw(" ".repeat(label_width)))
+                .chain(table.column_labels.iter().map(|label| {
+                    Span::styled(format!("{:>width$}", label), Style::new().dark_gray())
+                }))
+                .collect::<Vec<_>>();
+
+        let mut lines = vec![Line::from(header)];
+
+        for (row, label) in table.row_labels.iter().enumerate() {
+            let mut spans = vec![Span::styled(
+                format!("{:<label_width$}", label),
+                Style::new().dark_gray(),
+            )];
+
+            for column in 0..table.column_labels.len() {
+                // Matrix chain only ever fills the upper triangle.
+                if problem == Problem::MatrixChain && column < row {
+                    spans.push(Span::raw(" ".repeat(width)));
+                    continue;
+                }
+
+                let cell = (row, column);
+                let style = if Some(cell) == table.current {
+                    Style::new().black().on_yellow()
+                } else if table.dependencies.contains(&cell) {
+                    Style::new().black().on_cyan()
+                } else if table.path.contains(&cell) {
+                    Style::new().green().bold()
+                } else if table.cells[row][column].is_some() {
+                    Style::new().white()
+                } else {
+                    Style::new().dark_gray()
+                };
+
+                spans.push(Span::styled(format!("{:>width$}", text(cell)), style));
+            }
+
+            lines.push(Line::from(spans));
+        }
+
+        let block = Block::new()
+            .title(Line::raw(format!(" {} ", problem.name())).centered())
+            .title_bottom(
+                Line::from(vec![
+                    " filling ".fg(Color::Yellow),
+                    " depends on ".fg(Color::Cyan),
+                    " traceback ".fg(Color::Green),
+                ])
+                .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        frame.render_widget(Paragraph::new(lines).centered().block(block), area);
+    }
+}
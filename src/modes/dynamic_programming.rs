@@ -0,0 +1,590 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::state::{SharedState, Status};
+
+/// Cells that can't be reached, such as amounts no coins add up to.
+pub const INFINITY: i64 = i64::MAX;
+/// Characters per string, so the table stays on screen.
+const MAX_LETTERS: usize = 12;
+/// Largest knapsack capacity or coin amount, one column each.
+const MAX_COLUMNS: i64 = 24;
+/// Knapsack items or matrices in a chain.
+const MAX_ITEMS: usize = 8;
+/// Largest matrix dimension, keeping every product well inside an `i64`.
+const MAX_DIMENSION: i64 = 9999;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Problem {
+    Lcs,
+    EditDistance,
+    Knapsack,
+    CoinChange,
+    MatrixChain,
+}
+
+impl Problem {
+    pub const ALL: [Problem; 5] = [
+        Problem::Lcs,
+        Problem::EditDistance,
+        Problem::Knapsack,
+        Problem::CoinChange,
+        Problem::MatrixChain,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Problem::Lcs => "Longest Common Subsequence",
+            Problem::EditDistance => "Edit Distance",
+            Problem::Knapsack => "0/1 Knapsack",
+            Problem::CoinChange => "Coin Change",
+            Problem::MatrixChain => "Matrix Chain Multiplication",
+        }
+    }
+
+    /// Names of the inputs, edited as text.
+    pub fn fields(&self) -> &'static [&'static str] {
+        match self {
+            Problem::Lcs | Problem::EditDistance => &["First", "Second"],
+            Problem::Knapsack => &["Weights", "Values", "Capacity"],
+            Problem::CoinChange => &["Coins", "Amount"],
+            Problem::MatrixChain => &["Dimensions"],
+        }
+    }
+
+    pub fn defaults(&self) -> Vec<String> {
+        let defaults: &[&str] = match self {
+            Problem::Lcs => &["ABCBDAB", "BDCABA"],
+            Problem::EditDistance => &["KITTEN", "SITTING"],
+            Problem::Knapsack => &["1 3 4 5", "1 4 5 7", "7"],
+            Problem::CoinChange => &["1 5 6 9", "11"],
+            Problem::MatrixChain => &["30 35 15 5 10 20 25"],
+        };
+
+        defaults.iter().map(|input| input.to_string()).collect()
+    }
+
+    /// Whether `character` may be typed into this problem's inputs.
+    pub fn accepts(&self, character: char) -> bool {
+        match self {
+            Problem::Lcs | Problem::EditDistance => character.is_ascii_alphanumeric(),
+            _ => character.is_ascii_digit() || character == ' ',
+        }
+    }
+
+    pub fn run(&self, state: &SharedState, table: &SharedTable, inputs: Vec<String>) {
+        *table.get() = Table::default();
+
+        state.init_algorithm(self.name().to_string());
+
+        let runner = Runner { state, table };
+
+        let result = match self.parse(&inputs) {
+            Err(error) => Some(error),
+            Ok(Input::Strings(first, second)) => match self {
+                Problem::Lcs => runner.lcs(&first, &second),
+                _ => runner.edit_distance(&first, &second),
+            },
+            Ok(Input::Knapsack(weights, values, capacity)) => {
+                runner.knapsack(&weights, &values, capacity)
+            }
+            Ok(Input::Coins(coins, amount)) => runner.coin_change(&coins, amount),
+            Ok(Input::Chain(dimensions)) => runner.matrix_chain(&dimensions),
+        };
+
+        // Interrupted, so the next run gets a clean table.
+        let Some(result) = result else {
+            return;
+        };
+
+        {
+            let mut table = table.get();
+            table.current = None;
+            table.dependencies.clear();
+            table.result = Some(result.clone());
+        }
+
+        state.log(format!("{}: {}", self.name(), result));
+        state.set_status(Status::Completed);
+    }
+
+    fn parse(&self, inputs: &[String]) -> Result<Input, String> {
+        let input = |index: usize| inputs.get(index).map_or("", |input| input.trim());
+
+        match self {
+            Problem::Lcs | Problem::EditDistance => {
+                let [first, second] = [0, 1].map(|index| input(index).chars().collect::<Vec<_>>());
+
+                if first.len().max(second.len()) > MAX_LETTERS {
+                    return Err(format!("strings are limited to {} letters", MAX_LETTERS));
+                }
+
+                Ok(Input::Strings(first, second))
+            }
+            Problem::Knapsack => {
+                let weights = numbers(input(0), "weights")?;
+                let values = numbers(input(1), "values")?;
+                let capacity = single(input(2), "capacity")?;
+
+                if weights.len() != values.len() {
+                    return Err("every weight needs a value".to_string());
+                }
+
+                if weights.len() > MAX_ITEMS {
+                    return Err(format!("at most {} items fit", MAX_ITEMS));
+                }
+
+                Ok(Input::Knapsack(weights, values, capacity))
+            }
+            Problem::CoinChange => {
+                let coins = numbers(input(0), "coins")?;
+
+                if coins.is_empty() || coins.contains(&0) {
+                    return Err("coins have to be worth something".to_string());
+                }
+
+                Ok(Input::Coins(coins, single(input(1), "amount")?))
+            }
+            Problem::MatrixChain => {
+                let dimensions = numbers(input(0), "dimensions")?;
+
+                if !(2..=MAX_ITEMS + 1).contains(&dimensions.len()) {
+                    return Err(format!(
+                        "1 to {} matrices need 2 to {} dimensions",
+                        MAX_ITEMS,
+                        MAX_ITEMS + 1
+                    ));
+                }
+
+                if dimensions
+                    .iter()
+                    .any(|&dimension| !(1..=MAX_DIMENSION).contains(&dimension))
+                {
+                    return Err(format!("dimensions run from 1 to {}", MAX_DIMENSION));
+                }
+
+                Ok(Input::Chain(dimensions))
+            }
+        }
+    }
+}
+
+enum Input {
+    Strings(Vec<char>, Vec<char>),
+    Knapsack(Vec<i64>, Vec<i64>, i64),
+    Coins(Vec<i64>, i64),
+    Chain(Vec<i64>),
+}
+
+fn numbers(input: &str, name: &str) -> Result<Vec<i64>, String> {
+    input
+        .split_whitespace()
+        .map(|number| {
+            number
+                .parse::<u32>()
+                .map(i64::from)
+                .map_err(|_| format!("{} are whole numbers", name))
+        })
+        .collect()
+}
+
+/// A single number that also becomes the last column.
+fn single(input: &str, name: &str) -> Result<i64, String> {
+    let number = input
+        .parse::<u32>()
+        .map(i64::from)
+        .map_err(|_| format!("the {} is a whole number", name))?;
+
+    if number > MAX_COLUMNS {
+        return Err(format!("the {} is at most {}", name, MAX_COLUMNS));
+    }
+
+    Ok(number)
+}
+
+#[derive(Default)]
+pub struct Table {
+    pub row_labels: Vec<String>,
+    pub column_labels: Vec<String>,
+    /// `None` until filled, and forever for cells a problem never uses.
+    pub cells: Vec<Vec<Option<i64>>>,
+    pub current: Option<(usize, usize)>,
+    /// Cells the current one is computed from.
+    pub dependencies: Vec<(usize, usize)>,
+    /// Cells visited while tracing the answer back.
+    pub path: Vec<(usize, usize)>,
+    pub result: Option<String>,
+}
+
+impl Table {
+    fn new(row_labels: Vec<String>, column_labels: Vec<String>) -> Self {
+        Self {
+            cells: vec![vec![None; column_labels.len()]; row_labels.len()],
+            row_labels,
+            column_labels,
+            ..Default::default()
+        }
+    }
+
+    fn value(&self, (row, column): (usize, usize)) -> i64 {
+        self.cells[row][column].unwrap_or(INFINITY)
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedTable(Arc<Mutex<Table>>);
+
+impl SharedTable {
+    pub fn new(table: Table) -> Self {
+        Self(Arc::new(Mutex::new(table)))
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, Table> {
+        self.0.lock().unwrap()
+    }
+}
+
+/// Labels for a string's rows or columns, led by one for the empty prefix.
+fn prefixes(letters: &[char]) -> Vec<String> {
+    std::iter::once("ε".to_string())
+        .chain(letters.iter().map(char::to_string))
+        .collect()
+}
+
+fn range(end: i64) -> Vec<String> {
+    (0..=end).map(|index| index.to_string()).collect()
+}
+
+struct Runner<'a> {
+    state: &'a SharedState,
+    table: &'a SharedTable,
+}
+
+impl Runner<'_> {
+    /// Fills `cell` from `dependencies`, counting a write for it and an access
+    /// for every dependency, or returns `None` once interrupted.
+    fn fill(
+        &self,
+        cell: (usize, usize),
+        dependencies: Vec<(usize, usize)>,
+        value: i64,
+    ) -> Option<()> {
+        if self.state.interrupted() {
+            return None;
+        }
+
+        self.state.increment_accesses(dependencies.len() as u32);
+        self.state.increment_writes(1);
+
+        {
+            let mut table = self.table.get();
+            table.cells[cell.0][cell.1] = Some(value);
+            table.current = Some(cell);
+            table.dependencies = dependencies;
+        }
+
+        self.state.sleep(None);
+        Some(())
+    }
+
+    /// Adds `cell` to the traceback, or returns `None` once interrupted.
+    fn trace(&self, cell: (usize, usize)) -> Option<()> {
+        if self.state.interrupted() {
+            return None;
+        }
+
+        {
+            let mut table = self.table.get();
+            table.path.push(cell);
+            table.current = Some(cell);
+            table.dependencies.clear();
+        }
+
+        self.state.sleep(None);
+        Some(())
+    }
+
+    fn value(&self, cell: (usize, usize)) -> i64 {
+        self.table.get().value(cell)
+    }
+
+    fn lcs(&self, first: &[char], second: &[char]) -> Option<String> {
+        *self.table.get() = Table::new(prefixes(first), prefixes(second));
+
+        for i in 0..=first.len() {
+            for j in 0..=second.len() {
+                if i == 0 || j == 0 {
+                    self.fill((i, j), vec![], 0)?;
+                } else if first[i - 1] == second[j - 1] {
+                    let value = self.value((i - 1, j - 1)) + 1;
+                    self.fill((i, j), vec![(i - 1, j - 1)], value)?;
+                } else {
+                    let value = self.value((i - 1, j)).max(self.value((i, j - 1)));
+                    self.fill((i, j), vec![(i - 1, j), (i, j - 1)], value)?;
+                }
+            }
+        }
+
+        let (mut i, mut j) = (first.len(), second.len());
+        let mut common = vec![];
+
+        loop {
+            self.trace((i, j))?;
+
+            if i == 0 || j == 0 {
+                break;
+            }
+
+            if first[i - 1] == second[j - 1] {
+                common.push(first[i - 1]);
+                (i, j) = (i - 1, j - 1);
+            } else if self.value((i - 1, j)) >= self.value((i, j - 1)) {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+        }
+
+        let common: String = common.into_iter().rev().collect();
+        Some(format!(
+            "\"{}\" of length {}",
+            common,
+            common.chars().count()
+        ))
+    }
+
+    fn edit_distance(&self, first: &[char], second: &[char]) -> Option<String> {
+        *self.table.get() = Table::new(prefixes(first), prefixes(second));
+
+        for i in 0..=first.len() {
+            for j in 0..=second.len() {
+                match (i, j) {
+                    (0, _) if j > 0 => self.fill((i, j), vec![(i, j - 1)], j as i64)?,
+                    (_, 0) if i > 0 => self.fill((i, j), vec![(i - 1, j)], i as i64)?,
+                    (0, 0) => self.fill((i, j), vec![], 0)?,
+                    _ => {
+                        let cost = i64::from(first[i - 1] != second[j - 1]);
+                        let value = (self.value((i - 1, j - 1)) + cost)
+                            .min(self.value((i - 1, j)) + 1)
+                            .min(self.value((i, j - 1)) + 1);
+
+                        self.fill((i, j), vec![(i - 1, j - 1), (i - 1, j), (i, j - 1)], value)?;
+                    }
+                }
+            }
+        }
+
+        let (mut i, mut j) = (first.len(), second.len());
+        let [mut substitutions, mut deletions, mut insertions] = [0; 3];
+
+        loop {
+            self.trace((i, j))?;
+
+            if i == 0 && j == 0 {
+                break;
+            }
+
+            let value = self.value((i, j));
+
+            if i > 0 && j > 0 {
+                let cost = i64::from(first[i - 1] != second[j - 1]);
+
+                if self.value((i - 1, j - 1)) + cost == value {
+                    substitutions += cost;
+                    (i, j) = (i - 1, j - 1);
+                    continue;
+                }
+            }
+
+            if i > 0 && self.value((i - 1, j)) + 1 == value {
+                deletions += 1;
+                i -= 1;
+            } else {
+                insertions += 1;
+                j -= 1;
+            }
+        }
+
+        Some(format!(
+            "{} edits: {} substitutions, {} deletions, {} insertions",
+            self.value((first.len(), second.len())),
+            substitutions,
+            deletions,
+            insertions
+        ))
+    }
+
+    fn knapsack(&self, weights: &[i64], values: &[i64], capacity: i64) -> Option<String> {
+        let items = std::iter::once("-".to_string())
+            .chain((1..=weights.len()).map(|item| format!("#{}", item)))
+            .collect();
+        *self.table.get() = Table::new(items, range(capacity));
+
+        let capacity = capacity as usize;
+
+        for i in 0..=weights.len() {
+            for c in 0..=capacity {
+                if i == 0 {
+                    self.fill((i, c), vec![], 0)?;
+                    continue;
+                }
+
+                let weight = weights[i - 1] as usize;
+                let skip = self.value((i - 1, c));
+
+                if weight > c {
+                    self.fill((i, c), vec![(i - 1, c)], skip)?;
+                } else {
+                    let take = self.value((i - 1, c - weight)) + values[i - 1];
+                    self.fill(
+                        (i, c),
+                        vec![(i - 1, c), (i - 1, c - weight)],
+                        skip.max(take),
+                    )?;
+                }
+            }
+        }
+
+        let mut c = capacity;
+        let mut taken = vec![];
+
+        for i in (1..=weights.len()).rev() {
+            self.trace((i, c))?;
+
+            if self.value((i, c)) != self.value((i - 1, c)) {
+                taken.push(i);
+                c -= weights[i - 1] as usize;
+            }
+        }
+
+        self.trace((0, c))?;
+        taken.reverse();
+
+        let weight: i64 = taken.iter().map(|&item| weights[item - 1]).sum();
+        let items: Vec<String> = taken.iter().map(|item| format!("#{}", item)).collect();
+
+        Some(format!(
+            "value {} from items [{}] weighing {}",
+            self.value((weights.len(), capacity)),
+            items.join(", "),
+            weight
+        ))
+    }
+
+    fn coin_change(&self, coins: &[i64], amount: i64) -> Option<String> {
+        *self.table.get() = Table::new(vec!["coins".to_string()], range(amount));
+
+        let amount = amount as usize;
+        self.fill((0, 0), vec![], 0)?;
+
+        for a in 1..=amount {
+            let fits: Vec<usize> = coins
+                .iter()
+                .map(|&coin| coin as usize)
+                .filter(|&coin| coin <= a)
+                .collect();
+
+            let value = fits
+                .iter()
+                .map(|&coin| self.value((0, a - coin)))
+                .filter(|&value| value != INFINITY)
+                .map(|value| value + 1)
+                .min()
+                .unwrap_or(INFINITY);
+
+            self.fill(
+                (0, a),
+                fits.iter().map(|&coin| (0, a - coin)).collect(),
+                value,
+            )?;
+        }
+
+        if self.value((0, amount)) == INFINITY {
+            return Some(format!("no coins add up to {}", amount));
+        }
+
+        let mut a = amount;
+        let mut used = vec![];
+
+        loop {
+            self.trace((0, a))?;
+
+            if a == 0 {
+                break;
+            }
+
+            let value = self.value((0, a));
+            let coin = coins
+                .iter()
+                .map(|&coin| coin as usize)
+                .find(|&coin| coin <= a && self.value((0, a - coin)) + 1 == value)?;
+
+            used.push(coin.to_string());
+            a -= coin;
+        }
+
+        Some(format!(
+            "{} = {} ({} coins)",
+            amount,
+            used.join(" + "),
+            used.len()
+        ))
+    }
+
+    fn matrix_chain(&self, dimensions: &[i64]) -> Option<String> {
+        let count = dimensions.len() - 1;
+        let names: Vec<String> = (1..=count).map(|matrix| format!("A{}", matrix)).collect();
+        *self.table.get() = Table::new(names.clone(), names);
+
+        // Where the cheapest product of matrices i through j splits.
+        let mut split = vec![vec![0; count]; count];
+
+        for i in 0..count {
+            self.fill((i, i), vec![], 0)?;
+        }
+
+        for length in 2..=count {
+            for i in 0..=count - length {
+                let j = i + length - 1;
+                let mut dependencies = vec![];
+                let mut best = INFINITY;
+
+                for k in i..j {
+                    dependencies.extend([(i, k), (k + 1, j)]);
+
+                    let cost = self.value((i, k))
+                        + self.value((k + 1, j))
+                        + dimensions[i] * dimensions[k + 1] * dimensions[j + 1];
+
+                    if cost < best {
+                        best = cost;
+                        split[i][j] = k;
+                    }
+                }
+
+                self.fill((i, j), dependencies, best)?;
+            }
+        }
+
+        let order = self.parenthesize(&split, 0, count - 1)?;
+
+        Some(format!(
+            "{} with {} multiplications",
+            order,
+            self.value((0, count - 1))
+        ))
+    }
+
+    fn parenthesize(&self, split: &[Vec<usize>], i: usize, j: usize) -> Option<String> {
+        self.trace((i, j))?;
+
+        if i == j {
+            return Some(format!("A{}", i + 1));
+        }
+
+        let k = split[i][j];
+        let left = self.parenthesize(split, i, k)?;
+        let right = self.parenthesize(split, k + 1, j)?;
+
+        Some(format!("({}{})", left, right))
+    }
+}
@@ -0,0 +1,665 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::state::{SharedState, Status};
+
+/// Points drawn for the hulls and closest pair.
+const POINTS: usize = 40;
+/// Segments drawn for the sweep, two points each.
+const SEGMENTS: usize = 14;
+/// Generated coordinates run from zero to this.
+pub const COORDINATE_MAX: i64 = 100;
+
+pub type Point = (i64, i64);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Algorithm {
+    Graham,
+    Jarvis,
+    MonotoneChain,
+    QuickHull,
+    ClosestPair,
+    SegmentIntersection,
+}
+
+impl Algorithm {
+    pub const ALL: [Algorithm; 6] = [
+        Algorithm::Graham,
+        Algorithm::Jarvis,
+        Algorithm::MonotoneChain,
+        Algorithm::QuickHull,
+        Algorithm::ClosestPair,
+        Algorithm::SegmentIntersection,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Graham => "Graham Scan",
+            Algorithm::Jarvis => "Jarvis March",
+            Algorithm::MonotoneChain => "Monotone Chain",
+            Algorithm::QuickHull => "QuickHull",
+            Algorithm::ClosestPair => "Closest Pair (Divide and Conquer)",
+            Algorithm::SegmentIntersection => "Segment Intersection Sweep",
+        }
+    }
+
+    /// What the comparison counter counts for this algorithm.
+    pub fn counted(&self) -> &'static str {
+        match self {
+            Algorithm::ClosestPair => "Distance Checks",
+            _ => "Orientation Tests",
+        }
+    }
+
+    pub fn run(&self, state: &SharedState, plane: &SharedPlane, points: Vec<Point>) {
+        *plane.get() = Plane::new(points, *self == Algorithm::SegmentIntersection);
+
+        state.init_algorithm(self.name().to_string());
+
+        let runner = Runner { state, plane };
+
+        let summary = match self {
+            Algorithm::Graham => runner.graham(),
+            Algorithm::Jarvis => runner.jarvis(),
+            Algorithm::MonotoneChain => runner.monotone_chain(),
+            Algorithm::QuickHull => runner.quickhull(),
+            Algorithm::ClosestPair => runner.closest_pair(),
+            Algorithm::SegmentIntersection => runner.segment_intersections(),
+        };
+
+        // Interrupted, so the next run starts over anyway.
+        let Some(summary) = summary else {
+            return;
+        };
+
+        {
+            let mut plane = plane.get();
+            plane.tested = None;
+            plane.region.clear();
+            plane.strip = None;
+            plane.sweep = None;
+            plane.checking = None;
+        }
+
+        state.log(format!(
+            "{}: {}, {} {}",
+            self.name(),
+            summary,
+            state.get_comparisons(),
+            self.counted().to_lowercase()
+        ));
+        state.set_status(Status::Completed);
+    }
+}
+
+/// Distinct points with coordinates up to [`COORDINATE_MAX`], paired up for the sweep.
+pub fn random_points(seed: u64, segments: bool) -> Vec<Point> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let count = match segments {
+        true => SEGMENTS * 2,
+        false => POINTS,
+    };
+
+    let mut points = vec![];
+    while points.len() < count {
+        let point = (
+            rng.random_range(0..=COORDINATE_MAX),
+            rng.random_range(0..=COORDINATE_MAX),
+        );
+
+        if !points.contains(&point) {
+            points.push(point);
+        }
+    }
+
+    points
+}
+
+/// Reads one point per line as `x y`, skipping blank lines and `#` comments.
+/// Repeated points are dropped, as they would only ever tie.
+pub fn parse_points(text: &str) -> Result<Vec<Point>, String> {
+    let mut points = vec![];
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let coordinates: Vec<i64> = line
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("line {}: coordinates are whole numbers", number + 1))?;
+
+        let [x, y] = coordinates[..] else {
+            return Err(format!("line {}: expected \"x y\"", number + 1));
+        };
+
+        // Far enough from overflowing any product of two differences.
+        if x.abs().max(y.abs()) > 1_000_000 {
+            return Err(format!(
+                "line {}: coordinates are at most a million",
+                number + 1
+            ));
+        }
+
+        if !points.contains(&(x, y)) {
+            points.push((x, y));
+        }
+    }
+
+    if points.len() < 2 {
+        return Err("at least two points are needed".to_string());
+    }
+
+    Ok(points)
+}
+
+pub struct Plane {
+    pub points: Vec<Point>,
+    /// Point indexes joined pairwise, only for the sweep.
+    pub segments: Vec<(usize, usize)>,
+    /// The hull so far, in counterclockwise order.
+    pub chain: Vec<usize>,
+    /// Whether the chain's last point joins back up with its first.
+    pub closed: bool,
+    /// The three points of the last orientation test.
+    pub tested: Option<[usize; 3]>,
+    /// Points the current step works on, such as one half of the closest pair.
+    pub region: Vec<usize>,
+    /// The pair of points or segments being checked.
+    pub checking: Option<(usize, usize)>,
+    pub closest: Option<(usize, usize)>,
+    /// Range of x coordinates close enough to the dividing line to check.
+    pub strip: Option<(f64, f64)>,
+    pub sweep: Option<f64>,
+    /// Segments the sweep line currently crosses.
+    pub active: Vec<usize>,
+    pub intersections: Vec<(f64, f64)>,
+}
+
+impl Plane {
+    pub fn new(points: Vec<Point>, segments: bool) -> Self {
+        let segments = match segments {
+            true => (0..points.len() / 2).map(|i| (2 * i, 2 * i + 1)).collect(),
+            false => vec![],
+        };
+
+        Self {
+            points,
+            segments,
+            chain: vec![],
+            closed: false,
+            tested: None,
+            region: vec![],
+            checking: None,
+            closest: None,
+            strip: None,
+            sweep: None,
+            active: vec![],
+            intersections: vec![],
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedPlane(Arc<Mutex<Plane>>);
+
+impl SharedPlane {
+    pub fn new(plane: Plane) -> Self {
+        Self(Arc::new(Mutex::new(plane)))
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, Plane> {
+        self.0.lock().unwrap()
+    }
+}
+
+fn distance((x1, y1): Point, (x2, y2): Point) -> i64 {
+    (x1 - x2).pow(2) + (y1 - y2).pow(2)
+}
+
+struct Runner<'a> {
+    state: &'a SharedState,
+    plane: &'a SharedPlane,
+}
+
+impl Runner<'_> {
+    fn point(&self, index: usize) -> Point {
+        self.plane.get().points[index]
+    }
+
+    /// Twice the signed area of `a`, `b`, `c`: positive when they turn
+    /// counterclockwise, zero when collinear. Counted like a comparison.
+    fn orientation(&self, a: usize, b: usize, c: usize) -> i64 {
+        let ((ax, ay), (bx, by), (cx, cy)) = (self.point(a), self.point(b), self.point(c));
+
+        self.state.increment_comparisons();
+        self.plane.get().tested = Some([a, b, c]);
+
+        (bx - ax) * (cy - ay) - (by - ay) * (cx - ax)
+    }
+
+    /// Shows the latest test, or returns `None` once interrupted.
+    fn step(&self) -> Option<()> {
+        if self.state.interrupted() {
+            return None;
+        }
+
+        self.state.sleep(None);
+        Some(())
+    }
+
+    /// [`Runner::orientation`] followed by a [`Runner::step`].
+    fn orient(&self, a: usize, b: usize, c: usize) -> Option<i64> {
+        let orientation = self.orientation(a, b, c);
+        self.step()?;
+        Some(orientation)
+    }
+
+    fn push(&self, index: usize) {
+        self.plane.get().chain.push(index);
+    }
+
+    fn pop(&self) {
+        self.plane.get().chain.pop();
+    }
+
+    /// Last two points of the chain, once it has two.
+    fn last_two(&self) -> Option<(usize, usize)> {
+        match self.plane.get().chain[..] {
+            [.., second, last] => Some((second, last)),
+            _ => None,
+        }
+    }
+
+    fn finish_hull(&self) -> String {
+        let mut plane = self.plane.get();
+        plane.closed = true;
+        format!(
+            "{} of {} points on the hull",
+            plane.chain.len(),
+            plane.points.len()
+        )
+    }
+
+    /// Sorts by angle around the lowest point, then walks the points in that
+    /// order, dropping every one that would make the chain turn clockwise.
+    fn graham(&self) -> Option<String> {
+        let points = self.plane.get().points.clone();
+        let pivot = (0..points.len())
+            .min_by_key(|&index| (points[index].1, points[index].0))
+            .unwrap_or(0);
+
+        let mut order: Vec<usize> = (0..points.len()).filter(|&index| index != pivot).collect();
+
+        order.sort_by(|&a, &b| match self.orientation(pivot, a, b) {
+            0 => distance(points[pivot], points[a]).cmp(&distance(points[pivot], points[b])),
+            orientation => 0.cmp(&orientation),
+        });
+        self.step()?;
+
+        self.push(pivot);
+
+        for index in order {
+            while let Some((second, last)) = self.last_two() {
+                if self.orient(second, last, index)? > 0 {
+                    break;
+                }
+
+                self.pop();
+            }
+
+            self.push(index);
+            self.step()?;
+        }
+
+        Some(self.finish_hull())
+    }
+
+    /// Starts at the leftmost point and wraps around the set, each time
+    /// taking the point that leaves every other one on its left.
+    fn jarvis(&self) -> Option<String> {
+        let points = self.plane.get().points.clone();
+        let start = (0..points.len())
+            .min_by_key(|&index| points[index])
+            .unwrap_or(0);
+        let mut current = start;
+
+        loop {
+            self.push(current);
+
+            let mut candidate = (current + 1) % points.len();
+
+            for index in 0..points.len() {
+                if index == current || index == candidate {
+                    continue;
+                }
+
+                let orientation = self.orient(current, candidate, index)?;
+                let farther = distance(points[current], points[index])
+                    > distance(points[current], points[candidate]);
+
+                if orientation < 0 || (orientation == 0 && farther) {
+                    candidate = index;
+                }
+            }
+
+            current = candidate;
+
+            if current == start || self.plane.get().chain.len() == points.len() {
+                break;
+            }
+        }
+
+        Some(self.finish_hull())
+    }
+
+    /// Sorts by x and builds the lower hull left to right and the upper hull
+    /// right to left, popping clockwise turns like Graham scan.
+    fn monotone_chain(&self) -> Option<String> {
+        let points = self.plane.get().points.clone();
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        order.sort_by_key(|&index| points[index]);
+
+        for &index in &order {
+            while let Some((second, last)) = self.last_two() {
+                if self.orient(second, last, index)? > 0 {
+                    break;
+                }
+
+                self.pop();
+            }
+
+            self.push(index);
+        }
+
+        let lower = self.plane.get().chain.len();
+
+        for &index in order.iter().rev().skip(1) {
+            while self.plane.get().chain.len() > lower {
+                let (second, last) = self.last_two()?;
+
+                if self.orient(second, last, index)? > 0 {
+                    break;
+                }
+
+                self.pop();
+            }
+
+            self.push(index);
+        }
+
+        // The upper hull ends where the lower one started.
+        if self.plane.get().chain.len() > 2 {
+            self.pop();
+        }
+
+        Some(self.finish_hull())
+    }
+
+    /// Splits the points by the line through the leftmost and rightmost
+    /// ones, then keeps adding the point farthest outside each hull edge.
+    fn quickhull(&self) -> Option<String> {
+        let points = self.plane.get().points.clone();
+        let all: Vec<usize> = (0..points.len()).collect();
+        let left = (0..points.len())
+            .min_by_key(|&index| points[index])
+            .unwrap_or(0);
+        let right = (0..points.len())
+            .max_by_key(|&index| points[index])
+            .unwrap_or(0);
+
+        {
+            let mut plane = self.plane.get();
+            plane.chain = vec![left, right];
+            plane.closed = true;
+        }
+
+        let below = self.outside(left, right, &all)?;
+        let above = self.outside(right, left, &all)?;
+
+        self.expand(left, right, below)?;
+        self.expand(right, left, above)?;
+
+        Some(self.finish_hull())
+    }
+
+    /// Those of `candidates` strictly to the right of the line from `from` to
+    /// `to`, with their orientations.
+    fn outside(&self, from: usize, to: usize, candidates: &[usize]) -> Option<Vec<(usize, i64)>> {
+        let mut outside = vec![];
+
+        for &index in candidates {
+            if index == from || index == to {
+                continue;
+            }
+
+            let orientation = self.orient(from, to, index)?;
+
+            if orientation < 0 {
+                outside.push((index, orientation));
+            }
+        }
+
+        Some(outside)
+    }
+
+    /// Adds the farthest of `outside` between `from` and `to` on the hull and
+    /// goes on with what is still outside the two new edges.
+    fn expand(&self, from: usize, to: usize, outside: Vec<(usize, i64)>) -> Option<()> {
+        // Farthest from the line means the largest area with its endpoints.
+        let Some(&(farthest, _)) = outside.iter().min_by_key(|&&(_, orientation)| orientation)
+        else {
+            return Some(());
+        };
+
+        let outside: Vec<usize> = outside.into_iter().map(|(index, _)| index).collect();
+        self.plane.get().region = outside.clone();
+
+        {
+            let mut plane = self.plane.get();
+            let position = plane
+                .chain
+                .iter()
+                .position(|&index| index == to)
+                .filter(|&position| position > 0)
+                .unwrap_or(plane.chain.len());
+
+            plane.chain.insert(position, farthest);
+        }
+
+        let first = self.outside(from, farthest, &outside)?;
+        let second = self.outside(farthest, to, &outside)?;
+
+        self.expand(from, farthest, first)?;
+        self.expand(farthest, to, second)
+    }
+
+    /// Measures the squared distance between two points, counting it.
+    fn check(&self, a: usize, b: usize) -> Option<i64> {
+        self.state.increment_comparisons();
+        self.plane.get().checking = Some((a, b));
+        self.step()?;
+
+        Some(distance(self.point(a), self.point(b)))
+    }
+
+    fn closest_pair(&self) -> Option<String> {
+        let points = self.plane.get().points.clone();
+        let mut by_x: Vec<usize> = (0..points.len()).collect();
+        by_x.sort_by_key(|&index| points[index]);
+
+        let (squared, (a, b)) = self.closest(&by_x)?;
+
+        Some(format!(
+            "({}, {}) and ({}, {}) are {:.2} apart",
+            points[a].0,
+            points[a].1,
+            points[b].0,
+            points[b].1,
+            (squared as f64).sqrt()
+        ))
+    }
+
+    /// Keeps `pair` as the closest one if it beats `best`.
+    fn consider(&self, best: &mut (i64, (usize, usize)), squared: i64, pair: (usize, usize)) {
+        if squared < best.0 {
+            *best = (squared, pair);
+            self.plane.get().closest = Some(pair);
+        }
+    }
+
+    /// Closest pair among `sorted`, ordered by x: halves solved on their own,
+    /// then only pairs straddling the middle closer than the best so far.
+    fn closest(&self, sorted: &[usize]) -> Option<(i64, (usize, usize))> {
+        self.plane.get().region = sorted.to_vec();
+
+        let mut best = (i64::MAX, (sorted[0], sorted[0]));
+
+        if sorted.len() <= 3 {
+            for (i, &a) in sorted.iter().enumerate() {
+                for &b in &sorted[i + 1..] {
+                    self.consider(&mut best, self.check(a, b)?, (a, b));
+                }
+            }
+
+            return Some(best);
+        }
+
+        let middle = sorted.len() / 2;
+        let (left, right) = (
+            self.closest(&sorted[..middle])?,
+            self.closest(&sorted[middle..])?,
+        );
+        self.consider(&mut best, left.0, left.1);
+        self.consider(&mut best, right.0, right.1);
+
+        let divide = self.point(sorted[middle]).0;
+        let reach = (best.0 as f64).sqrt();
+
+        {
+            let mut plane = self.plane.get();
+            plane.region = sorted.to_vec();
+            plane.strip = Some((divide as f64 - reach, divide as f64 + reach));
+            plane.closest = Some(best.1);
+        }
+
+        let mut strip: Vec<usize> = sorted
+            .iter()
+            .copied()
+            .filter(|&index| (self.point(index).0 - divide).pow(2) < best.0)
+            .collect();
+        strip.sort_by_key(|&index| self.point(index).1);
+
+        // Only a handful of points above each can be close enough, so this stays linear.
+        for (i, &a) in strip.iter().enumerate() {
+            for &b in &strip[i + 1..] {
+                if (self.point(b).1 - self.point(a).1).pow(2) >= best.0 {
+                    break;
+                }
+
+                self.consider(&mut best, self.check(a, b)?, (a, b));
+            }
+        }
+
+        self.plane.get().strip = None;
+        Some(best)
+    }
+
+    /// Sweeps a vertical line from left to right. Each segment, as the line
+    /// reaches it, is tested against every segment the line crosses.
+    fn segment_intersections(&self) -> Option<String> {
+        let (points, segments) = {
+            let plane = self.plane.get();
+            (plane.points.clone(), plane.segments.clone())
+        };
+
+        // Starts before ends at the same x, so segments that only touch still meet.
+        let mut events: Vec<(i64, bool, usize)> = segments
+            .iter()
+            .enumerate()
+            .flat_map(|(segment, &(a, b))| {
+                let (start, end) = (points[a].0.min(points[b].0), points[a].0.max(points[b].0));
+                [(start, false, segment), (end, true, segment)]
+            })
+            .collect();
+        events.sort();
+
+        for (x, ends, segment) in events {
+            self.plane.get().sweep = Some(x as f64);
+
+            if ends {
+                self.plane.get().active.retain(|&other| other != segment);
+                self.step()?;
+                continue;
+            }
+
+            let active = self.plane.get().active.clone();
+
+            for other in active {
+                self.plane.get().checking = Some((segment, other));
+
+                if let Some(point) = self.intersection(segments[segment], segments[other]) {
+                    self.plane.get().intersections.push(point);
+                }
+
+                self.step()?;
+            }
+
+            self.plane.get().checking = None;
+            self.plane.get().active.push(segment);
+            self.step()?;
+        }
+
+        Some(format!(
+            "{} intersections among {} segments",
+            self.plane.get().intersections.len(),
+            segments.len()
+        ))
+    }
+
+    /// Where two segments meet, from the four orientations of each one's
+    /// endpoints against the other.
+    fn intersection(&self, (a, b): (usize, usize), (c, d): (usize, usize)) -> Option<(f64, f64)> {
+        let [abc, abd, cda, cdb] = [
+            self.orientation(a, b, c),
+            self.orientation(a, b, d),
+            self.orientation(c, d, a),
+            self.orientation(c, d, b),
+        ];
+
+        let [a, b, c, d] = [a, b, c, d].map(|index| self.point(index));
+        let within = |(px, py): Point, (qx, qy): Point, (x, y): Point| {
+            (px.min(qx)..=px.max(qx)).contains(&x) && (py.min(qy)..=py.max(qy)).contains(&y)
+        };
+
+        // Touching or overlapping while collinear meets at a shared point.
+        let touching = [
+            (abc, a, b, c),
+            (abd, a, b, d),
+            (cda, c, d, a),
+            (cdb, c, d, b),
+        ]
+        .into_iter()
+        .find(|&(orientation, p, q, r)| orientation == 0 && within(p, q, r));
+
+        if let Some((_, _, _, (x, y))) = touching {
+            return Some((x as f64, y as f64));
+        }
+
+        if abc.signum() * abd.signum() >= 0 || cda.signum() * cdb.signum() >= 0 {
+            return None;
+        }
+
+        // Proper crossing, at the fraction of cd given by the areas of abc and abd.
+        let t = abc as f64 / (abc - abd) as f64;
+        Some((
+            c.0 as f64 + t * (d.0 - c.0) as f64,
+            c.1 as f64 + t * (d.1 - c.1) as f64,
+        ))
+    }
+}
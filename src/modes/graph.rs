@@ -0,0 +1,606 @@
+use std::{
+    f64::consts::TAU,
+    fs,
+    sync::{Arc, Mutex, MutexGuard},
+    thread::{self, JoinHandle},
+};
+
+use crossterm::{event::KeyCode, style::Color};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use ratatui::{
+    layout::{Constraint, Direction, Layout as Split, Rect},
+    style::{Style, Stylize},
+    symbols,
+    text::Line,
+    widgets::{
+        canvas::{self, Canvas, Circle},
+        Block, BorderType, Borders, Cell, Row, Table,
+    },
+    Frame,
+};
+
+use super::{graph_algorithms::GraphAlgorithm, Mode};
+use crate::state::{SharedState, State, Status};
+
+/// Nodes in a randomly generated graph.
+pub const NODES: usize = 10;
+/// Most nodes an imported graph may have, as many as the canvas and the
+/// distance table have room for.
+pub const MAX_NODES: usize = 20;
+/// Edges added on top of the spanning tree that keeps a random graph connected.
+const EXTRA_EDGES: usize = 8;
+/// Chance of a random edge pointing against the node order, closing a cycle.
+const BACK_EDGE_CHANCE: f64 = 0.15;
+pub const MAX_WEIGHT: i64 = 9;
+/// Largest weight either way an imported edge may have, which keeps the sum
+/// along any path, and every total, far inside `i64`.
+pub const MAX_IMPORTED_WEIGHT: i64 = MAX_WEIGHT * 1000;
+/// Edge list read by the import key, relative to the working directory.
+pub const GRAPH_FILE: &str = "graph.txt";
+const NODE_RADIUS: f64 = 0.07;
+
+#[derive(Clone, Copy)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub weight: i64,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Layout {
+    Circular,
+    Force,
+}
+
+impl Layout {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Layout::Circular => "Circular",
+            Layout::Force => "Force-Directed",
+        }
+    }
+
+    /// Node positions within `[-1, 1]` on both axes.
+    pub fn positions(&self, nodes: usize, edges: &[Edge]) -> Vec<(f64, f64)> {
+        let mut positions: Vec<(f64, f64)> = (0..nodes)
+            .map(|i| {
+                let angle = TAU * i as f64 / nodes as f64;
+                (angle.sin(), angle.cos())
+            })
+            .collect();
+
+        if *self == Layout::Circular || nodes < 2 {
+            return positions;
+        }
+
+        // Fruchterman-Reingold from the circle, so the layout is deterministic.
+        let k = (4.0 / nodes as f64).sqrt();
+
+        for iteration in 0..200 {
+            let temperature = 0.1 * (1.0 - f64::from(iteration) / 200.0);
+            let mut forces = vec![(0.0, 0.0); nodes];
+
+            for i in 0..nodes {
+                for j in 0..nodes {
+                    if i == j {
+                        continue;
+                    }
+
+                    let (dx, dy) = (
+                        positions[i].0 - positions[j].0,
+                        positions[i].1 - positions[j].1,
+                    );
+                    let distance = (dx * dx + dy * dy).sqrt().max(0.01);
+                    let repulsion = k * k / distance;
+
+                    forces[i].0 += dx / distance * repulsion;
+                    forces[i].1 += dy / distance * repulsion;
+                }
+            }
+
+            for edge in edges {
+                let (from, to) = (positions[edge.from], positions[edge.to]);
+                let (dx, dy) = (from.0 - to.0, from.1 - to.1);
+                let distance = (dx * dx + dy * dy).sqrt().max(0.01);
+                let attraction = distance * distance / k;
+
+                forces[edge.from].0 -= dx / distance * attraction;
+                forces[edge.from].1 -= dy / distance * attraction;
+                forces[edge.to].0 += dx / distance * attraction;
+                forces[edge.to].1 += dy / distance * attraction;
+            }
+
+            for (position, (fx, fy)) in positions.iter_mut().zip(forces) {
+                let length = (fx * fx + fy * fy).sqrt().max(0.01);
+                position.0 += fx / length * length.min(temperature);
+                position.1 += fy / length * length.min(temperature);
+            }
+        }
+
+        let extent = positions
+            .iter()
+            .map(|(x, y)| x.abs().max(y.abs()))
+            .fold(0.01, f64::max);
+
+        positions
+            .into_iter()
+            .map(|(x, y)| (x / extent, y / extent))
+            .collect()
+    }
+}
+
+/// A directed weighted graph plus what the running algorithm has found so far.
+/// Minimum spanning trees ignore the edge directions.
+pub struct Graph {
+    pub nodes: usize,
+    pub edges: Vec<Edge>,
+    pub positions: Vec<(f64, f64)>,
+    /// Edges in the spanning tree or shortest path tree.
+    pub chosen: Vec<bool>,
+    pub visited: Vec<bool>,
+    /// Distance, position in the order or component shown next to each node.
+    pub values: Vec<Option<i64>>,
+    pub components: Vec<Option<usize>>,
+    /// Floyd–Warshall's distance matrix, `None` being unreachable.
+    pub matrix: Vec<Vec<Option<i64>>>,
+    /// The intermediate node Floyd–Warshall is currently trying.
+    pub via: Option<usize>,
+    pub current: Option<usize>,
+}
+
+impl Graph {
+    pub fn new(nodes: usize, edges: Vec<Edge>, layout: Layout) -> Self {
+        Self {
+            nodes,
+            positions: layout.positions(nodes, &edges),
+            chosen: vec![false; edges.len()],
+            edges,
+            visited: vec![false; nodes],
+            values: vec![None; nodes],
+            components: vec![None; nodes],
+            matrix: vec![],
+            via: None,
+            current: None,
+        }
+    }
+
+    /// A connected graph of [`NODES`] nodes, mostly pointing from lower to higher nodes.
+    pub fn random(seed: u64, layout: Layout) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut edges: Vec<Edge> = vec![];
+
+        let mut connect = |rng: &mut StdRng, a: usize, b: usize| {
+            let (from, to) = match rng.random_bool(BACK_EDGE_CHANCE) {
+                true => (a.max(b), a.min(b)),
+                false => (a.min(b), a.max(b)),
+            };
+
+            if from != to
+                && !edges
+                    .iter()
+                    .any(|e| e.from.min(e.to) == a.min(b) && e.from.max(e.to) == a.max(b))
+            {
+                edges.push(Edge {
+                    from,
+                    to,
+                    weight: rng.random_range(1..=MAX_WEIGHT),
+                });
+            }
+        };
+
+        for node in 1..NODES {
+            let other = rng.random_range(0..node);
+            connect(&mut rng, other, node);
+        }
+
+        for _ in 0..EXTRA_EDGES {
+            let (a, b) = (rng.random_range(0..NODES), rng.random_range(0..NODES));
+            connect(&mut rng, a, b);
+        }
+
+        Self::new(NODES, edges, layout)
+    }
+
+    /// Reads one `from to [weight]` edge per line, nodes numbered from zero up
+    /// to [`MAX_NODES`] and the weight defaulting to one, at most
+    /// [`MAX_IMPORTED_WEIGHT`] either way. Lines starting with `#` are skipped.
+    pub fn from_edge_list(text: &str, layout: Layout) -> Result<Self, String> {
+        let mut edges = vec![];
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let parse = |field: &str| {
+                field
+                    .parse::<i64>()
+                    .map_err(|_| format!("line {}: invalid number '{}'", number + 1, field))
+            };
+
+            let (from, to, weight) = match fields[..] {
+                [from, to] => (parse(from)?, parse(to)?, 1),
+                [from, to, weight] => (parse(from)?, parse(to)?, parse(weight)?),
+                _ => return Err(format!("line {}: expected 'from to [weight]'", number + 1)),
+            };
+
+            if from < 0 || to < 0 {
+                return Err(format!("line {}: negative node", number + 1));
+            }
+
+            if from.max(to) >= MAX_NODES as i64 {
+                return Err(format!(
+                    "line {}: nodes are numbered below {}",
+                    number + 1,
+                    MAX_NODES
+                ));
+            }
+
+            if !(-MAX_IMPORTED_WEIGHT..=MAX_IMPORTED_WEIGHT).contains(&weight) {
+                return Err(format!(
+                    "line {}: weights are at most {} either way",
+                    number + 1,
+                    MAX_IMPORTED_WEIGHT
+                ));
+            }
+
+            edges.push(Edge {
+                from: from as usize,
+                to: to as usize,
+                weight,
+            });
+        }
+
+        let nodes = edges
+            .iter()
+            .map(|e| e.from.max(e.to) + 1)
+            .max()
+            .unwrap_or(0);
+
+        if nodes == 0 {
+            return Err("no edges".to_string());
+        }
+
+        Ok(Self::new(nodes, edges, layout))
+    }
+
+    /// Forgets everything a previous algorithm found.
+    pub fn clear_results(&mut self) {
+        self.chosen.fill(false);
+        self.visited.fill(false);
+        self.values.fill(None);
+        self.components.fill(None);
+        self.matrix.clear();
+        self.via = None;
+        self.current = None;
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedGraph(Arc<Mutex<Graph>>);
+
+impl SharedGraph {
+    pub fn new(graph: Graph) -> Self {
+        Self(Arc::new(Mutex::new(graph)))
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, Graph> {
+        self.0.lock().unwrap()
+    }
+}
+
+pub struct GraphMode {
+    graph: SharedGraph,
+    algorithm_index: usize,
+    layout: Layout,
+    /// Seed of the random graph, or `None` once one is imported.
+    seed: Option<u64>,
+    /// Outcome of the last import.
+    file: Option<String>,
+    /// Replaces the graph once the running algorithm has stopped using it.
+    pending: Option<Graph>,
+}
+
+impl Default for GraphMode {
+    fn default() -> Self {
+        let seed = u64::from(rand::rng().random::<u32>());
+
+        Self {
+            graph: SharedGraph::new(Graph::random(seed, Layout::Circular)),
+            algorithm_index: 0,
+            layout: Layout::Circular,
+            seed: Some(seed),
+            file: None,
+            pending: None,
+        }
+    }
+}
+
+impl GraphMode {
+    fn algorithm(&self) -> GraphAlgorithm {
+        GraphAlgorithm::ALL[self.algorithm_index % GraphAlgorithm::ALL.len()]
+    }
+
+    fn import(&mut self) -> bool {
+        let graph = fs::read_to_string(GRAPH_FILE)
+            .map_err(|error| error.to_string())
+            .and_then(|text| Graph::from_edge_list(&text, self.layout));
+
+        match graph {
+            Ok(graph) => {
+                self.pending = Some(graph);
+                self.seed = None;
+                self.file = Some(format!("imported from {}", GRAPH_FILE));
+                true
+            }
+            Err(error) => {
+                self.file = Some(format!("import failed: {}", error));
+                false
+            }
+        }
+    }
+
+    fn draw_matrix(&self, frame: &mut Frame, area: Rect, graph: &Graph, comparison: [u32; 2]) {
+        let [i, j] = comparison.map(|index| index as usize);
+
+        let header = Row::new(
+            std::iter::once(Cell::from(""))
+                .chain((0..graph.nodes).map(|node| Cell::from(node.to_string()))),
+        )
+        .style(Style::new().bold());
+
+        let rows = graph.matrix.iter().enumerate().map(|(row, distances)| {
+            Row::new(std::iter::once(Cell::from(row.to_string()).bold()).chain(
+                distances.iter().enumerate().map(|(column, distance)| {
+                    let text = distance.map_or("∞".to_string(), |d| d.to_string());
+                    let cell = Cell::from(text);
+
+                    if (row, column) == (i, j) {
+                        cell.red().bold()
+                    } else if Some(row) == graph.via || Some(column) == graph.via {
+                        cell.yellow()
+                    } else {
+                        cell
+                    }
+                }),
+            ))
+        });
+
+        let widths = std::iter::once(Constraint::Length(3))
+            .chain((0..graph.nodes).map(|_| Constraint::Length(4)));
+
+        let table = Table::new(rows, widths).header(header).block(
+            Block::new()
+                .title(Line::raw(" Distances ").centered())
+                .borders(Borders::ALL)
+                .border_type(BorderType::Thick),
+        );
+
+        frame.render_widget(table, area);
+    }
+}
+
+impl Mode for GraphMode {
+    fn name(&self) -> &'static str {
+        "Graph"
+    }
+
+    fn speed(&self) -> u32 {
+        self.algorithm().speed()
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        if let Some(graph) = self.pending.take() {
+            *self.graph.get() = graph;
+        }
+
+        let (algorithm, graph) = (self.algorithm(), self.graph.clone());
+
+        thread::spawn(move || algorithm.run(&state, &graph))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        let count = GraphAlgorithm::ALL.len();
+
+        match key {
+            KeyCode::Char('l') => self.algorithm_index = (self.algorithm_index + 1) % count,
+            KeyCode::Char('h') => self.algorithm_index = (self.algorithm_index + count - 1) % count,
+            KeyCode::Char('g') => {
+                let seed = u64::from(rand::rng().random::<u32>());
+                self.pending = Some(Graph::random(seed, self.layout));
+                self.seed = Some(seed);
+            }
+            KeyCode::Char('i') => return self.import(),
+            KeyCode::Char('y') => {
+                self.layout = match self.layout {
+                    Layout::Circular => Layout::Force,
+                    Layout::Force => Layout::Circular,
+                };
+
+                let mut graph = self.graph.get();
+                graph.positions = self.layout.positions(graph.nodes, &graph.edges);
+                return false;
+            }
+            KeyCode::Char('r') => {}
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn overview(&self, state: &State) -> Vec<Line<'static>> {
+        let graph = self.graph.get();
+
+        let mut overview: Vec<Line> = vec![
+            format!("Graph: {} nodes, {} edges", graph.nodes, graph.edges.len()).into(),
+            format!(
+                "Seed: {}",
+                self.seed.map_or("-".to_string(), |seed| seed.to_string())
+            )
+            .into(),
+            format!("Layout: {}", self.layout.name()).into(),
+            format!("Edges Examined: {}", state.comparisons).into(),
+            format!(
+                "Chosen Edges: {}",
+                graph.chosen.iter().filter(|&&chosen| chosen).count()
+            )
+            .into(),
+        ];
+
+        if let Some(file) = &self.file {
+            overview.push(format!("File: {}", file).into());
+        }
+
+        overview
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        vec![
+            "Rerun: <R>".into(),
+            "Next: <L>".into(),
+            "Previous: <H>".into(),
+            "Random Graph: <G>".into(),
+            format!("Import: <I> ({})", GRAPH_FILE).into(),
+            "Layout: <Y>".into(),
+        ]
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, state: &State) {
+        let graph = self.graph.get();
+        let algorithm = self.algorithm();
+        let component_colors = [
+            Color::Cyan,
+            Color::Magenta,
+            Color::Yellow,
+            Color::Blue,
+            Color::DarkGreen,
+            Color::DarkCyan,
+        ];
+
+        // Only the running algorithm's examined edge is of interest.
+        let comparison = match state.status {
+            Status::Running | Status::Paused => state.comparison,
+            _ => [u32::MAX; 2],
+        };
+        let examined = |edge: &Edge| {
+            let pair = [edge.from as u32, edge.to as u32];
+            pair == comparison || (!algorithm.directed() && pair == [comparison[1], comparison[0]])
+        };
+
+        let area = match algorithm {
+            GraphAlgorithm::FloydWarshall if !graph.matrix.is_empty() => {
+                let split = Split::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(area);
+
+                self.draw_matrix(frame, split[1], &graph, comparison);
+                split[0]
+            }
+            _ => area,
+        };
+
+        let legend = Line::from(vec![
+            " examined ".fg(Color::Red),
+            " chosen ".fg(Color::Green),
+            " visited ".fg(Color::Blue),
+            " components ".fg(Color::Magenta),
+        ]);
+
+        let block = Block::new()
+            .title(Line::raw(format!(" {} ", algorithm.name())).centered())
+            .title_bottom(legend.centered())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(symbols::Marker::Braille)
+            .x_bounds([-1.25, 1.25])
+            .y_bounds([-1.25, 1.25])
+            .paint(|ctx| {
+                for (index, edge) in graph.edges.iter().enumerate() {
+                    let color = if examined(edge) {
+                        Color::Red
+                    } else if graph.chosen[index] {
+                        Color::Green
+                    } else {
+                        Color::DarkGrey
+                    };
+
+                    let (from, to) = (graph.positions[edge.from], graph.positions[edge.to]);
+                    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+                    let length = (dx * dx + dy * dy).sqrt().max(0.01);
+                    let (ux, uy) = (dx / length, dy / length);
+                    let tip = (to.0 - ux * NODE_RADIUS, to.1 - uy * NODE_RADIUS);
+
+                    ctx.draw(&canvas::Line::new(
+                        from.0 + ux * NODE_RADIUS,
+                        from.1 + uy * NODE_RADIUS,
+                        tip.0,
+                        tip.1,
+                        color.into(),
+                    ));
+
+                    if algorithm.directed() {
+                        for angle in [0.4_f64, -0.4] {
+                            let (sin, cos) = angle.sin_cos();
+                            let (bx, by) = (-ux * cos + uy * sin, -ux * sin - uy * cos);
+
+                            ctx.draw(&canvas::Line::new(
+                                tip.0,
+                                tip.1,
+                                tip.0 + bx * 0.08,
+                                tip.1 + by * 0.08,
+                                color.into(),
+                            ));
+                        }
+                    }
+                }
+
+                for (node, &(x, y)) in graph.positions.iter().enumerate() {
+                    let color = if Some(node) == graph.current {
+                        Color::Red
+                    } else if let Some(component) = graph.components[node] {
+                        component_colors[component % component_colors.len()]
+                    } else if graph.visited[node] {
+                        Color::Blue
+                    } else {
+                        Color::White
+                    };
+
+                    ctx.draw(&Circle {
+                        x,
+                        y,
+                        radius: NODE_RADIUS,
+                        color: color.into(),
+                    });
+                }
+
+                ctx.layer();
+
+                for edge in &graph.edges {
+                    let (from, to) = (graph.positions[edge.from], graph.positions[edge.to]);
+                    ctx.print(
+                        (from.0 + to.0) / 2.0,
+                        (from.1 + to.1) / 2.0,
+                        edge.weight.to_string().dark_gray(),
+                    );
+                }
+
+                for (node, &(x, y)) in graph.positions.iter().enumerate() {
+                    let label = match graph.values[node] {
+                        Some(value) => format!("{} ({})", node, value),
+                        None => node.to_string(),
+                    };
+
+                    ctx.print(x + NODE_RADIUS, y + NODE_RADIUS, label.bold());
+                }
+            });
+
+        frame.render_widget(canvas, area);
+    }
+}
@@ -0,0 +1,522 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use super::graph::{Edge, SharedGraph};
+use crate::state::{SharedState, Status};
+
+/// Node the single-source algorithms start from.
+pub const SOURCE: usize = 0;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum GraphAlgorithm {
+    Kruskal,
+    Prim,
+    BellmanFord,
+    FloydWarshall,
+    TopologicalSort,
+    Tarjan,
+    Kosaraju,
+}
+
+impl GraphAlgorithm {
+    pub const ALL: [GraphAlgorithm; 7] = [
+        GraphAlgorithm::Kruskal,
+        GraphAlgorithm::Prim,
+        GraphAlgorithm::BellmanFord,
+        GraphAlgorithm::FloydWarshall,
+        GraphAlgorithm::TopologicalSort,
+        GraphAlgorithm::Tarjan,
+        GraphAlgorithm::Kosaraju,
+    ];
+
+    /// Floyd-Warshall takes a step for every pair of nodes through every other.
+    pub fn speed(&self) -> u32 {
+        match self {
+            GraphAlgorithm::FloydWarshall => 80,
+            _ => 5,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GraphAlgorithm::Kruskal => "Kruskal",
+            GraphAlgorithm::Prim => "Prim",
+            GraphAlgorithm::BellmanFord => "Bellman-Ford",
+            GraphAlgorithm::FloydWarshall => "Floyd–Warshall",
+            GraphAlgorithm::TopologicalSort => "Topological Sort",
+            GraphAlgorithm::Tarjan => "Tarjan SCC",
+            GraphAlgorithm::Kosaraju => "Kosaraju SCC",
+        }
+    }
+
+    /// Whether the algorithm follows edge directions, which spanning trees ignore.
+    pub fn directed(&self) -> bool {
+        !matches!(self, GraphAlgorithm::Kruskal | GraphAlgorithm::Prim)
+    }
+
+    pub fn run(&self, state: &SharedState, graph: &SharedGraph) {
+        let (nodes, edges) = {
+            let mut graph = graph.get();
+            graph.clear_results();
+            (graph.nodes, graph.edges.clone())
+        };
+
+        let mut outgoing = vec![vec![]; nodes];
+        let mut incoming = vec![vec![]; nodes];
+
+        for (index, edge) in edges.iter().enumerate() {
+            outgoing[edge.from].push(index);
+            incoming[edge.to].push(index);
+        }
+
+        let runner = Runner {
+            state,
+            graph,
+            nodes,
+            edges,
+            outgoing,
+            incoming,
+        };
+
+        state.init_algorithm(self.name().to_string());
+
+        let summary = match self {
+            GraphAlgorithm::Kruskal => runner.kruskal(),
+            GraphAlgorithm::Prim => runner.prim(),
+            GraphAlgorithm::BellmanFord => runner.bellman_ford(),
+            GraphAlgorithm::FloydWarshall => runner.floyd_warshall(),
+            GraphAlgorithm::TopologicalSort => runner.topological_sort(),
+            GraphAlgorithm::Tarjan => runner.tarjan(),
+            GraphAlgorithm::Kosaraju => runner.kosaraju(),
+        };
+
+        // Interrupted, so whatever replaced the graph is left alone.
+        let Some(summary) = summary else {
+            return;
+        };
+
+        {
+            let mut graph = graph.get();
+            graph.current = None;
+            graph.via = None;
+        }
+
+        state.log(format!("{}: {}", self.name(), summary));
+        state.set_status(Status::Completed);
+    }
+}
+
+struct Runner<'a> {
+    state: &'a SharedState,
+    graph: &'a SharedGraph,
+    nodes: usize,
+    edges: Vec<Edge>,
+    /// Indexes into `edges` leaving and entering each node.
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl Runner<'_> {
+    /// Shows the pair of nodes being looked at, counting it as a comparison,
+    /// or returns `None` once interrupted.
+    fn examine(&self, from: usize, to: usize) -> Option<()> {
+        if self.state.interrupted() {
+            return None;
+        }
+
+        self.state.set_comparison([from as u32, to as u32]);
+        self.state.increment_comparisons();
+        self.graph.get().current = Some(from);
+        self.state.sleep(None);
+
+        Some(())
+    }
+
+    fn visit(&self, node: usize) {
+        self.graph.get().visited[node] = true;
+    }
+
+    fn choose(&self, edge: usize) {
+        let mut graph = self.graph.get();
+        graph.chosen[edge] = true;
+        graph.visited[self.edges[edge].from] = true;
+        graph.visited[self.edges[edge].to] = true;
+    }
+
+    /// Takes the lightest edges first, skipping those that would close a cycle.
+    fn kruskal(&self) -> Option<String> {
+        let mut order: Vec<usize> = (0..self.edges.len()).collect();
+        order.sort_by_key(|&index| self.edges[index].weight);
+
+        let mut parent: Vec<usize> = (0..self.nodes).collect();
+        let find = |parent: &mut Vec<usize>, mut node: usize| {
+            while parent[node] != node {
+                parent[node] = parent[parent[node]];
+                node = parent[node];
+            }
+            node
+        };
+
+        let (mut count, mut total) = (0, 0);
+
+        for index in order {
+            let edge = self.edges[index];
+            self.examine(edge.from, edge.to)?;
+
+            let (from, to) = (find(&mut parent, edge.from), find(&mut parent, edge.to));
+
+            if from != to {
+                parent[from] = to;
+                self.choose(index);
+                count += 1;
+                total += edge.weight;
+            }
+        }
+
+        Some(format!(
+            "spanning forest of {} edges weighing {}",
+            count, total
+        ))
+    }
+
+    /// Grows a tree from each unreached node through the lightest edge leaving it.
+    fn prim(&self) -> Option<String> {
+        let mut in_tree = vec![false; self.nodes];
+        let mut frontier = BinaryHeap::new();
+        let (mut count, mut total) = (0, 0);
+
+        let push = |frontier: &mut BinaryHeap<_>, in_tree: &[bool], node: usize| {
+            for &index in self.outgoing[node].iter().chain(&self.incoming[node]) {
+                let edge: Edge = self.edges[index];
+                let other = if edge.from == node {
+                    edge.to
+                } else {
+                    edge.from
+                };
+
+                if !in_tree[other] {
+                    frontier.push(Reverse((edge.weight, index, other)));
+                }
+            }
+        };
+
+        for root in 0..self.nodes {
+            if in_tree[root] {
+                continue;
+            }
+
+            in_tree[root] = true;
+            self.visit(root);
+            push(&mut frontier, &in_tree, root);
+
+            while let Some(Reverse((weight, index, node))) = frontier.pop() {
+                let edge = self.edges[index];
+                self.examine(edge.from, edge.to)?;
+
+                if in_tree[node] {
+                    continue;
+                }
+
+                in_tree[node] = true;
+                self.choose(index);
+                count += 1;
+                total += weight;
+                push(&mut frontier, &in_tree, node);
+            }
+        }
+
+        Some(format!(
+            "spanning forest of {} edges weighing {}",
+            count, total
+        ))
+    }
+
+    /// Relaxes every edge until nothing changes, then once more to find negative cycles.
+    fn bellman_ford(&self) -> Option<String> {
+        let mut distance: Vec<Option<i64>> = vec![None; self.nodes];
+        let mut predecessor: Vec<Option<usize>> = vec![None; self.nodes];
+
+        distance[SOURCE] = Some(0);
+        self.graph.get().values[SOURCE] = Some(0);
+        self.visit(SOURCE);
+
+        let mut rounds = 0;
+
+        for _ in 1..self.nodes {
+            let mut changed = false;
+            rounds += 1;
+
+            for (index, edge) in self.edges.iter().enumerate() {
+                self.examine(edge.from, edge.to)?;
+
+                let Some(from) = distance[edge.from] else {
+                    continue;
+                };
+
+                if distance[edge.to].is_none_or(|to| from + edge.weight < to) {
+                    distance[edge.to] = Some(from + edge.weight);
+                    predecessor[edge.to] = Some(index);
+                    changed = true;
+
+                    let mut graph = self.graph.get();
+                    graph.values[edge.to] = distance[edge.to];
+                    graph.visited[edge.to] = true;
+                    graph.chosen.fill(false);
+
+                    for &index in predecessor.iter().flatten() {
+                        graph.chosen[index] = true;
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        for edge in &self.edges {
+            self.examine(edge.from, edge.to)?;
+
+            if let (Some(from), Some(to)) = (distance[edge.from], distance[edge.to]) {
+                if from + edge.weight < to {
+                    return Some(format!(
+                        "negative cycle through {} -> {}",
+                        edge.from, edge.to
+                    ));
+                }
+            }
+        }
+
+        let reached = distance.iter().flatten().count();
+
+        Some(format!(
+            "distances from node {} to {} nodes after {} rounds",
+            SOURCE, reached, rounds
+        ))
+    }
+
+    /// Lets every node in turn shorten the paths between all pairs.
+    fn floyd_warshall(&self) -> Option<String> {
+        let mut distance = vec![vec![None; self.nodes]; self.nodes];
+
+        for (node, row) in distance.iter_mut().enumerate() {
+            row[node] = Some(0);
+        }
+
+        for edge in &self.edges {
+            let current: &mut Option<i64> = &mut distance[edge.from][edge.to];
+            *current = Some(current.map_or(edge.weight, |d| d.min(edge.weight)));
+        }
+
+        self.graph.get().matrix = distance.clone();
+
+        for via in 0..self.nodes {
+            {
+                let mut graph = self.graph.get();
+                graph.via = Some(via);
+                graph.visited[via] = true;
+            }
+
+            for from in 0..self.nodes {
+                for to in 0..self.nodes {
+                    let (Some(first), Some(second)) = (distance[from][via], distance[via][to])
+                    else {
+                        continue;
+                    };
+
+                    self.examine(from, to)?;
+
+                    if distance[from][to].is_none_or(|d| first + second < d) {
+                        distance[from][to] = Some(first + second);
+                        self.graph.get().matrix[from][to] = distance[from][to];
+                    }
+                }
+            }
+        }
+
+        let negative = (0..self.nodes).any(|node| distance[node][node] < Some(0));
+
+        Some(match negative {
+            true => "negative cycle on the diagonal".to_string(),
+            false => format!("all-pairs distances between {} nodes", self.nodes),
+        })
+    }
+
+    /// Kahn's algorithm, repeatedly taking a node nothing points to any more.
+    fn topological_sort(&self) -> Option<String> {
+        let mut indegree: Vec<usize> = self.incoming.iter().map(Vec::len).collect();
+        let mut ready: VecDeque<usize> = (0..self.nodes).filter(|&n| indegree[n] == 0).collect();
+        let mut order = vec![];
+
+        while let Some(node) = ready.pop_front() {
+            {
+                let mut graph = self.graph.get();
+                graph.values[node] = Some(order.len() as i64);
+                graph.visited[node] = true;
+            }
+            order.push(node);
+
+            for &index in &self.outgoing[node] {
+                let edge = self.edges[index];
+                self.examine(edge.from, edge.to)?;
+                self.graph.get().chosen[index] = true;
+
+                indegree[edge.to] -= 1;
+
+                if indegree[edge.to] == 0 {
+                    ready.push_back(edge.to);
+                }
+            }
+        }
+
+        let order: Vec<String> = order.iter().map(usize::to_string).collect();
+
+        Some(match self.nodes - order.len() {
+            0 => format!("order {}", order.join(" -> ")),
+            left => format!(
+                "{} nodes lie on cycles after ordering {}",
+                left,
+                order.join(" -> ")
+            ),
+        })
+    }
+
+    fn tarjan(&self) -> Option<String> {
+        let mut tarjan = Tarjan {
+            index: vec![None; self.nodes],
+            low: vec![0; self.nodes],
+            on_stack: vec![false; self.nodes],
+            stack: vec![],
+            counter: 0,
+            components: 0,
+        };
+
+        for node in 0..self.nodes {
+            if tarjan.index[node].is_none() {
+                self.connect(&mut tarjan, node)?;
+            }
+        }
+
+        Some(format!(
+            "{} strongly connected components",
+            tarjan.components
+        ))
+    }
+
+    /// Tarjan's depth-first search, closing a component whenever a node turns
+    /// out to be the lowest one reachable from itself.
+    fn connect(&self, tarjan: &mut Tarjan, node: usize) -> Option<()> {
+        tarjan.index[node] = Some(tarjan.counter);
+        tarjan.low[node] = tarjan.counter;
+        tarjan.counter += 1;
+        tarjan.stack.push(node);
+        tarjan.on_stack[node] = true;
+        self.visit(node);
+
+        for &index in &self.outgoing[node] {
+            let next = self.edges[index].to;
+            self.examine(node, next)?;
+
+            match tarjan.index[next] {
+                None => {
+                    self.connect(tarjan, next)?;
+                    tarjan.low[node] = tarjan.low[node].min(tarjan.low[next]);
+                }
+                Some(index) if tarjan.on_stack[next] => {
+                    tarjan.low[node] = tarjan.low[node].min(index);
+                }
+                _ => {}
+            }
+        }
+
+        if Some(tarjan.low[node]) == tarjan.index[node] {
+            let component = tarjan.components;
+            tarjan.components += 1;
+
+            let mut graph = self.graph.get();
+
+            while let Some(member) = tarjan.stack.pop() {
+                tarjan.on_stack[member] = false;
+                graph.components[member] = Some(component);
+                graph.values[member] = Some(component as i64);
+
+                if member == node {
+                    break;
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    /// Orders the nodes by when a depth-first search finishes them, then
+    /// collects components by searching the reversed edges in the opposite order.
+    fn kosaraju(&self) -> Option<String> {
+        let mut seen = vec![false; self.nodes];
+        let mut finished = vec![];
+
+        for node in 0..self.nodes {
+            if !seen[node] {
+                self.finish(node, &mut seen, &mut finished)?;
+            }
+        }
+
+        let mut components = 0;
+
+        for &node in finished.iter().rev() {
+            if self.graph.get().components[node].is_none() {
+                self.assign(node, components)?;
+                components += 1;
+            }
+        }
+
+        Some(format!("{} strongly connected components", components))
+    }
+
+    fn finish(&self, node: usize, seen: &mut [bool], finished: &mut Vec<usize>) -> Option<()> {
+        seen[node] = true;
+        self.visit(node);
+
+        for &index in &self.outgoing[node] {
+            let next = self.edges[index].to;
+            self.examine(node, next)?;
+
+            if !seen[next] {
+                self.finish(next, seen, finished)?;
+            }
+        }
+
+        finished.push(node);
+        Some(())
+    }
+
+    fn assign(&self, node: usize, component: usize) -> Option<()> {
+        {
+            let mut graph = self.graph.get();
+            graph.components[node] = Some(component);
+            graph.values[node] = Some(component as i64);
+        }
+
+        for &index in &self.incoming[node] {
+            let previous = self.edges[index].from;
+            self.examine(previous, node)?;
+
+            if self.graph.get().components[previous].is_none() {
+                self.assign(previous, component)?;
+            }
+        }
+
+        Some(())
+    }
+}
+
+struct Tarjan {
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    counter: usize,
+    components: usize,
+}
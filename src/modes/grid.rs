@@ -0,0 +1,564 @@
+use std::{
+    fs,
+    sync::{Arc, Mutex, MutexGuard},
+    thread::{self, JoinHandle},
+};
+
+use crossterm::{event::KeyCode, style::Color};
+use rand::Rng;
+use ratatui::{
+    layout::Rect,
+    style::Stylize,
+    symbols,
+    text::Line,
+    widgets::{
+        canvas::{Canvas, Points},
+        Block, BorderType, Borders,
+    },
+    Frame,
+};
+
+use super::{
+    maze::Generator,
+    pathfinding::{Heuristic, Pathfinder},
+    Mode,
+};
+use crate::state::{SharedState, State};
+
+pub const WIDTH: usize = 61;
+pub const HEIGHT: usize = 25;
+/// Cost of stepping onto a mud cell, against 1 for an open one.
+pub const MUD_COST: u32 = 5;
+/// Share of the cells turned into walls by random scattering.
+const WALL_DENSITY: f64 = 0.25;
+/// Where mazes are exported to and imported from, relative to the working directory.
+pub const MAZE_FILE: &str = "maze.txt";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Cell {
+    Open,
+    Wall,
+    Mud,
+}
+
+impl Cell {
+    /// Cost of stepping onto the cell, if it can be entered at all.
+    pub fn cost(&self) -> Option<u32> {
+        match self {
+            Cell::Open => Some(1),
+            Cell::Mud => Some(MUD_COST),
+            Cell::Wall => None,
+        }
+    }
+}
+
+/// The cells plus what the running search has made of them so far.
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<Cell>,
+    pub start: usize,
+    pub goal: usize,
+    pub visited: Vec<bool>,
+    pub frontier: Vec<bool>,
+    pub path: Vec<usize>,
+    pub current: Option<usize>,
+    pub expanded: u32,
+    pub cost: Option<u32>,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::Open; width * height],
+            start: (height / 2) * width + 1,
+            goal: (height / 2) * width + width - 2,
+            visited: vec![false; width * height],
+            frontier: vec![false; width * height],
+            path: vec![],
+            current: None,
+            expanded: 0,
+            cost: None,
+        }
+    }
+
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    pub fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    /// The up to four cells sharing a side with `index`.
+    pub fn neighbours(&self, index: usize) -> Vec<usize> {
+        let (x, y) = self.position(index);
+        let mut neighbours = vec![];
+
+        if y > 0 {
+            neighbours.push(index - self.width);
+        }
+        if x + 1 < self.width {
+            neighbours.push(index + 1);
+        }
+        if y + 1 < self.height {
+            neighbours.push(index + self.width);
+        }
+        if x > 0 {
+            neighbours.push(index - 1);
+        }
+
+        neighbours
+    }
+
+    /// Writes the grid as one line of `#` walls, `.` open cells, `~` mud, `S`
+    /// and `G` per row, after an optional `seed` line.
+    pub fn to_text(&self, seed: Option<u64>) -> String {
+        let mut text = seed.map_or(String::new(), |seed| format!("seed {}\n", seed));
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let index = self.index(x, y);
+
+                text.push(match self.cells[index] {
+                    _ if index == self.start => 'S',
+                    _ if index == self.goal => 'G',
+                    Cell::Open => '.',
+                    Cell::Wall => '#',
+                    Cell::Mud => '~',
+                });
+            }
+
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Reads a grid written by [`Grid::to_text`] along with its seed, if any.
+    pub fn from_text(text: &str) -> Result<(Grid, Option<u64>), String> {
+        let mut lines = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .peekable();
+
+        let seed = match lines.peek().and_then(|line| line.strip_prefix("seed ")) {
+            Some(seed) => {
+                let seed = seed
+                    .trim()
+                    .parse()
+                    .map_err(|_| "invalid seed".to_string())?;
+                lines.next();
+                Some(seed)
+            }
+            None => None,
+        };
+
+        let rows: Vec<Vec<char>> = lines
+            .map(|line| line.trim_end().chars().collect())
+            .collect();
+        let (width, height) = (rows.first().map_or(0, Vec::len), rows.len());
+
+        if width < 3 || height < 3 {
+            return Err("grid must be at least 3x3".to_string());
+        }
+
+        if rows.iter().any(|row| row.len() != width) {
+            return Err("rows differ in length".to_string());
+        }
+
+        let mut grid = Grid::new(width, height);
+        let (mut start, mut goal) = (None, None);
+
+        for (index, &char) in rows.iter().flatten().enumerate() {
+            grid.cells[index] = match char {
+                '.' => Cell::Open,
+                '#' => Cell::Wall,
+                '~' => Cell::Mud,
+                'S' if start.is_none() => {
+                    start = Some(index);
+                    Cell::Open
+                }
+                'G' if goal.is_none() => {
+                    goal = Some(index);
+                    Cell::Open
+                }
+                _ => return Err(format!("unexpected '{}'", char)),
+            };
+        }
+
+        match (start, goal) {
+            (Some(start), Some(goal)) => {
+                grid.start = start;
+                grid.goal = goal;
+                Ok((grid, seed))
+            }
+            _ => Err("missing S or G".to_string()),
+        }
+    }
+
+    /// Forgets everything a previous search found.
+    pub fn clear_search(&mut self) {
+        self.visited.fill(false);
+        self.frontier.fill(false);
+        self.path.clear();
+        self.current = None;
+        self.expanded = 0;
+        self.cost = None;
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedGrid(Arc<Mutex<Grid>>);
+
+impl Default for SharedGrid {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(Grid::new(WIDTH, HEIGHT))))
+    }
+}
+
+impl SharedGrid {
+    pub fn get(&self) -> MutexGuard<'_, Grid> {
+        self.0.lock().unwrap()
+    }
+}
+
+#[derive(Default)]
+pub struct GridMode {
+    grid: SharedGrid,
+    pathfinder_index: usize,
+    heuristic_index: usize,
+    generator_index: usize,
+    /// Seed of the generated or imported maze, until the grid is redrawn by hand.
+    seed: Option<u64>,
+    /// Whether the next start generates a maze instead of searching.
+    generate: bool,
+    /// Outcome of the last export or import.
+    file: Option<String>,
+    /// Imported grid, swapped in once the running algorithm has stopped using the old one.
+    pending: Option<Grid>,
+    cursor: (usize, usize),
+}
+
+impl GridMode {
+    fn pathfinder(&self) -> Pathfinder {
+        Pathfinder::ALL[self.pathfinder_index % Pathfinder::ALL.len()]
+    }
+
+    fn generator(&self) -> Generator {
+        Generator::ALL[self.generator_index % Generator::ALL.len()]
+    }
+
+    fn export(&mut self) {
+        let text = self.grid.get().to_text(self.seed);
+
+        self.file = Some(match fs::write(MAZE_FILE, text) {
+            Ok(()) => format!("exported to {}", MAZE_FILE),
+            Err(error) => format!("export failed: {}", error),
+        });
+    }
+
+    fn import(&mut self) -> bool {
+        let grid = fs::read_to_string(MAZE_FILE)
+            .map_err(|error| error.to_string())
+            .and_then(|text| Grid::from_text(&text));
+
+        match grid {
+            Ok((grid, seed)) => {
+                self.cursor = (
+                    self.cursor.0.min(grid.width - 1),
+                    self.cursor.1.min(grid.height - 1),
+                );
+                self.pending = Some(grid);
+                self.seed = seed;
+                self.file = Some(format!("imported from {}", MAZE_FILE));
+                true
+            }
+            Err(error) => {
+                self.file = Some(format!("import failed: {}", error));
+                false
+            }
+        }
+    }
+
+    fn heuristic(&self) -> Heuristic {
+        Heuristic::ALL[self.heuristic_index % Heuristic::ALL.len()]
+    }
+
+    /// Applies `edit` to the cell under the cursor unless it is the start or goal.
+    fn edit(&mut self, edit: impl FnOnce(Cell) -> Cell) -> bool {
+        let mut grid = self.grid.get();
+        let index = grid.index(self.cursor.0, self.cursor.1);
+
+        if index == grid.start || index == grid.goal {
+            return false;
+        }
+
+        grid.cells[index] = edit(grid.cells[index]);
+        true
+    }
+}
+
+impl Mode for GridMode {
+    fn name(&self) -> &'static str {
+        "Pathfinding"
+    }
+
+    fn speed(&self) -> u32 {
+        75
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        if let Some(grid) = self.pending.take() {
+            *self.grid.get() = grid;
+        }
+
+        let grid = self.grid.clone();
+
+        if std::mem::take(&mut self.generate) {
+            let (generator, seed) = (self.generator(), self.seed.unwrap_or_default());
+            return thread::spawn(move || generator.run(&state, &grid, seed));
+        }
+
+        let (pathfinder, heuristic) = (self.pathfinder(), self.heuristic());
+
+        thread::spawn(move || pathfinder.run(&state, &grid, heuristic))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        let (x, y) = self.cursor;
+        let (width, height) = {
+            let grid = self.grid.get();
+            (grid.width, grid.height)
+        };
+
+        match key {
+            KeyCode::Left => self.cursor.0 = x.saturating_sub(1),
+            KeyCode::Right => self.cursor.0 = (x + 1).min(width - 1),
+            KeyCode::Up => self.cursor.1 = y.saturating_sub(1),
+            KeyCode::Down => self.cursor.1 = (y + 1).min(height - 1),
+            KeyCode::Char(' ') => {
+                return self.edit(|cell| match cell {
+                    Cell::Wall => Cell::Open,
+                    _ => Cell::Wall,
+                })
+            }
+            KeyCode::Char('e') => {
+                return self.edit(|cell| match cell {
+                    Cell::Mud => Cell::Open,
+                    _ => Cell::Mud,
+                })
+            }
+            KeyCode::Char('s') | KeyCode::Char('g') => {
+                let mut grid = self.grid.get();
+                let index = grid.index(x, y);
+                grid.cells[index] = Cell::Open;
+
+                if key == KeyCode::Char('s') && index != grid.goal {
+                    grid.start = index;
+                } else if key == KeyCode::Char('g') && index != grid.start {
+                    grid.goal = index;
+                }
+
+                return true;
+            }
+            KeyCode::Char('x') => {
+                self.grid.get().cells.fill(Cell::Open);
+                self.seed = None;
+                return true;
+            }
+            KeyCode::Char('w') => {
+                let mut rng = rand::rng();
+                let mut grid = self.grid.get();
+                let (start, goal) = (grid.start, grid.goal);
+
+                for (index, cell) in grid.cells.iter_mut().enumerate() {
+                    *cell = if index != start && index != goal && rng.random_bool(WALL_DENSITY) {
+                        Cell::Wall
+                    } else {
+                        Cell::Open
+                    };
+                }
+
+                self.seed = None;
+                return true;
+            }
+            KeyCode::Char('m') => {
+                self.seed = Some(rand::rng().random::<u32>().into());
+                self.generate = true;
+                return true;
+            }
+            KeyCode::Char('n') => {
+                self.generator_index = (self.generator_index + 1) % Generator::ALL.len();
+            }
+            KeyCode::Char('o') => self.export(),
+            KeyCode::Char('i') => return self.import(),
+            KeyCode::Char('l') => {
+                self.pathfinder_index = (self.pathfinder_index + 1) % Pathfinder::ALL.len();
+                return true;
+            }
+            KeyCode::Char('h') => {
+                self.pathfinder_index =
+                    (self.pathfinder_index + Pathfinder::ALL.len() - 1) % Pathfinder::ALL.len();
+                return true;
+            }
+            KeyCode::Char('v') => {
+                self.heuristic_index = (self.heuristic_index + 1) % Heuristic::ALL.len();
+                return self.pathfinder().uses_heuristic();
+            }
+            KeyCode::Char('r') => return true,
+            _ => {}
+        }
+
+        false
+    }
+
+    fn overview(&self, _state: &State) -> Vec<Line<'static>> {
+        let grid = self.grid.get();
+
+        let mut overview: Vec<Line> = vec![
+            format!("Grid: {}x{}", grid.width, grid.height).into(),
+            format!("Maze: {}", self.generator().name()).into(),
+            format!(
+                "Seed: {}",
+                self.seed.map_or("-".to_string(), |seed| seed.to_string())
+            )
+            .into(),
+            format!(
+                "Heuristic: {}",
+                if self.pathfinder().uses_heuristic() {
+                    self.heuristic().name()
+                } else {
+                    "None"
+                }
+            )
+            .into(),
+            format!("Expanded: {}", grid.expanded).into(),
+            format!(
+                "Frontier: {}",
+                grid.frontier.iter().filter(|&&cell| cell).count()
+            )
+            .into(),
+            format!("Path Length: {}", grid.path.len().saturating_sub(1)).into(),
+            format!(
+                "Path Cost: {}",
+                grid.cost.map_or("-".to_string(), |cost| cost.to_string())
+            )
+            .into(),
+            format!("Cursor: ({}, {})", self.cursor.0, self.cursor.1).into(),
+        ];
+
+        if let Some(file) = &self.file {
+            overview.push(format!("File: {}", file).into());
+        }
+
+        overview
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        vec![
+            "Rerun: <R>".into(),
+            "Next: <L>".into(),
+            "Previous: <H>".into(),
+            "Heuristic: <V>".into(),
+            "Move Cursor: <Arrows>".into(),
+            "Wall: <Space>".into(),
+            "Mud: <E>".into(),
+            "Start: <S>".into(),
+            "Goal: <G>".into(),
+            "Random Walls: <W>".into(),
+            "Clear: <X>".into(),
+            "Generate Maze: <M>".into(),
+            "Maze Generator: <N>".into(),
+            format!("Export: <O> ({})", MAZE_FILE).into(),
+            format!("Import: <I> ({})", MAZE_FILE).into(),
+        ]
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, _state: &State) {
+        let grid = self.grid.get();
+        let cursor = grid.index(self.cursor.0, self.cursor.1);
+
+        let color = |index: usize| {
+            if index == cursor {
+                Color::Magenta
+            } else if index == grid.start {
+                Color::Green
+            } else if index == grid.goal {
+                Color::Red
+            } else if grid.path.contains(&index) {
+                Color::Yellow
+            } else if Some(index) == grid.current {
+                Color::White
+            } else {
+                match grid.cells[index] {
+                    Cell::Wall => Color::Grey,
+                    _ if grid.frontier[index] => Color::Cyan,
+                    _ if grid.visited[index] => Color::Blue,
+                    Cell::Mud => Color::DarkYellow,
+                    Cell::Open => Color::Reset,
+                }
+            }
+        };
+
+        let legend = Line::from(vec![
+            " start ".fg(Color::Green),
+            " goal ".fg(Color::Red),
+            " wall ".fg(Color::Grey),
+            format!(" mud (cost {}) ", MUD_COST).fg(Color::DarkYellow),
+            " frontier ".fg(Color::Cyan),
+            " visited ".fg(Color::Blue),
+            " path ".fg(Color::Yellow),
+            " cursor ".fg(Color::Magenta),
+        ]);
+
+        let block = Block::new()
+            .title(Line::raw(format!(" {} ", self.pathfinder().name())).centered())
+            .title_bottom(legend.centered())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        let inner = block.inner(area);
+        let (columns, rows) = (usize::from(inner.width), usize::from(inner.height));
+
+        // One point per terminal cell, coloured after the grid cell under it.
+        let mut layers: Vec<(Color, Vec<(f64, f64)>)> = vec![];
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let x = column * grid.width / columns;
+                let y = row * grid.height / rows;
+                let color = color(grid.index(x, y));
+
+                if color == Color::Reset {
+                    continue;
+                }
+
+                let point = (column as f64, (rows - 1 - row) as f64);
+
+                match layers.iter_mut().find(|(layer, _)| *layer == color) {
+                    Some((_, points)) => points.push(point),
+                    None => layers.push((color, vec![point])),
+                }
+            }
+        }
+
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(symbols::Marker::Block)
+            .x_bounds([0.0, columns.saturating_sub(1) as f64])
+            .y_bounds([0.0, rows.saturating_sub(1) as f64])
+            .paint(|ctx| {
+                for (color, points) in &layers {
+                    ctx.draw(&Points {
+                        coords: points,
+                        color: (*color).into(),
+                    });
+                }
+            });
+
+        frame.render_widget(canvas, area);
+    }
+}
@@ -0,0 +1,225 @@
+use std::thread::{self, JoinHandle};
+
+use crossterm::{event::KeyCode, style::Color};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, BorderType, Borders},
+    Frame,
+};
+
+use super::{
+    hash_table::{self, Operation, SharedTable, Strategy, Table},
+    key_input::KeyInput,
+    Mode,
+};
+use crate::{
+    app::{self, App},
+    state::{SharedState, State},
+};
+
+/// Longest probe length with a bar of its own, longer ones share the last.
+const HISTOGRAM_BARS: usize = 10;
+
+pub struct HashMode {
+    table: SharedTable,
+    strategy_index: usize,
+    keys: KeyInput<Operation>,
+}
+
+impl Default for HashMode {
+    fn default() -> Self {
+        Self {
+            table: SharedTable::new(Table::new(Strategy::ALL[0])),
+            strategy_index: 0,
+            keys: KeyInput::default(),
+        }
+    }
+}
+
+impl HashMode {
+    fn strategy(&self) -> Strategy {
+        Strategy::ALL[self.strategy_index % Strategy::ALL.len()]
+    }
+
+    fn switch_strategy(&mut self, increment: usize) -> bool {
+        self.strategy_index = (self.strategy_index + increment) % Strategy::ALL.len();
+        self.keys.pending = vec![Operation::Rebuild(self.strategy())];
+        true
+    }
+
+    fn draw_buckets(&self, frame: &mut Frame, area: Rect, table: &Table) {
+        let block = Block::new()
+            .title(Line::raw(format!(" {} ", table.strategy.name())).centered())
+            .title_bottom(
+                Line::from(vec![
+                    " probing ".fg(Color::Yellow),
+                    " found ".fg(Color::Green),
+                ])
+                .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let digits = (table.capacity() - 1).to_string().len();
+
+        let lines: Vec<Line> = table
+            .buckets
+            .iter()
+            .enumerate()
+            .map(|(bucket, keys)| {
+                let color = if Some(bucket) == table.found {
+                    Color::Green
+                } else if Some(bucket) == table.current {
+                    Color::Yellow
+                } else {
+                    Color::White
+                };
+
+                let keys = keys
+                    .iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" → ");
+
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:>digits$} │ ", bucket),
+                        Style::new().fg(Color::DarkGrey.into()),
+                    ),
+                    Span::styled(keys, Style::new().fg(color.into()).bold()),
+                ])
+            })
+            .collect();
+
+        // Buckets wrap into as many columns as it takes to show all of them.
+        app::render_columns(frame, inner, lines);
+    }
+
+    fn draw_histogram(&self, frame: &mut Frame, area: Rect, table: &Table) {
+        let mut counts = [0; HISTOGRAM_BARS];
+        for length in table.probe_lengths() {
+            counts[length.min(HISTOGRAM_BARS) - 1] += 1;
+        }
+
+        let bars: Vec<Bar> = counts
+            .iter()
+            .enumerate()
+            .map(|(index, &count)| {
+                let label = match index + 1 {
+                    HISTOGRAM_BARS => format!("{}+", HISTOGRAM_BARS),
+                    length => length.to_string(),
+                };
+
+                Bar::default()
+                    .value(count)
+                    .label(label.into())
+                    .style(Style::new().fg(Color::Cyan.into()))
+            })
+            .collect();
+
+        let load = if table.rehashing {
+            " rehashing ".fg(Color::Yellow)
+        } else {
+            format!(" load {:.2} ", table.load_factor()).white()
+        };
+
+        let chart = BarChart::default()
+            .block(
+                Block::new()
+                    .title(Line::raw(" Probe Lengths ").centered())
+                    .title_bottom(Line::from(load).centered())
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Thick),
+            )
+            .data(BarGroup::default().bars(&bars))
+            .bar_gap(1)
+            .bar_width((area.width.saturating_sub(2) / HISTOGRAM_BARS as u16).max(2) - 1);
+
+        frame.render_widget(chart, area);
+    }
+}
+
+impl Mode for HashMode {
+    fn name(&self) -> &'static str {
+        "Hash Tables"
+    }
+
+    fn speed(&self) -> u32 {
+        90
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        let (operations, table) = (std::mem::take(&mut self.keys.pending), self.table.clone());
+
+        thread::spawn(move || hash_table::run(&state, &table, operations))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        let table = self.table.clone();
+
+        if let Some(restart) = self.keys.handle_key(key, || table.get().keys.clone()) {
+            return restart;
+        }
+
+        match key {
+            KeyCode::Char('s') => self.keys.with_input(Operation::Search),
+            KeyCode::Char('a') => self.keys.insert_all(App::generate_array()),
+            KeyCode::Char('l') => self.switch_strategy(1),
+            KeyCode::Char('h') => self.switch_strategy(Strategy::ALL.len() - 1),
+            _ => false,
+        }
+    }
+
+    fn overview(&self, state: &State) -> Vec<Line<'static>> {
+        let table = self.table.get();
+        let lengths = table.probe_lengths();
+        let average = match lengths.len() {
+            0 => 0.0,
+            len => lengths.iter().sum::<usize>() as f64 / len as f64,
+        };
+
+        vec![
+            format!("Keys: {}", table.len).into(),
+            format!("Buckets: {}", table.capacity()).into(),
+            format!(
+                "Load Factor: {:.2} / {:.2}",
+                table.load_factor(),
+                table.strategy.max_load()
+            )
+            .into(),
+            format!("Resizes: {}", table.resizes).into(),
+            format!("Average Probe Length: {:.2}", average).into(),
+            format!("Probes: {}", state.probes).into(),
+            format!("Last Operation Probes: {}", table.last_probes).into(),
+            self.keys.overview(),
+        ]
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        vec![
+            "Type Key: <0-9>".into(),
+            "Insert: <I>".into(),
+            "Search: <S>".into(),
+            "Insert Generated Input: <A>".into(),
+            "Replay: <R>".into(),
+            "Clear: <C>".into(),
+            "Next Strategy: <L>".into(),
+            "Previous Strategy: <H>".into(),
+        ]
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, _state: &State) {
+        let table = self.table.get();
+        let [buckets, histogram] =
+            Layout::horizontal([Constraint::Percentage(70), Constraint::Percentage(30)])
+                .areas(area);
+
+        self.draw_buckets(frame, buckets, &table);
+        self.draw_histogram(frame, histogram, &table);
+    }
+}
@@ -0,0 +1,529 @@
+use std::{
+    cell::Cell,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use super::key_input::KeyOperation;
+use crate::state::{SharedState, Status};
+
+/// Buckets in a new table, doubled on every resize so masks can replace modulo.
+pub const INITIAL_CAPACITY: usize = 8;
+/// Evictions a cuckoo insert tries before giving up and rehashing.
+const MAX_KICKS: usize = 32;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Strategy {
+    Chaining,
+    Linear,
+    Quadratic,
+    Double,
+    RobinHood,
+    Cuckoo,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 6] = [
+        Strategy::Chaining,
+        Strategy::Linear,
+        Strategy::Quadratic,
+        Strategy::Double,
+        Strategy::RobinHood,
+        Strategy::Cuckoo,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Chaining => "Separate Chaining",
+            Strategy::Linear => "Linear Probing",
+            Strategy::Quadratic => "Quadratic Probing",
+            Strategy::Double => "Double Hashing",
+            Strategy::RobinHood => "Robin Hood Hashing",
+            Strategy::Cuckoo => "Cuckoo Hashing",
+        }
+    }
+
+    /// Load factor above which the table doubles before the next insert.
+    pub fn max_load(&self) -> f64 {
+        match self {
+            Strategy::Chaining => 1.0,
+            Strategy::Cuckoo => 0.5,
+            _ => 0.75,
+        }
+    }
+}
+
+/// Scrambles `key` with the splitmix64 finalizer, so even consecutive keys
+/// collide the way random ones would, then keeps the bits the capacity needs.
+fn mix(key: u32, seed: u64, capacity: usize) -> usize {
+    let mut x = u64::from(key) ^ seed;
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    (x ^ (x >> 31)) as usize & (capacity - 1)
+}
+
+pub fn hash(key: u32, capacity: usize) -> usize {
+    mix(key, 0x9E37_79B9_7F4A_7C15, capacity)
+}
+
+/// Independent second hash, for double hashing's step and cuckoo's other bucket.
+pub fn second_hash(key: u32, capacity: usize) -> usize {
+    mix(key, 0xC2B2_AE3D_27D4_EB4F, capacity)
+}
+
+/// Each bucket holds a chain for separate chaining and at most one key otherwise.
+pub struct Table {
+    pub strategy: Strategy,
+    pub buckets: Vec<Vec<u32>>,
+    pub len: usize,
+    pub resizes: u32,
+    /// Keys in the order they went in, to rebuild the table with another strategy.
+    pub keys: Vec<u32>,
+    pub current: Option<usize>,
+    pub found: Option<usize>,
+    pub rehashing: bool,
+    pub last_probes: u32,
+}
+
+impl Table {
+    pub fn new(strategy: Strategy) -> Self {
+        Self {
+            strategy,
+            buckets: vec![vec![]; INITIAL_CAPACITY],
+            len: 0,
+            resizes: 0,
+            keys: vec![],
+            current: None,
+            found: None,
+            rehashing: false,
+            last_probes: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buckets.len()
+    }
+
+    pub fn load_factor(&self) -> f64 {
+        self.len as f64 / self.capacity() as f64
+    }
+
+    /// Bucket `key` tries on its `attempt`th probe under open addressing.
+    fn position(&self, key: u32, attempt: usize) -> usize {
+        let capacity = self.capacity();
+        let start = hash(key, capacity);
+
+        let offset = match self.strategy {
+            Strategy::Quadratic => attempt * (attempt + 1) / 2,
+            // An odd step is coprime with the capacity, so every bucket gets visited.
+            Strategy::Double => attempt * (second_hash(key, capacity) | 1),
+            _ => attempt,
+        };
+
+        (start + offset) & (capacity - 1)
+    }
+
+    /// Probes it takes to reach every stored key, for the histogram.
+    pub fn probe_lengths(&self) -> Vec<usize> {
+        let mut lengths = vec![];
+
+        for (bucket, keys) in self.buckets.iter().enumerate() {
+            for (index, &key) in keys.iter().enumerate() {
+                lengths.push(match self.strategy {
+                    Strategy::Chaining => index + 1,
+                    Strategy::Cuckoo if hash(key, self.capacity()) == bucket => 1,
+                    Strategy::Cuckoo => 2,
+                    _ => self.displacement(key, bucket) + 1,
+                });
+            }
+        }
+
+        lengths
+    }
+
+    /// Probes `key` took past its first choice to land in `bucket`.
+    fn displacement(&self, key: u32, bucket: usize) -> usize {
+        (0..self.capacity())
+            .find(|&attempt| self.position(key, attempt) == bucket)
+            .unwrap_or(0)
+    }
+
+    fn place(&mut self, key: u32, bucket: usize) {
+        self.buckets[bucket].push(key);
+        self.len += 1;
+        self.keys.push(key);
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedTable(Arc<Mutex<Table>>);
+
+impl SharedTable {
+    pub fn new(table: Table) -> Self {
+        Self(Arc::new(Mutex::new(table)))
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, Table> {
+        self.0.lock().unwrap()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Operation {
+    Insert(u32),
+    Search(u32),
+    Clear,
+    /// Reinserts the keys into an empty table of another strategy without animating.
+    Rebuild(Strategy),
+}
+
+impl KeyOperation for Operation {
+    fn insert(key: u32) -> Self {
+        Operation::Insert(key)
+    }
+
+    fn clear() -> Self {
+        Operation::Clear
+    }
+}
+
+/// Applies `operations` to `table` one probe at a time, never leaving a key
+/// half moved when interrupted.
+pub fn run(state: &SharedState, table: &SharedTable, operations: Vec<Operation>) {
+    let runner = Runner {
+        state,
+        table,
+        quiet: Cell::new(false),
+    };
+
+    state.set_algorithm(table.get().strategy.name().to_string());
+    state.set_status(Status::Running);
+
+    for (done, &operation) in operations.iter().enumerate() {
+        if state.interrupted() {
+            return;
+        }
+
+        let before = state.get_probes();
+        table.get().found = None;
+
+        let message = match operation {
+            Operation::Insert(key) => runner.insert(key),
+            Operation::Search(key) => runner.search(key),
+            Operation::Clear => {
+                let strategy = table.get().strategy;
+                *table.get() = Table::new(strategy);
+                Some("Cleared the table".to_string())
+            }
+            Operation::Rebuild(strategy) => {
+                let keys = std::mem::take(&mut table.get().keys);
+                *table.get() = Table::new(strategy);
+                state.set_algorithm(strategy.name().to_string());
+
+                runner.quiet.set(true);
+                for &key in &keys {
+                    runner.insert(key);
+                }
+                runner.quiet.set(false);
+
+                Some(format!(
+                    "Rebuilt {} keys with {}",
+                    keys.len(),
+                    strategy.name()
+                ))
+            }
+        };
+
+        let Some(message) = message else {
+            return;
+        };
+
+        let load = {
+            let mut table = table.get();
+            table.last_probes = state.get_probes() - before;
+            table.load_factor()
+        };
+
+        match operations.len() {
+            1 => state.log(format!("{}, load factor {:.2}", message, load)),
+            len => state.log(format!(
+                "{} ({} of {}), load factor {:.2}",
+                message,
+                done + 1,
+                len,
+                load
+            )),
+        };
+    }
+
+    table.get().current = None;
+    state.set_status(Status::Completed);
+}
+
+struct Runner<'a> {
+    state: &'a SharedState,
+    table: &'a SharedTable,
+    /// Set while rebuilding, which skips every probe.
+    quiet: Cell<bool>,
+}
+
+impl Runner<'_> {
+    /// Shows a probe of `bucket`, returning whether an `abortable` operation
+    /// was interrupted. Others finish regardless, just without waiting.
+    fn probe(&self, bucket: usize, abortable: bool) -> bool {
+        if self.quiet.get() {
+            return false;
+        }
+
+        self.table.get().current = Some(bucket);
+        self.state.increment_probes();
+
+        if self.state.interrupted() {
+            return abortable;
+        }
+
+        self.state.sleep(None);
+        false
+    }
+
+    fn insert(&self, key: u32) -> Option<String> {
+        if self.table.get().keys.contains(&key) {
+            return Some(format!("{} is already in the table", key));
+        }
+
+        let full = {
+            let table = self.table.get();
+            (table.len + 1) as f64 > table.capacity() as f64 * table.strategy.max_load()
+        };
+
+        if full {
+            self.resize();
+        }
+
+        self.add(key, true)?;
+        Some(format!("Inserted {}", key))
+    }
+
+    /// Doubles the table and moves every key over, which has to finish once started.
+    fn resize(&self) {
+        let keys = {
+            let mut table = self.table.get();
+            let capacity = table.capacity() * 2;
+
+            table.buckets = vec![vec![]; capacity];
+            table.len = 0;
+            table.resizes += 1;
+            table.rehashing = true;
+            std::mem::take(&mut table.keys)
+        };
+
+        for key in keys {
+            self.add(key, false);
+        }
+
+        self.table.get().rehashing = false;
+    }
+
+    /// Puts `key` in its bucket, or returns `None` if interrupted before moving anything.
+    fn add(&self, key: u32, abortable: bool) -> Option<()> {
+        let strategy = self.table.get().strategy;
+
+        match strategy {
+            Strategy::Chaining => self.add_chained(key, abortable),
+            Strategy::RobinHood => self.add_robin_hood(key, abortable),
+            Strategy::Cuckoo => self.add_cuckoo(key, abortable),
+            _ => self.add_open(key, abortable),
+        }
+    }
+
+    fn add_chained(&self, key: u32, abortable: bool) -> Option<()> {
+        let (bucket, chain) = {
+            let table = self.table.get();
+            let bucket = hash(key, table.capacity());
+            (bucket, table.buckets[bucket].len())
+        };
+
+        // One probe for the bucket and one for every key already chained there.
+        for _ in 0..=chain {
+            if self.probe(bucket, abortable) {
+                return None;
+            }
+        }
+
+        self.table.get().place(key, bucket);
+        Some(())
+    }
+
+    fn add_open(&self, key: u32, abortable: bool) -> Option<()> {
+        let capacity = self.table.get().capacity();
+
+        for attempt in 0..capacity {
+            let bucket = self.table.get().position(key, attempt);
+
+            if self.probe(bucket, abortable) {
+                return None;
+            }
+
+            let mut table = self.table.get();
+
+            if table.buckets[bucket].is_empty() {
+                table.place(key, bucket);
+                return Some(());
+            }
+        }
+
+        // Quadratic probing may miss free buckets, so grow and try again.
+        self.resize();
+        self.add(key, abortable)
+    }
+
+    /// Linear probing where a key travelling further than the resident takes
+    /// its bucket, and the resident carries on instead.
+    fn add_robin_hood(&self, key: u32, abortable: bool) -> Option<()> {
+        let capacity = self.table.get().capacity();
+        let (inserted, mut key, mut distance, mut moved) = (key, key, 0, false);
+        let mut bucket = hash(key, capacity);
+
+        loop {
+            if self.probe(bucket, abortable && !moved) {
+                return None;
+            }
+
+            let mut table = self.table.get();
+
+            let Some(&resident) = table.buckets[bucket].first() else {
+                table.buckets[bucket].push(key);
+                table.len += 1;
+                table.keys.push(inserted);
+                return Some(());
+            };
+
+            let resident_distance = table.displacement(resident, bucket);
+
+            if resident_distance < distance {
+                table.buckets[bucket][0] = key;
+                key = resident;
+                distance = resident_distance;
+                moved = true;
+            }
+
+            bucket = (bucket + 1) & (capacity - 1);
+            distance += 1;
+        }
+    }
+
+    /// Two candidate buckets per key, evicting the resident into its other
+    /// bucket when both are taken and rehashing after too many evictions.
+    fn add_cuckoo(&self, key: u32, abortable: bool) -> Option<()> {
+        let capacity = self.table.get().capacity();
+
+        for bucket in [hash(key, capacity), second_hash(key, capacity)] {
+            if self.probe(bucket, abortable) {
+                return None;
+            }
+
+            let mut table = self.table.get();
+
+            if table.buckets[bucket].is_empty() {
+                table.place(key, bucket);
+                return Some(());
+            }
+        }
+
+        self.table.get().keys.push(key);
+        self.table.get().len += 1;
+
+        let (mut homeless, mut bucket) = (key, hash(key, capacity));
+
+        for _ in 0..MAX_KICKS {
+            {
+                let mut table = self.table.get();
+                homeless = std::mem::replace(&mut table.buckets[bucket][0], homeless);
+            }
+
+            bucket = match hash(homeless, capacity) == bucket {
+                true => second_hash(homeless, capacity),
+                false => hash(homeless, capacity),
+            };
+
+            self.probe(bucket, false);
+
+            let mut table = self.table.get();
+
+            if table.buckets[bucket].is_empty() {
+                table.buckets[bucket].push(homeless);
+                return Some(());
+            }
+        }
+
+        // Still one key without a bucket, which goes back in once the table has grown.
+        {
+            let mut table = self.table.get();
+            table.len -= 1;
+            table.keys.retain(|&other| other != homeless);
+        }
+
+        self.resize();
+        self.add(homeless, false)
+    }
+
+    fn search(&self, key: u32) -> Option<String> {
+        let (strategy, capacity) = {
+            let table = self.table.get();
+            (table.strategy, table.capacity())
+        };
+
+        let candidates: Vec<usize> = match strategy {
+            Strategy::Chaining => {
+                let bucket = hash(key, capacity);
+                let chain = self.table.get().buckets[bucket].len();
+                vec![bucket; chain.max(1)]
+            }
+            Strategy::Cuckoo => vec![hash(key, capacity), second_hash(key, capacity)],
+            _ => {
+                let table = self.table.get();
+                (0..capacity)
+                    .map(|attempt| table.position(key, attempt))
+                    .collect()
+            }
+        };
+
+        for (attempt, &bucket) in candidates.iter().enumerate() {
+            if self.probe(bucket, true) {
+                return None;
+            }
+
+            let mut table = self.table.get();
+            let keys = &table.buckets[bucket];
+
+            let found = match strategy {
+                Strategy::Chaining => keys.get(attempt) == Some(&key),
+                _ => keys.first() == Some(&key),
+            };
+
+            if found {
+                table.found = Some(bucket);
+                return Some(format!("Found {} after {} probes", key, attempt + 1));
+            }
+
+            let missing = match (strategy, keys.first()) {
+                (Strategy::Chaining | Strategy::Cuckoo, _) => false,
+                (_, None) => true,
+                // Robin Hood keeps keys sorted by displacement, so a closer one ends the run.
+                (Strategy::RobinHood, Some(&resident)) => {
+                    table.displacement(resident, bucket) < attempt
+                }
+                _ => false,
+            };
+
+            if missing {
+                return Some(format!("{} not found after {} probes", key, attempt + 1));
+            }
+        }
+
+        Some(format!(
+            "{} not found after {} probes",
+            key,
+            candidates.len()
+        ))
+    }
+}
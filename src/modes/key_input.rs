@@ -0,0 +1,87 @@
+use crossterm::event::KeyCode;
+use rand::Rng;
+use ratatui::text::Line;
+
+/// Digits a typed key may have.
+const KEY_DIGITS: usize = 4;
+/// Upper bound for keys inserted without typing one first.
+const RANDOM_KEY_MAX: u32 = 999;
+
+/// Operations on a structure of keys that [`KeyInput`] queues.
+pub trait KeyOperation {
+    fn insert(key: u32) -> Self;
+
+    fn clear() -> Self;
+}
+
+/// The key being typed in the modes that insert and look up keys, and the
+/// operations it queues.
+pub struct KeyInput<O> {
+    /// Digits typed so far for the next key.
+    input: String,
+    /// Operations for the next start, once the previous ones have stopped.
+    pub pending: Vec<O>,
+}
+
+impl<O> Default for KeyInput<O> {
+    fn default() -> Self {
+        Self {
+            input: String::new(),
+            pending: vec![],
+        }
+    }
+}
+
+impl<O: KeyOperation> KeyInput<O> {
+    /// Queues `operation` with the typed key, clearing the input.
+    pub fn with_input(&mut self, operation: fn(u32) -> O) -> bool {
+        let Ok(key) = self.input.parse() else {
+            return false;
+        };
+
+        self.input.clear();
+        self.pending = vec![operation(key)];
+        true
+    }
+
+    /// Queues clearing the structure and inserting `keys` into it afresh.
+    pub fn insert_all(&mut self, keys: Vec<u32>) -> bool {
+        self.pending = std::iter::once(O::clear())
+            .chain(keys.into_iter().map(O::insert))
+            .collect();
+        true
+    }
+
+    /// Handles typing a key, inserting it or a random one, replaying the
+    /// inserted `keys` and clearing, or returns `None` for any other key.
+    pub fn handle_key(&mut self, key: KeyCode, keys: impl FnOnce() -> Vec<u32>) -> Option<bool> {
+        Some(match key {
+            KeyCode::Char(digit @ '0'..='9') if self.input.len() < KEY_DIGITS => {
+                self.input.push(digit);
+                false
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+                false
+            }
+            KeyCode::Char('i') => {
+                if self.input.is_empty() {
+                    let key = rand::rng().random_range(1..=RANDOM_KEY_MAX);
+                    self.input = key.to_string();
+                }
+
+                self.with_input(O::insert)
+            }
+            KeyCode::Char('r') => self.insert_all(keys()),
+            KeyCode::Char('c') => {
+                self.pending = vec![O::clear()];
+                true
+            }
+            _ => return None,
+        })
+    }
+
+    pub fn overview(&self) -> Line<'static> {
+        format!("Key: {}_", self.input).into()
+    }
+}
@@ -0,0 +1,506 @@
+use std::cmp::Ordering;
+
+use rand::{
+    rngs::StdRng,
+    seq::{IndexedRandom, SliceRandom},
+    Rng, SeedableRng,
+};
+
+use super::grid::{Cell, SharedGrid};
+use crate::state::{SharedState, Status};
+
+/// A cell of the maze, which sits at the odd grid position `(2x + 1, 2y + 1)`
+/// so that the even rows and columns in between hold its walls.
+type Position = (usize, usize);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Generator {
+    RecursiveBacktracker,
+    Prim,
+    Kruskal,
+    Wilson,
+    Eller,
+    RecursiveDivision,
+}
+
+impl Generator {
+    pub const ALL: [Generator; 6] = [
+        Generator::RecursiveBacktracker,
+        Generator::Prim,
+        Generator::Kruskal,
+        Generator::Wilson,
+        Generator::Eller,
+        Generator::RecursiveDivision,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Generator::RecursiveBacktracker => "Recursive Backtracker",
+            Generator::Prim => "Randomized Prim",
+            Generator::Kruskal => "Randomized Kruskal",
+            Generator::Wilson => "Wilson",
+            Generator::Eller => "Eller",
+            Generator::RecursiveDivision => "Recursive Division",
+        }
+    }
+
+    /// Turns `grid` into a perfect maze, one cell per step, moving the start
+    /// and goal to opposite corners.
+    pub fn run(&self, state: &SharedState, grid: &SharedGrid, seed: u64) {
+        let name = format!("{} (seed {})", self.name(), seed);
+
+        let (width, height) = {
+            let mut grid = grid.get();
+            grid.clear_search();
+            (grid.width, grid.height)
+        };
+
+        state.init_algorithm(name.clone());
+
+        let maze = Maze {
+            state,
+            grid,
+            width,
+            columns: (width - 1) / 2,
+            rows: (height - 1) / 2,
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        // The start and goal need two cells of their own.
+        if maze.columns * maze.rows < 2 {
+            state.log(format!("{}: grid too small for a maze", name));
+            state.set_status(Status::Completed);
+            return;
+        }
+
+        {
+            let mut grid = grid.get();
+            let carving = *self != Generator::RecursiveDivision;
+
+            for index in 0..grid.cells.len() {
+                let (x, y) = grid.position(index);
+                let inside = x > 0 && y > 0 && x < maze.columns * 2 && y < maze.rows * 2;
+
+                grid.cells[index] = match inside && !carving {
+                    true => Cell::Open,
+                    false => Cell::Wall,
+                };
+            }
+
+            grid.start = maze.index((0, 0));
+            grid.goal = maze.index((maze.columns - 1, maze.rows - 1));
+        }
+
+        match self {
+            Generator::RecursiveBacktracker => maze.recursive_backtracker(&mut rng),
+            Generator::Prim => maze.prim(&mut rng),
+            Generator::Kruskal => maze.kruskal(&mut rng),
+            Generator::Wilson => maze.wilson(&mut rng),
+            Generator::Eller => maze.eller(&mut rng),
+            Generator::RecursiveDivision => maze.recursive_division(&mut rng),
+        }
+
+        if state.interrupted() {
+            return;
+        }
+
+        let mut grid = grid.get();
+        grid.clear_search();
+
+        let dead_ends = (0..maze.columns * maze.rows)
+            .map(|i| maze.index((i % maze.columns, i / maze.columns)))
+            .filter(|&index| {
+                grid.neighbours(index)
+                    .iter()
+                    .filter(|&&neighbour| grid.cells[neighbour] != Cell::Wall)
+                    .count()
+                    == 1
+            })
+            .count();
+
+        state.log(format!(
+            "{}: {}x{} cells with {} dead ends",
+            name, maze.columns, maze.rows, dead_ends
+        ));
+        state.set_status(Status::Completed);
+    }
+}
+
+struct Maze<'a> {
+    state: &'a SharedState,
+    grid: &'a SharedGrid,
+    width: usize,
+    columns: usize,
+    rows: usize,
+}
+
+impl Maze<'_> {
+    fn index(&self, (x, y): Position) -> usize {
+        (2 * y + 1) * self.width + 2 * x + 1
+    }
+
+    fn id(&self, (x, y): Position) -> usize {
+        y * self.columns + x
+    }
+
+    fn neighbours(&self, (x, y): Position) -> Vec<Position> {
+        let mut neighbours = vec![];
+
+        if y > 0 {
+            neighbours.push((x, y - 1));
+        }
+        if x + 1 < self.columns {
+            neighbours.push((x + 1, y));
+        }
+        if y + 1 < self.rows {
+            neighbours.push((x, y + 1));
+        }
+        if x > 0 {
+            neighbours.push((x - 1, y));
+        }
+
+        neighbours
+    }
+
+    /// Sets one grid cell, counting a write and waiting for the next step.
+    fn set(&self, index: usize, cell: Cell) {
+        {
+            let mut grid = self.grid.get();
+            grid.cells[index] = cell;
+            grid.current = Some(index);
+        }
+
+        self.state.increment_writes(1);
+        self.state.sleep(None);
+    }
+
+    /// Opens `to` together with the wall between it and `from`, if any.
+    fn carve(&self, from: Option<Position>, to: Position) {
+        if let Some(from) = from {
+            let wall = (self.index(from) + self.index(to)) / 2;
+            self.grid.get().cells[wall] = Cell::Open;
+        }
+
+        self.mark(to, false);
+        self.set(self.index(to), Cell::Open);
+    }
+
+    /// Shows `position` as part of the frontier, or takes it out again.
+    fn mark(&self, position: Position, frontier: bool) {
+        let index = self.index(position);
+        self.grid.get().frontier[index] = frontier;
+    }
+
+    fn random(&self, rng: &mut StdRng) -> Position {
+        (
+            rng.random_range(0..self.columns),
+            rng.random_range(0..self.rows),
+        )
+    }
+
+    /// Depth-first walk that backs up along its stack at every dead end.
+    fn recursive_backtracker(&self, rng: &mut StdRng) {
+        let mut visited = vec![false; self.columns * self.rows];
+        let first = self.random(rng);
+
+        visited[self.id(first)] = true;
+        self.carve(None, first);
+        self.mark(first, true);
+
+        let mut stack = vec![first];
+
+        while let Some(&current) = stack.last() {
+            if self.state.interrupted() {
+                return;
+            }
+
+            let unvisited: Vec<Position> = self
+                .neighbours(current)
+                .into_iter()
+                .filter(|&neighbour| !visited[self.id(neighbour)])
+                .collect();
+
+            match unvisited.choose(rng) {
+                Some(&next) => {
+                    visited[self.id(next)] = true;
+                    self.carve(Some(current), next);
+                    self.mark(next, true);
+                    stack.push(next);
+                }
+                None => {
+                    self.mark(current, false);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    /// Grows the maze through a random wall on its border each step.
+    fn prim(&self, rng: &mut StdRng) {
+        let mut in_maze = vec![false; self.columns * self.rows];
+        let mut walls = vec![];
+        let mut next = Some((None, self.random(rng)));
+
+        while let Some((from, to)) = next {
+            if self.state.interrupted() {
+                return;
+            }
+
+            in_maze[self.id(to)] = true;
+            self.carve(from, to);
+
+            for neighbour in self.neighbours(to) {
+                if !in_maze[self.id(neighbour)] {
+                    self.mark(neighbour, true);
+                    walls.push((to, neighbour));
+                }
+            }
+
+            next = None;
+
+            while !walls.is_empty() {
+                let (from, to) = walls.swap_remove(rng.random_range(0..walls.len()));
+
+                if !in_maze[self.id(to)] {
+                    next = Some((Some(from), to));
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Removes walls in random order whenever they separate two different trees.
+    fn kruskal(&self, rng: &mut StdRng) {
+        let mut parent: Vec<usize> = (0..self.columns * self.rows).collect();
+        let mut edges = vec![];
+
+        for y in 0..self.rows {
+            for x in 0..self.columns {
+                let index = self.index((x, y));
+                self.grid.get().cells[index] = Cell::Open;
+
+                if x + 1 < self.columns {
+                    edges.push(((x, y), (x + 1, y)));
+                }
+                if y + 1 < self.rows {
+                    edges.push(((x, y), (x, y + 1)));
+                }
+            }
+        }
+
+        edges.shuffle(rng);
+
+        let find = |parent: &mut Vec<usize>, mut id: usize| {
+            while parent[id] != id {
+                parent[id] = parent[parent[id]];
+                id = parent[id];
+            }
+            id
+        };
+
+        for (a, b) in edges {
+            if self.state.interrupted() {
+                return;
+            }
+
+            self.state.increment_comparisons();
+
+            let (root_a, root_b) = (find(&mut parent, self.id(a)), find(&mut parent, self.id(b)));
+
+            if root_a != root_b {
+                parent[root_a] = root_b;
+                self.carve(Some(a), b);
+            }
+        }
+    }
+
+    /// Joins every cell through a loop-erased random walk that ends on the maze,
+    /// which picks uniformly among all perfect mazes.
+    fn wilson(&self, rng: &mut StdRng) {
+        let mut in_maze = vec![false; self.columns * self.rows];
+        let first = self.random(rng);
+
+        in_maze[self.id(first)] = true;
+        self.carve(None, first);
+
+        let mut order: Vec<Position> = (0..self.columns * self.rows)
+            .map(|i| (i % self.columns, i / self.columns))
+            .collect();
+        order.shuffle(rng);
+
+        // Where the walk last left each cell, so revisiting a cell erases the loop.
+        let mut exit = vec![None; self.columns * self.rows];
+
+        for start in order {
+            if in_maze[self.id(start)] {
+                continue;
+            }
+
+            let mut walked = vec![];
+            let mut current = start;
+
+            while !in_maze[self.id(current)] {
+                if self.state.interrupted() {
+                    return;
+                }
+
+                let next = *self.neighbours(current).choose(rng).unwrap();
+                exit[self.id(current)] = Some(next);
+                walked.push(current);
+
+                self.mark(current, true);
+                self.grid.get().current = Some(self.index(next));
+                self.state.sleep(None);
+
+                current = next;
+            }
+
+            for position in walked {
+                self.mark(position, false);
+            }
+
+            let mut current = start;
+
+            while !in_maze[self.id(current)] {
+                if self.state.interrupted() {
+                    return;
+                }
+
+                let next = exit[self.id(current)].unwrap();
+                in_maze[self.id(current)] = true;
+                self.carve(Some(next), current);
+                current = next;
+            }
+        }
+    }
+
+    /// Builds one row at a time, only remembering which cells of the current
+    /// row are already connected.
+    fn eller(&self, rng: &mut StdRng) {
+        let mut sets = vec![0; self.columns];
+        let mut next_set = 1;
+
+        for y in 0..self.rows {
+            let last = y + 1 == self.rows;
+
+            for (x, set) in sets.iter_mut().enumerate() {
+                if self.state.interrupted() {
+                    return;
+                }
+
+                if *set == 0 {
+                    *set = next_set;
+                    next_set += 1;
+                }
+
+                self.carve(None, (x, y));
+            }
+
+            for x in 0..self.columns - 1 {
+                if self.state.interrupted() {
+                    return;
+                }
+
+                // The last row has to join whatever is still apart.
+                if sets[x] != sets[x + 1] && (last || rng.random_bool(0.5)) {
+                    let (kept, merged) = (sets[x], sets[x + 1]);
+
+                    for set in sets.iter_mut().filter(|set| **set == merged) {
+                        *set = kept;
+                    }
+
+                    self.carve(Some((x, y)), (x + 1, y));
+                }
+            }
+
+            if last {
+                break;
+            }
+
+            let mut below = vec![0; self.columns];
+
+            for x in 0..self.columns {
+                if rng.random_bool(0.5) {
+                    below[x] = sets[x];
+                }
+            }
+
+            // Every set carries on downwards at least once.
+            for x in 0..self.columns {
+                if !below.contains(&sets[x]) {
+                    let members: Vec<usize> =
+                        (0..self.columns).filter(|&m| sets[m] == sets[x]).collect();
+                    let chosen = *members.choose(rng).unwrap();
+                    below[chosen] = sets[chosen];
+                }
+            }
+
+            for (x, &set) in below.iter().enumerate() {
+                if self.state.interrupted() {
+                    return;
+                }
+
+                if set != 0 {
+                    self.carve(Some((x, y)), (x, y + 1));
+                }
+            }
+
+            sets = below;
+        }
+    }
+
+    /// Splits open chambers in two with a wall that has a single gap, starting
+    /// from an empty field rather than carving passages.
+    fn recursive_division(&self, rng: &mut StdRng) {
+        let width = self.width;
+        let mut chambers = vec![(0, 0, self.columns, self.rows)];
+
+        while let Some((x, y, columns, rows)) = chambers.pop() {
+            if columns < 2 || rows < 2 {
+                continue;
+            }
+
+            let horizontal = match columns.cmp(&rows) {
+                Ordering::Less => true,
+                Ordering::Greater => false,
+                Ordering::Equal => rng.random_bool(0.5),
+            };
+
+            if horizontal {
+                let split = rng.random_range(1..rows);
+                let gap = x + rng.random_range(0..columns);
+                let row = 2 * (y + split);
+
+                for column in 2 * x + 1..2 * (x + columns) {
+                    if self.state.interrupted() {
+                        return;
+                    }
+
+                    if column != 2 * gap + 1 {
+                        self.set(row * width + column, Cell::Wall);
+                    }
+                }
+
+                chambers.push((x, y, columns, split));
+                chambers.push((x, y + split, columns, rows - split));
+            } else {
+                let split = rng.random_range(1..columns);
+                let gap = y + rng.random_range(0..rows);
+                let column = 2 * (x + split);
+
+                for row in 2 * y + 1..2 * (y + rows) {
+                    if self.state.interrupted() {
+                        return;
+                    }
+
+                    if row != 2 * gap + 1 {
+                        self.set(row * width + column, Cell::Wall);
+                    }
+                }
+
+                chambers.push((x, y, split, rows));
+                chambers.push((x + split, y, columns - split, rows));
+            }
+        }
+    }
+}
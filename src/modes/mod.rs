@@ -0,0 +1,72 @@
+use std::thread::JoinHandle;
+
+use crossterm::event::KeyCode;
+use ratatui::{layout::Rect, text::Line, Frame};
+
+use crate::state::{SharedState, State};
+
+pub mod backtracking;
+pub mod cellular_automata;
+pub mod dynamic;
+pub mod dynamic_programming;
+pub mod geometry;
+pub mod graph;
+pub mod graph_algorithms;
+pub mod grid;
+pub mod hash;
+pub mod hash_table;
+pub mod key_input;
+pub mod maze;
+pub mod pathfinding;
+pub mod plane;
+pub mod puzzle;
+pub mod search_tree;
+pub mod string_matching;
+pub mod text;
+pub mod torus;
+pub mod tree;
+
+/// A visualization besides sorting, switched to with Tab. The app keeps
+/// quitting, pausing and the speed to itself and forwards every other key.
+pub trait Mode {
+    fn name(&self) -> &'static str;
+
+    /// Spawns the selected algorithm, controlled through `state`.
+    fn start(&mut self, state: SharedState) -> JoinHandle<()>;
+
+    /// Handles a key, returning whether the algorithm has to start over.
+    fn handle_key(&mut self, key: KeyCode) -> bool;
+
+    /// Speed set on switching to the mode, which the user's changes then
+    /// override until it is left.
+    fn speed(&self) -> u32 {
+        100
+    }
+
+    /// Whether text is being typed, which sends the app's own keys here too.
+    fn editing(&self) -> bool {
+        false
+    }
+
+    /// Lines added to the overview after the algorithm's name.
+    fn overview(&self, state: &State) -> Vec<Line<'static>>;
+
+    fn help(&self) -> Vec<Line<'static>>;
+
+    fn draw(&self, frame: &mut Frame, area: Rect, state: &State);
+}
+
+/// Every mode in the order Tab cycles through them, after sorting.
+pub fn all() -> Vec<Box<dyn Mode>> {
+    vec![
+        Box::new(grid::GridMode::default()),
+        Box::new(graph::GraphMode::default()),
+        Box::new(tree::TreeMode::default()),
+        Box::new(hash::HashMode::default()),
+        Box::new(dynamic::DynamicMode::default()),
+        Box::new(puzzle::PuzzleMode::default()),
+        Box::new(text::TextMode::default()),
+        Box::new(plane::PlaneMode::default()),
+        Box::new(torus::TorusMode::default()),
+    ]
+}
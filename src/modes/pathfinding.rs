@@ -0,0 +1,214 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use super::grid::SharedGrid;
+use crate::state::{SharedState, Status};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Pathfinder {
+    BreadthFirst,
+    DepthFirst,
+    Dijkstra,
+    GreedyBestFirst,
+    AStar,
+}
+
+impl Pathfinder {
+    pub const ALL: [Pathfinder; 5] = [
+        Pathfinder::BreadthFirst,
+        Pathfinder::DepthFirst,
+        Pathfinder::Dijkstra,
+        Pathfinder::GreedyBestFirst,
+        Pathfinder::AStar,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pathfinder::BreadthFirst => "Breadth-First Search",
+            Pathfinder::DepthFirst => "Depth-First Search",
+            Pathfinder::Dijkstra => "Dijkstra",
+            Pathfinder::GreedyBestFirst => "Greedy Best-First Search",
+            Pathfinder::AStar => "A*",
+        }
+    }
+
+    pub fn uses_heuristic(&self) -> bool {
+        matches!(self, Pathfinder::GreedyBestFirst | Pathfinder::AStar)
+    }
+
+    /// Whether a cell is expanded again when a cheaper way to it turns up.
+    fn uses_cost(&self) -> bool {
+        matches!(self, Pathfinder::Dijkstra | Pathfinder::AStar)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Heuristic {
+    Manhattan,
+    Euclidean,
+    Chebyshev,
+}
+
+impl Heuristic {
+    pub const ALL: [Heuristic; 3] = [
+        Heuristic::Manhattan,
+        Heuristic::Euclidean,
+        Heuristic::Chebyshev,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Heuristic::Manhattan => "Manhattan",
+            Heuristic::Euclidean => "Euclidean",
+            Heuristic::Chebyshev => "Chebyshev",
+        }
+    }
+
+    /// Estimated cost between two positions, never more than the cheapest
+    /// path on a grid without diagonal moves.
+    pub fn estimate(&self, (x1, y1): (usize, usize), (x2, y2): (usize, usize)) -> u32 {
+        let (dx, dy) = (x1.abs_diff(x2) as u32, y1.abs_diff(y2) as u32);
+
+        match self {
+            Heuristic::Manhattan => dx + dy,
+            Heuristic::Euclidean => f64::from(dx * dx + dy * dy).sqrt() as u32,
+            Heuristic::Chebyshev => dx.max(dy),
+        }
+    }
+}
+
+impl Pathfinder {
+    /// Searches `grid` from its start to its goal, one expanded cell per step.
+    pub fn run(&self, state: &SharedState, grid: &SharedGrid, heuristic: Heuristic) {
+        let name = match self.uses_heuristic() {
+            true => format!("{} ({})", self.name(), heuristic.name()),
+            false => self.name().to_string(),
+        };
+
+        let (cells, start, goal, width) = {
+            let mut grid = grid.get();
+            grid.clear_search();
+            (grid.cells.clone(), grid.start, grid.goal, grid.width)
+        };
+        let position = |index: usize| (index % width, index / width);
+
+        state.init_algorithm(name.clone());
+
+        let len = cells.len();
+        let mut cost = vec![u32::MAX; len];
+        let mut came_from = vec![None; len];
+        let mut closed = vec![false; len];
+        // Ordered by priority, then by insertion so that ties leave in FIFO order.
+        let mut frontier = BinaryHeap::new();
+        let mut pushed: i64 = 0;
+
+        let priority = |cost: u32, index: usize, pushed: i64| -> i64 {
+            let estimate = || i64::from(heuristic.estimate(position(index), position(goal)));
+
+            match self {
+                Pathfinder::BreadthFirst => pushed,
+                Pathfinder::DepthFirst => -pushed,
+                Pathfinder::Dijkstra => i64::from(cost),
+                Pathfinder::GreedyBestFirst => estimate(),
+                Pathfinder::AStar => i64::from(cost) + estimate(),
+            }
+        };
+
+        cost[start] = 0;
+        frontier.push(Reverse((priority(0, start, 0), 0, start)));
+        grid.get().frontier[start] = true;
+
+        while let Some(Reverse((_, _, index))) = frontier.pop() {
+            if state.interrupted() {
+                return;
+            }
+
+            if closed[index] {
+                continue;
+            }
+
+            closed[index] = true;
+
+            let neighbours = {
+                let mut grid = grid.get();
+                grid.frontier[index] = false;
+                grid.visited[index] = true;
+                grid.current = Some(index);
+                grid.expanded += 1;
+                grid.neighbours(index)
+            };
+
+            if index == goal {
+                break;
+            }
+
+            for neighbour in neighbours {
+                let Some(step) = cells[neighbour].cost() else {
+                    continue;
+                };
+
+                state.increment_comparisons();
+
+                let new_cost = cost[index] + step;
+                let improves = match self {
+                    Pathfinder::DepthFirst => !closed[neighbour],
+                    _ if self.uses_cost() => new_cost < cost[neighbour],
+                    _ => cost[neighbour] == u32::MAX,
+                };
+
+                if !improves {
+                    continue;
+                }
+
+                cost[neighbour] = new_cost;
+                came_from[neighbour] = Some(index);
+                pushed += 1;
+                frontier.push(Reverse((
+                    priority(new_cost, neighbour, pushed),
+                    pushed,
+                    neighbour,
+                )));
+                grid.get().frontier[neighbour] = true;
+            }
+
+            state.sleep(None);
+        }
+
+        let mut grid = grid.get();
+        grid.current = None;
+
+        if !closed[goal] {
+            state.log(format!(
+                "{}: no path after expanding {} cells",
+                name, grid.expanded
+            ));
+            state.set_status(Status::Completed);
+            return;
+        }
+
+        let mut path = vec![goal];
+
+        while let Some(previous) = came_from[path[path.len() - 1]] {
+            path.push(previous);
+        }
+
+        path.reverse();
+
+        // Depth-first search overwrites costs as it goes, so sum the path itself.
+        let path_cost = path[1..]
+            .iter()
+            .filter_map(|&index| cells[index].cost())
+            .sum();
+
+        state.log(format!(
+            "{}: path of {} steps costing {} after expanding {} cells",
+            name,
+            path.len() - 1,
+            path_cost,
+            grid.expanded
+        ));
+
+        grid.path = path;
+        grid.cost = Some(path_cost);
+        state.set_status(Status::Completed);
+    }
+}
@@ -0,0 +1,313 @@
+use std::{
+    fs,
+    thread::{self, JoinHandle},
+};
+
+use crossterm::{event::KeyCode, style::Color};
+use rand::Rng;
+use ratatui::{
+    layout::Rect,
+    style::Stylize,
+    symbols,
+    text::Line,
+    widgets::{
+        canvas::{self, Canvas, Circle},
+        Block, BorderType, Borders,
+    },
+    Frame,
+};
+
+use super::{
+    geometry::{self, Algorithm, Plane, Point, SharedPlane},
+    Mode,
+};
+use crate::state::{SharedState, State};
+
+/// Point list read by the import key, relative to the working directory.
+pub const POINTS_FILE: &str = "points.txt";
+
+pub struct PlaneMode {
+    plane: SharedPlane,
+    algorithm_index: usize,
+    /// Seed of the random points, or `None` once some are imported.
+    seed: Option<u64>,
+    /// Points from the last import, used instead of random ones.
+    imported: Option<Vec<Point>>,
+    /// Outcome of the last import.
+    file: Option<String>,
+}
+
+impl Default for PlaneMode {
+    fn default() -> Self {
+        let seed = u64::from(rand::rng().random::<u32>());
+
+        Self {
+            plane: SharedPlane::new(Plane::new(geometry::random_points(seed, false), false)),
+            algorithm_index: 0,
+            seed: Some(seed),
+            imported: None,
+            file: None,
+        }
+    }
+}
+
+impl PlaneMode {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::ALL[self.algorithm_index % Algorithm::ALL.len()]
+    }
+
+    fn switch_algorithm(&mut self, increment: usize) -> bool {
+        self.algorithm_index = (self.algorithm_index + increment) % Algorithm::ALL.len();
+        true
+    }
+
+    /// Points for the selected algorithm, which pairs them up for the sweep.
+    fn points(&self) -> Vec<Point> {
+        let segments = self.algorithm() == Algorithm::SegmentIntersection;
+
+        match &self.imported {
+            Some(points) => points.clone(),
+            None => geometry::random_points(self.seed.unwrap_or(0), segments),
+        }
+    }
+
+    fn import(&mut self) -> bool {
+        let points = fs::read_to_string(POINTS_FILE)
+            .map_err(|error| error.to_string())
+            .and_then(|text| geometry::parse_points(&text));
+
+        match points {
+            Ok(points) => {
+                self.imported = Some(points);
+                self.seed = None;
+                self.file = Some(format!("imported from {}", POINTS_FILE));
+                true
+            }
+            Err(error) => {
+                self.file = Some(format!("import failed: {}", error));
+                false
+            }
+        }
+    }
+}
+
+impl Mode for PlaneMode {
+    fn name(&self) -> &'static str {
+        "Geometry"
+    }
+
+    fn speed(&self) -> u32 {
+        85
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        let (algorithm, plane, points) = (self.algorithm(), self.plane.clone(), self.points());
+
+        thread::spawn(move || algorithm.run(&state, &plane, points))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('l') => self.switch_algorithm(1),
+            KeyCode::Char('h') => self.switch_algorithm(Algorithm::ALL.len() - 1),
+            KeyCode::Char('g') => {
+                self.seed = Some(u64::from(rand::rng().random::<u32>()));
+                self.imported = None;
+                true
+            }
+            KeyCode::Char('i') => self.import(),
+            KeyCode::Char('r') => true,
+            _ => false,
+        }
+    }
+
+    fn overview(&self, state: &State) -> Vec<Line<'static>> {
+        let plane = self.plane.get();
+        let algorithm = self.algorithm();
+
+        let mut overview: Vec<Line> = vec![
+            match plane.segments.is_empty() {
+                true => format!("Points: {}", plane.points.len()).into(),
+                false => format!("Segments: {}", plane.segments.len()).into(),
+            },
+            format!(
+                "Seed: {}",
+                self.seed.map_or("-".to_string(), |seed| seed.to_string())
+            )
+            .into(),
+            format!("{}: {}", algorithm.counted(), state.comparisons).into(),
+        ];
+
+        overview.push(match algorithm {
+            Algorithm::ClosestPair => {
+                let distance = plane.closest.map_or("-".to_string(), |(a, b)| {
+                    let ((x1, y1), (x2, y2)) = (plane.points[a], plane.points[b]);
+                    format!(
+                        "{:.2}",
+                        (((x1 - x2).pow(2) + (y1 - y2).pow(2)) as f64).sqrt()
+                    )
+                });
+
+                format!("Closest Distance: {}", distance).into()
+            }
+            Algorithm::SegmentIntersection => {
+                format!("Intersections: {}", plane.intersections.len()).into()
+            }
+            _ => format!("Hull Points: {}", plane.chain.len()).into(),
+        });
+
+        if let Some(file) = &self.file {
+            overview.push(format!("File: {}", file).into());
+        }
+
+        overview
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        vec![
+            "Rerun: <R>".into(),
+            "Next: <L>".into(),
+            "Previous: <H>".into(),
+            "Random Points: <G>".into(),
+            format!("Import: <I> ({})", POINTS_FILE).into(),
+        ]
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, _state: &State) {
+        let plane = self.plane.get();
+        let algorithm = self.algorithm();
+
+        let position = |index: usize| {
+            let (x, y) = plane.points[index];
+            (x as f64, y as f64)
+        };
+
+        // Imported points can lie anywhere, so the view fits around them.
+        let bounds = |coordinate: fn(&Point) -> i64| {
+            let (min, max) = plane
+                .points
+                .iter()
+                .map(coordinate)
+                .fold((0, geometry::COORDINATE_MAX), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                });
+            let margin = (max - min) as f64 * 0.05;
+
+            [min as f64 - margin, max as f64 + margin]
+        };
+        let (x_bounds, y_bounds) = (bounds(|point| point.0), bounds(|point| point.1));
+        let radius = (x_bounds[1] - x_bounds[0]) * 0.005;
+
+        let legend = Line::from(vec![
+            " tested ".fg(Color::Yellow),
+            match algorithm {
+                Algorithm::ClosestPair => " closest ".fg(Color::Green),
+                Algorithm::SegmentIntersection => " crossing ".fg(Color::Red),
+                _ => " hull ".fg(Color::Green),
+            },
+            match algorithm {
+                Algorithm::SegmentIntersection => " swept ".fg(Color::Cyan),
+                _ => " working set ".fg(Color::Cyan),
+            },
+        ]);
+
+        let block = Block::new()
+            .title(Line::raw(format!(" {} ", algorithm.name())).centered())
+            .title_bottom(legend.centered())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        let line = |(x1, y1): (f64, f64), (x2, y2): (f64, f64), color: Color| {
+            canvas::Line::new(x1, y1, x2, y2, color.into())
+        };
+
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(symbols::Marker::Braille)
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds)
+            .paint(|ctx| {
+                if let Some((left, right)) = plane.strip {
+                    for x in [left, right] {
+                        ctx.draw(&line((x, y_bounds[0]), (x, y_bounds[1]), Color::DarkGrey));
+                    }
+                }
+
+                if let Some(x) = plane.sweep {
+                    ctx.draw(&line((x, y_bounds[0]), (x, y_bounds[1]), Color::Yellow));
+                }
+
+                for (index, &(a, b)) in plane.segments.iter().enumerate() {
+                    let checked = plane
+                        .checking
+                        .is_some_and(|(first, second)| index == first || index == second);
+
+                    let color = if checked {
+                        Color::Yellow
+                    } else if plane.active.contains(&index) {
+                        Color::Cyan
+                    } else {
+                        Color::DarkGrey
+                    };
+
+                    ctx.draw(&line(position(a), position(b), color));
+                }
+
+                let edges = match plane.closed && plane.chain.len() > 2 {
+                    true => plane.chain.len(),
+                    false => plane.chain.len().saturating_sub(1),
+                };
+
+                for i in 0..edges {
+                    let (from, to) = (plane.chain[i], plane.chain[(i + 1) % plane.chain.len()]);
+                    ctx.draw(&line(position(from), position(to), Color::Green));
+                }
+
+                if let Some((a, b)) = plane.closest {
+                    ctx.draw(&line(position(a), position(b), Color::Green));
+                }
+
+                if plane.segments.is_empty() {
+                    if let Some((a, b)) = plane.checking {
+                        ctx.draw(&line(position(a), position(b), Color::Yellow));
+                    }
+                }
+
+                if let Some([a, b, c]) = plane.tested.filter(|_| plane.segments.is_empty()) {
+                    ctx.draw(&line(position(a), position(b), Color::Yellow));
+                    ctx.draw(&line(position(b), position(c), Color::Yellow));
+                }
+
+                ctx.layer();
+
+                for index in 0..plane.points.len() {
+                    let color = if plane.chain.contains(&index) {
+                        Color::Green
+                    } else if plane.region.contains(&index) {
+                        Color::Cyan
+                    } else {
+                        Color::White
+                    };
+
+                    let (x, y) = position(index);
+                    ctx.draw(&Circle {
+                        x,
+                        y,
+                        radius,
+                        color: color.into(),
+                    });
+                }
+
+                for &(x, y) in &plane.intersections {
+                    ctx.draw(&Circle {
+                        x,
+                        y,
+                        radius,
+                        color: Color::Red.into(),
+                    });
+                }
+            });
+
+        frame.render_widget(canvas, area);
+    }
+}
@@ -0,0 +1,308 @@
+use std::{
+    fs,
+    thread::{self, JoinHandle},
+};
+
+use crossterm::{event::KeyCode, style::Color};
+use rand::Rng;
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+use super::{
+    backtracking::{self, Board, Puzzle, SharedBoard},
+    Mode,
+};
+use crate::state::{SharedState, State};
+
+pub const SUDOKU_FILE: &str = "sudoku.txt";
+/// The puzzle solved until another one is imported.
+const SUDOKU: &str = "
+    53..7....
+    6..195...
+    .98....6.
+    8...6...3
+    4..8.3..1
+    7...2...6
+    .6....28.
+    ...419..5
+    ....8..79
+";
+/// Largest number to pick from for subset sum.
+const SUBSET_MAX: u32 = 40;
+
+pub struct PuzzleMode {
+    board: SharedBoard,
+    puzzle_index: usize,
+    /// Board side or amount of numbers for every puzzle.
+    sizes: Vec<usize>,
+    sudoku: Vec<u32>,
+    /// Numbers and target for subset sum, drawn again with G or a new size.
+    subset: (Vec<u32>, u32),
+    /// Outcome of the last import, if any.
+    file: Option<String>,
+}
+
+impl Default for PuzzleMode {
+    fn default() -> Self {
+        let sizes: Vec<usize> = Puzzle::ALL.iter().map(|puzzle| puzzle.sizes().1).collect();
+        let subset = random_subset(Puzzle::SubsetSum.sizes().1);
+
+        Self {
+            board: SharedBoard::new(Board::queens(sizes[0])),
+            puzzle_index: 0,
+            sizes,
+            sudoku: backtracking::parse_sudoku(SUDOKU).unwrap(),
+            subset,
+            file: None,
+        }
+    }
+}
+
+/// `count` numbers and a target some of them add up to.
+fn random_subset(count: usize) -> (Vec<u32>, u32) {
+    let mut rng = rand::rng();
+    let values: Vec<u32> = (0..count)
+        .map(|_| rng.random_range(1..=SUBSET_MAX))
+        .collect();
+    let target = values.iter().filter(|_| rng.random_bool(0.5)).sum::<u32>();
+    let target = target.max(values[0]);
+
+    (values, target)
+}
+
+impl PuzzleMode {
+    fn puzzle(&self) -> Puzzle {
+        Puzzle::ALL[self.puzzle_index % Puzzle::ALL.len()]
+    }
+
+    fn switch_puzzle(&mut self, increment: usize) -> bool {
+        self.puzzle_index = (self.puzzle_index + increment) % Puzzle::ALL.len();
+        true
+    }
+
+    fn resize(&mut self, grow: bool) -> bool {
+        let (min, _, max) = self.puzzle().sizes();
+        let size = &mut self.sizes[self.puzzle_index];
+        let resized = match grow {
+            true => (*size + 1).min(max),
+            false => size.saturating_sub(1).max(min),
+        };
+
+        if resized == *size {
+            return false;
+        }
+
+        *size = resized;
+
+        if self.puzzle() == Puzzle::SubsetSum {
+            self.subset = random_subset(resized);
+        }
+
+        true
+    }
+
+    fn import(&mut self) -> bool {
+        let sudoku = fs::read_to_string(SUDOKU_FILE)
+            .map_err(|error| error.to_string())
+            .and_then(|text| backtracking::parse_sudoku(&text));
+
+        match sudoku {
+            Ok(sudoku) => {
+                self.sudoku = sudoku;
+                self.puzzle_index = Puzzle::ALL
+                    .iter()
+                    .position(|&puzzle| puzzle == Puzzle::Sudoku)
+                    .unwrap_or(0);
+                self.file = Some(format!("imported from {}", SUDOKU_FILE));
+                true
+            }
+            Err(error) => {
+                self.file = Some(format!("import failed: {}", error));
+                false
+            }
+        }
+    }
+
+    fn initial_board(&self) -> Board {
+        let size = self.sizes[self.puzzle_index];
+
+        match self.puzzle() {
+            Puzzle::NQueens => Board::queens(size),
+            Puzzle::Sudoku => Board::sudoku(&self.sudoku),
+            Puzzle::KnightsTour => Board::knights_tour(size),
+            Puzzle::SubsetSum => Board::subset_sum(self.subset.0.clone(), self.subset.1),
+        }
+    }
+}
+
+impl Mode for PuzzleMode {
+    fn name(&self) -> &'static str {
+        "Backtracking"
+    }
+
+    fn speed(&self) -> u32 {
+        self.puzzle().speed()
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        let (puzzle, board, initial) = (self.puzzle(), self.board.clone(), self.initial_board());
+
+        thread::spawn(move || puzzle.run(&state, &board, initial))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('l') => self.switch_puzzle(1),
+            KeyCode::Char('h') => self.switch_puzzle(Puzzle::ALL.len() - 1),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.resize(true),
+            KeyCode::Char('-') => self.resize(false),
+            KeyCode::Char('i') => self.import(),
+            KeyCode::Char('g') if self.puzzle() == Puzzle::SubsetSum => {
+                self.subset = random_subset(self.sizes[self.puzzle_index]);
+                true
+            }
+            KeyCode::Char('r') => true,
+            _ => false,
+        }
+    }
+
+    fn overview(&self, state: &State) -> Vec<Line<'static>> {
+        let board = self.board.get();
+
+        let mut overview: Vec<Line> = vec![match board.puzzle {
+            Puzzle::SubsetSum => format!("Sum: {} of {}", board.sum(), board.target).into(),
+            _ => format!("Board: {}x{}", board.width, board.height).into(),
+        }];
+
+        overview.extend([
+            format!(
+                "Recursion Depth: {} (deepest {})",
+                state.depth, board.deepest
+            )
+            .into(),
+            format!("Nodes Explored: {}", state.nodes).into(),
+            format!("Backtracks: {}", board.backtracks).into(),
+        ]);
+
+        if let Some(file) = &self.file {
+            overview.push(format!("File: {}", file).into());
+        }
+
+        overview
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        let mut help: Vec<Line> = vec![
+            "Replay: <R>".into(),
+            "Next Puzzle: <L>".into(),
+            "Previous Puzzle: <H>".into(),
+        ];
+
+        match self.puzzle() {
+            Puzzle::Sudoku => {}
+            Puzzle::SubsetSum => help.extend([
+                "More/Fewer Numbers: <+/->".into(),
+                "New Numbers: <G>".into(),
+            ]),
+            _ => help.push("Bigger/Smaller Board: <+/->".into()),
+        }
+
+        help.push(format!("Import Sudoku: <I> ({})", SUDOKU_FILE).into());
+        help
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, _state: &State) {
+        let board = self.board.get();
+
+        let style = |index: usize| {
+            let style = if Some(index) == board.rejected {
+                Style::new().black().on_red()
+            } else if Some(index) == board.current {
+                Style::new().black().on_yellow()
+            } else if board.fixed[index] {
+                Style::new().white().bold()
+            } else {
+                Style::new().cyan()
+            };
+
+            // Chess boards get their squares, a sudoku its boxes.
+            let (column, row) = (index % board.width, index / board.width);
+            let shaded = match board.puzzle {
+                Puzzle::NQueens | Puzzle::KnightsTour => (column + row) % 2 == 1,
+                Puzzle::Sudoku => (column / 3 + row / 3) % 2 == 1,
+                Puzzle::SubsetSum => false,
+            };
+
+            match shaded && style.bg.is_none() {
+                true => style.on_dark_gray(),
+                false => style,
+            }
+        };
+
+        let text = |index: usize| match (board.puzzle, board.cells[index]) {
+            (Puzzle::SubsetSum, _) => board.values[index].to_string(),
+            (Puzzle::NQueens, 1) => "♛".to_string(),
+            (Puzzle::NQueens, _) if Some(index) == board.rejected => "♛".to_string(),
+            (_, 0) if Some(index) == board.rejected => "×".to_string(),
+            (_, 0) => "·".to_string(),
+            (_, value) => value.to_string(),
+        };
+
+        let width = match board.puzzle {
+            Puzzle::KnightsTour => 4,
+            Puzzle::SubsetSum => 5,
+            _ => 3,
+        };
+
+        let mut lines: Vec<Line> = (0..board.height)
+            .map(|row| {
+                Line::from(
+                    (0..board.width)
+                        .map(|column| {
+                            let index = row * board.width + column;
+                            Span::styled(format!("{:^width$}", text(index)), style(index))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+
+        if board.puzzle == Puzzle::SubsetSum {
+            lines.push(Line::from(
+                board
+                    .cells
+                    .iter()
+                    .map(|&cell| match cell {
+                        1 => format!("{:^width$}", "take").green(),
+                        2 => format!("{:^width$}", "skip").dark_gray(),
+                        _ => format!("{:^width$}", "·").dark_gray(),
+                    })
+                    .collect::<Vec<_>>(),
+            ));
+        }
+
+        let top = usize::from(area.height.saturating_sub(2)).saturating_sub(lines.len()) / 2;
+        let lines: Vec<Line> = std::iter::repeat_n(Line::raw(""), top)
+            .chain(lines)
+            .collect();
+
+        let block = Block::new()
+            .title(Line::raw(format!(" {} ", board.puzzle.name())).centered())
+            .title_bottom(
+                Line::from(vec![
+                    " placing ".fg(Color::Yellow),
+                    " ruled out ".fg(Color::Red),
+                ])
+                .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        frame.render_widget(Paragraph::new(lines).centered().block(block), area);
+    }
+}
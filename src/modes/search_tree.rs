@@ -0,0 +1,631 @@
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use super::key_input::KeyOperation;
+use crate::state::{SharedState, Status};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum TreeKind {
+    Plain,
+    Avl,
+    RedBlack,
+}
+
+impl TreeKind {
+    pub const ALL: [TreeKind; 3] = [TreeKind::Plain, TreeKind::Avl, TreeKind::RedBlack];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TreeKind::Plain => "Binary Search Tree",
+            TreeKind::Avl => "AVL Tree",
+            TreeKind::RedBlack => "Red-Black Tree",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Node {
+    pub key: u32,
+    pub left: Option<usize>,
+    pub right: Option<usize>,
+    pub parent: Option<usize>,
+    /// Height of the subtree, only kept up to date for AVL trees.
+    pub height: i32,
+    pub red: bool,
+}
+
+/// Nodes live in an arena and are never reused, so removed ones are simply unreachable.
+pub struct Tree {
+    pub kind: TreeKind,
+    pub nodes: Vec<Node>,
+    pub root: Option<usize>,
+    pub len: usize,
+    pub rotations: u32,
+    /// Keys in the order they went in, to rebuild the tree as another kind.
+    pub keys: Vec<u32>,
+    pub current: Option<usize>,
+    /// Nodes compared against by the current operation.
+    pub path: Vec<usize>,
+    pub found: Option<usize>,
+}
+
+impl Tree {
+    pub fn new(kind: TreeKind) -> Self {
+        Self {
+            kind,
+            nodes: vec![],
+            root: None,
+            len: 0,
+            rotations: 0,
+            keys: vec![],
+            current: None,
+            path: vec![],
+            found: None,
+        }
+    }
+
+    fn height(&self, node: Option<usize>) -> i32 {
+        node.map_or(0, |node| self.nodes[node].height)
+    }
+
+    /// Left height minus right height.
+    pub fn balance(&self, node: usize) -> i32 {
+        self.height(self.nodes[node].left) - self.height(self.nodes[node].right)
+    }
+
+    fn update(&mut self, node: usize) {
+        self.nodes[node].height = 1 + self
+            .height(self.nodes[node].left)
+            .max(self.height(self.nodes[node].right));
+    }
+
+    /// Whether `node` is red, missing leaves counting as black.
+    fn is_red(&self, node: Option<usize>) -> bool {
+        node.is_some_and(|node| self.nodes[node].red)
+    }
+
+    fn set_black(&mut self, node: Option<usize>) {
+        if let Some(node) = node {
+            self.nodes[node].red = false;
+        }
+    }
+
+    /// Points whatever pointed at `old` from above to `new` instead.
+    fn replace_child(&mut self, parent: Option<usize>, old: usize, new: Option<usize>) {
+        match parent {
+            None => self.root = new,
+            Some(parent) if self.nodes[parent].left == Some(old) => self.nodes[parent].left = new,
+            Some(parent) => self.nodes[parent].right = new,
+        }
+
+        if let Some(new) = new {
+            self.nodes[new].parent = parent;
+        }
+    }
+
+    fn rotate_left(&mut self, node: usize) {
+        let pivot = self.nodes[node].right.unwrap();
+        let inner = self.nodes[pivot].left;
+
+        self.nodes[node].right = inner;
+        if let Some(inner) = inner {
+            self.nodes[inner].parent = Some(node);
+        }
+
+        self.replace_child(self.nodes[node].parent, node, Some(pivot));
+        self.nodes[pivot].left = Some(node);
+        self.nodes[node].parent = Some(pivot);
+
+        self.update(node);
+        self.update(pivot);
+        self.rotations += 1;
+    }
+
+    fn rotate_right(&mut self, node: usize) {
+        let pivot = self.nodes[node].left.unwrap();
+        let inner = self.nodes[pivot].right;
+
+        self.nodes[node].left = inner;
+        if let Some(inner) = inner {
+            self.nodes[inner].parent = Some(node);
+        }
+
+        self.replace_child(self.nodes[node].parent, node, Some(pivot));
+        self.nodes[pivot].right = Some(node);
+        self.nodes[node].parent = Some(pivot);
+
+        self.update(node);
+        self.update(pivot);
+        self.rotations += 1;
+    }
+
+    /// Every reachable node with its in-order position and depth.
+    pub fn layout(&self) -> Vec<(usize, usize, usize)> {
+        let mut layout = vec![];
+        let mut stack = vec![];
+        let mut current = self.root.map(|root| (root, 0));
+
+        loop {
+            while let Some((node, depth)) = current {
+                stack.push((node, depth));
+                current = self.nodes[node].left.map(|left| (left, depth + 1));
+            }
+
+            let Some((node, depth)) = stack.pop() else {
+                break;
+            };
+
+            layout.push((node, layout.len(), depth));
+            current = self.nodes[node].right.map(|right| (right, depth + 1));
+        }
+
+        layout
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedTree(Arc<Mutex<Tree>>);
+
+impl SharedTree {
+    pub fn new(tree: Tree) -> Self {
+        Self(Arc::new(Mutex::new(tree)))
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, Tree> {
+        self.0.lock().unwrap()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Operation {
+    Insert(u32),
+    Delete(u32),
+    Search(u32),
+    /// Empties the tree.
+    Clear,
+    /// Reinserts the keys into an empty tree of another kind without animating.
+    Rebuild(TreeKind),
+}
+
+impl KeyOperation for Operation {
+    fn insert(key: u32) -> Self {
+        Operation::Insert(key)
+    }
+
+    fn clear() -> Self {
+        Operation::Clear
+    }
+}
+
+/// Where a search for a key ended up.
+enum Descent {
+    Found(usize),
+    /// The parent a new node would hang from, on the side the key compared to it.
+    Missing(Option<usize>, Ordering),
+}
+
+/// Applies `operations` to `tree` one step at a time. The tree is only left
+/// between operations when interrupted, so it always satisfies its invariants.
+pub fn run(state: &SharedState, tree: &SharedTree, operations: Vec<Operation>) {
+    let runner = Runner {
+        state,
+        tree,
+        quiet: Cell::new(false),
+    };
+
+    state.set_algorithm(tree.get().kind.name().to_string());
+    state.set_status(Status::Running);
+
+    for (done, &operation) in operations.iter().enumerate() {
+        if state.interrupted() {
+            return;
+        }
+
+        {
+            let mut tree = tree.get();
+            tree.path.clear();
+            tree.found = None;
+        }
+
+        let message = match operation {
+            Operation::Insert(key) => runner.insert(key),
+            Operation::Delete(key) => runner.delete(key),
+            Operation::Search(key) => runner.search(key),
+            Operation::Clear => {
+                let kind = tree.get().kind;
+                *tree.get() = Tree::new(kind);
+                Some("Cleared the tree".to_string())
+            }
+            Operation::Rebuild(kind) => {
+                let keys = std::mem::take(&mut tree.get().keys);
+                *tree.get() = Tree::new(kind);
+                state.set_algorithm(kind.name().to_string());
+
+                runner.quiet.set(true);
+                for &key in &keys {
+                    runner.insert(key);
+                }
+                runner.quiet.set(false);
+
+                Some(format!("Rebuilt {} keys as a {}", keys.len(), kind.name()))
+            }
+        };
+
+        let Some(message) = message else {
+            return;
+        };
+
+        let height = tree
+            .get()
+            .layout()
+            .iter()
+            .map(|&(_, _, depth)| depth + 1)
+            .max();
+
+        match operations.len() {
+            1 => state.log(format!("{}, height {}", message, height.unwrap_or(0))),
+            len => state.log(format!(
+                "{} ({} of {}), height {}",
+                message,
+                done + 1,
+                len,
+                height.unwrap_or(0)
+            )),
+        };
+    }
+
+    tree.get().current = None;
+    state.set_status(Status::Completed);
+}
+
+struct Runner<'a> {
+    state: &'a SharedState,
+    tree: &'a SharedTree,
+    /// Set while rebuilding, which skips every step.
+    quiet: Cell<bool>,
+}
+
+impl Runner<'_> {
+    /// Highlights `node` for one step, returning whether the run was interrupted.
+    /// Changes already under way finish regardless, just without waiting.
+    fn step(&self, node: usize) -> bool {
+        if self.quiet.get() {
+            return false;
+        }
+
+        {
+            let mut tree = self.tree.get();
+            tree.current = Some(node);
+            tree.path.push(node);
+        }
+
+        if self.state.interrupted() {
+            return true;
+        }
+
+        self.state.sleep(None);
+        false
+    }
+
+    fn descend(&self, key: u32) -> Option<Descent> {
+        let mut parent = None;
+        let mut current = self.tree.get().root;
+        let mut side = Ordering::Equal;
+
+        while let Some(node) = current {
+            if self.step(node) {
+                return None;
+            }
+
+            if !self.quiet.get() {
+                self.state.increment_comparisons();
+            }
+
+            let tree = self.tree.get();
+            side = key.cmp(&tree.nodes[node].key);
+            parent = Some(node);
+
+            current = match side {
+                Ordering::Equal => return Some(Descent::Found(node)),
+                Ordering::Less => tree.nodes[node].left,
+                Ordering::Greater => tree.nodes[node].right,
+            };
+        }
+
+        Some(Descent::Missing(parent, side))
+    }
+
+    fn search(&self, key: u32) -> Option<String> {
+        let descent = self.descend(key)?;
+        let comparisons = self.tree.get().path.len();
+
+        Some(match descent {
+            Descent::Found(node) => {
+                self.tree.get().found = Some(node);
+                format!("Found {} after {} comparisons", key, comparisons)
+            }
+            Descent::Missing(..) => format!("{} not found after {} comparisons", key, comparisons),
+        })
+    }
+
+    fn insert(&self, key: u32) -> Option<String> {
+        let Descent::Missing(parent, side) = self.descend(key)? else {
+            return Some(format!("{} is already in the tree", key));
+        };
+
+        let (node, kind) = {
+            let mut tree = self.tree.get();
+            let node = tree.nodes.len();
+            let kind = tree.kind;
+
+            tree.nodes.push(Node {
+                key,
+                left: None,
+                right: None,
+                parent,
+                height: 1,
+                red: kind == TreeKind::RedBlack,
+            });
+
+            match parent {
+                None => tree.root = Some(node),
+                Some(parent) if side == Ordering::Less => tree.nodes[parent].left = Some(node),
+                Some(parent) => tree.nodes[parent].right = Some(node),
+            }
+
+            tree.len += 1;
+            tree.keys.push(key);
+            (node, kind)
+        };
+
+        self.step(node);
+
+        match kind {
+            TreeKind::Plain => {}
+            TreeKind::Avl => self.rebalance(parent),
+            TreeKind::RedBlack => self.insert_fixup(node),
+        }
+
+        Some(format!("Inserted {}", key))
+    }
+
+    fn delete(&self, key: u32) -> Option<String> {
+        let Descent::Found(node) = self.descend(key)? else {
+            return Some(format!("{} is not in the tree", key));
+        };
+
+        // A node with two children takes its successor's key, and the successor goes instead.
+        let mut removed = node;
+        let right = {
+            let tree = self.tree.get();
+            tree.nodes[node].left.and(tree.nodes[node].right)
+        };
+
+        if let Some(right) = right {
+            removed = right;
+            self.step(removed);
+
+            loop {
+                let left = self.tree.get().nodes[removed].left;
+
+                let Some(left) = left else {
+                    break;
+                };
+
+                removed = left;
+                self.step(removed);
+            }
+
+            let mut tree = self.tree.get();
+            tree.nodes[node].key = tree.nodes[removed].key;
+        }
+
+        let (child, parent, red, kind) = {
+            let mut tree = self.tree.get();
+            let Node {
+                left,
+                right,
+                parent,
+                red,
+                ..
+            } = tree.nodes[removed];
+            let child = left.or(right);
+
+            tree.replace_child(parent, removed, child);
+            tree.len -= 1;
+            tree.keys.retain(|&other| other != key);
+            tree.current = parent;
+
+            (child, parent, red, tree.kind)
+        };
+
+        match kind {
+            TreeKind::Plain => {}
+            TreeKind::Avl => self.rebalance(parent),
+            TreeKind::RedBlack if red => {}
+            // A red child takes over the removed black.
+            TreeKind::RedBlack => {
+                let red_child = self.tree.get().is_red(child);
+
+                match red_child {
+                    true => self.tree.get().set_black(child),
+                    false => self.delete_fixup(child, parent),
+                }
+            }
+        }
+
+        Some(format!("Deleted {}", key))
+    }
+
+    /// Walks up from `start` fixing heights and rotating wherever the two
+    /// sides differ by more than one. An inner-heavy child is rotated first.
+    fn rebalance(&self, start: Option<usize>) {
+        let mut next = start;
+
+        while let Some(node) = next {
+            let (pivot, following) = {
+                let mut tree = self.tree.get();
+                tree.update(node);
+                let balance = tree.balance(node);
+                let (left, right) = (tree.nodes[node].left, tree.nodes[node].right);
+
+                if balance > 1 && tree.balance(left.unwrap()) < 0 {
+                    tree.rotate_left(left.unwrap());
+                    (Some(node), Some(node))
+                } else if balance < -1 && tree.balance(right.unwrap()) > 0 {
+                    tree.rotate_right(right.unwrap());
+                    (Some(node), Some(node))
+                } else if !(-1..=1).contains(&balance) {
+                    match balance > 1 {
+                        true => tree.rotate_right(node),
+                        false => tree.rotate_left(node),
+                    }
+
+                    let top = tree.nodes[node].parent.unwrap();
+                    (Some(top), tree.nodes[top].parent)
+                } else {
+                    (None, tree.nodes[node].parent)
+                }
+            };
+
+            if let Some(pivot) = pivot {
+                self.step(pivot);
+            }
+
+            next = following;
+        }
+    }
+
+    /// Restores the red-black rules after inserting the red `node`, one
+    /// recolouring or rotation per step.
+    fn insert_fixup(&self, mut node: usize) {
+        loop {
+            let outcome = {
+                let mut tree = self.tree.get();
+
+                match tree.nodes[node].parent {
+                    Some(parent) if tree.nodes[parent].red => {
+                        // A red parent is never the root, so there is a grandparent.
+                        let grandparent = tree.nodes[parent].parent.unwrap();
+                        let parent_is_left = tree.nodes[grandparent].left == Some(parent);
+                        let uncle = match parent_is_left {
+                            true => tree.nodes[grandparent].right,
+                            false => tree.nodes[grandparent].left,
+                        };
+
+                        if tree.is_red(uncle) {
+                            tree.set_black(Some(parent));
+                            tree.set_black(uncle);
+                            tree.nodes[grandparent].red = true;
+                            Some((grandparent, grandparent))
+                        } else if parent_is_left && tree.nodes[parent].right == Some(node) {
+                            tree.rotate_left(parent);
+                            Some((parent, parent))
+                        } else if !parent_is_left && tree.nodes[parent].left == Some(node) {
+                            tree.rotate_right(parent);
+                            Some((parent, parent))
+                        } else {
+                            tree.set_black(Some(parent));
+                            tree.nodes[grandparent].red = true;
+
+                            match parent_is_left {
+                                true => tree.rotate_right(grandparent),
+                                false => tree.rotate_left(grandparent),
+                            }
+
+                            Some((parent, node))
+                        }
+                    }
+                    _ => None,
+                }
+            };
+
+            let Some((pivot, next)) = outcome else {
+                break;
+            };
+
+            self.step(pivot);
+            node = next;
+        }
+
+        let mut tree = self.tree.get();
+        let root = tree.root;
+        tree.set_black(root);
+    }
+
+    /// Pushes the black missing from `node`, which hangs from `parent`, up the
+    /// tree or absorbs it through the sibling, one recolouring or rotation per step.
+    fn delete_fixup(&self, mut node: Option<usize>, mut parent: Option<usize>) {
+        loop {
+            let outcome = {
+                let mut tree = self.tree.get();
+
+                match parent {
+                    Some(above) if node != tree.root && !tree.is_red(node) => {
+                        let is_left = tree.nodes[above].left == node;
+                        // The missing black means the other side has a node.
+                        let sibling = match is_left {
+                            true => tree.nodes[above].right,
+                            false => tree.nodes[above].left,
+                        }
+                        .unwrap();
+                        let (near, far) = match is_left {
+                            true => (tree.nodes[sibling].left, tree.nodes[sibling].right),
+                            false => (tree.nodes[sibling].right, tree.nodes[sibling].left),
+                        };
+
+                        if tree.nodes[sibling].red {
+                            tree.set_black(Some(sibling));
+                            tree.nodes[above].red = true;
+
+                            match is_left {
+                                true => tree.rotate_left(above),
+                                false => tree.rotate_right(above),
+                            }
+
+                            Some((sibling, node, parent))
+                        } else if !tree.is_red(near) && !tree.is_red(far) {
+                            tree.nodes[sibling].red = true;
+                            Some((above, Some(above), tree.nodes[above].parent))
+                        } else if !tree.is_red(far) {
+                            tree.set_black(near);
+                            tree.nodes[sibling].red = true;
+
+                            match is_left {
+                                true => tree.rotate_right(sibling),
+                                false => tree.rotate_left(sibling),
+                            }
+
+                            Some((sibling, node, parent))
+                        } else {
+                            tree.nodes[sibling].red = tree.nodes[above].red;
+                            tree.set_black(Some(above));
+                            tree.set_black(far);
+
+                            match is_left {
+                                true => tree.rotate_left(above),
+                                false => tree.rotate_right(above),
+                            }
+
+                            Some((sibling, tree.root, None))
+                        }
+                    }
+                    _ => None,
+                }
+            };
+
+            let Some((pivot, next, above)) = outcome else {
+                break;
+            };
+
+            self.step(pivot);
+            node = next;
+            parent = above;
+        }
+
+        self.tree.get().set_black(node);
+    }
+}
@@ -0,0 +1,545 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::state::{SharedState, Status};
+
+/// Rabin–Karp hashes characters as digits in this base...
+const BASE: u64 = 256;
+/// ...modulo a prime small enough that spurious hits actually show up.
+const MODULUS: u64 = 101;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Matcher {
+    Naive,
+    Kmp,
+    BoyerMoore,
+    RabinKarp,
+    Z,
+}
+
+impl Matcher {
+    pub const ALL: [Matcher; 5] = [
+        Matcher::Naive,
+        Matcher::Kmp,
+        Matcher::BoyerMoore,
+        Matcher::RabinKarp,
+        Matcher::Z,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Matcher::Naive => "Naive Search",
+            Matcher::Kmp => "Knuth–Morris–Pratt",
+            Matcher::BoyerMoore => "Boyer–Moore",
+            Matcher::RabinKarp => "Rabin–Karp",
+            Matcher::Z => "Z-Algorithm",
+        }
+    }
+
+    pub fn run(&self, state: &SharedState, matching: &SharedMatching, text: &str, pattern: &str) {
+        *matching.get() = Matching::new(text, pattern);
+
+        state.init_algorithm(self.name().to_string());
+
+        let runner = Runner {
+            state,
+            matching,
+            text: text.chars().collect(),
+            pattern: pattern.chars().collect(),
+        };
+
+        if runner.pattern.is_empty() {
+            state.log(format!("{}: no pattern to search for", self.name()));
+            state.set_status(Status::Completed);
+            return;
+        }
+
+        let finished = match self {
+            Matcher::Naive => runner.naive(),
+            Matcher::Kmp => runner.kmp(),
+            Matcher::BoyerMoore => runner.boyer_moore(),
+            Matcher::RabinKarp => runner.rabin_karp(),
+            Matcher::Z => runner.z(),
+        };
+
+        // Interrupted, so the next run starts over anyway.
+        if finished.is_none() {
+            return;
+        }
+
+        let found = {
+            let mut matching = matching.get();
+            matching.compared = None;
+            matching.table_current = None;
+            matching.matches.len()
+        };
+
+        state.log(format!(
+            "{}: {} matches in {} alignments, {} character comparisons",
+            self.name(),
+            found,
+            matching.get().alignments,
+            state.get_comparisons()
+        ));
+        state.set_status(Status::Completed);
+    }
+}
+
+pub struct Matching {
+    pub text: Vec<char>,
+    pub pattern: Vec<char>,
+    /// Where the pattern sits under the text.
+    pub shift: Option<usize>,
+    pub alignments: u32,
+    /// Pattern index being compared at `shift`, and whether it matched.
+    pub compared: Option<(usize, bool)>,
+    pub matches: Vec<usize>,
+    /// The algorithm's own table and the characters it runs over.
+    pub table_name: &'static str,
+    pub table_labels: Vec<char>,
+    pub table: Vec<Option<i64>>,
+    pub table_current: Option<usize>,
+    /// Shift rules or hash values behind the last move.
+    pub note: String,
+}
+
+impl Matching {
+    fn new(text: &str, pattern: &str) -> Self {
+        Self {
+            text: text.chars().collect(),
+            pattern: pattern.chars().collect(),
+            shift: None,
+            alignments: 0,
+            compared: None,
+            matches: vec![],
+            table_name: "",
+            table_labels: vec![],
+            table: vec![],
+            table_current: None,
+            note: String::new(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedMatching(Arc<Mutex<Matching>>);
+
+impl SharedMatching {
+    pub fn new(matching: Matching) -> Self {
+        Self(Arc::new(Mutex::new(matching)))
+    }
+
+    pub fn get(&self) -> MutexGuard<'_, Matching> {
+        self.0.lock().unwrap()
+    }
+}
+
+impl Default for SharedMatching {
+    fn default() -> Self {
+        Self::new(Matching::new("", ""))
+    }
+}
+
+struct Runner<'a> {
+    state: &'a SharedState,
+    matching: &'a SharedMatching,
+    text: Vec<char>,
+    pattern: Vec<char>,
+}
+
+impl Runner<'_> {
+    /// Moves the pattern under the text at `shift`.
+    fn align(&self, shift: usize, note: String) {
+        let mut matching = self.matching.get();
+        matching.shift = Some(shift);
+        matching.alignments += 1;
+        matching.compared = None;
+        matching.note = note;
+    }
+
+    /// Compares `pattern[index]` with the text below it, or returns `None`
+    /// once interrupted.
+    fn compare(&self, index: usize) -> Option<bool> {
+        if self.state.interrupted() {
+            return None;
+        }
+
+        let equal = {
+            let mut matching = self.matching.get();
+            let shift = matching.shift.unwrap_or(0);
+            let equal = self.text[shift + index] == self.pattern[index];
+
+            matching.compared = Some((index, equal));
+            equal
+        };
+
+        self.state.increment_comparisons();
+        self.state.sleep(None);
+        Some(equal)
+    }
+
+    /// Compares two characters while filling a table, with `index` the cell
+    /// being computed.
+    fn compare_within(&self, index: usize, first: char, second: char) -> Option<bool> {
+        if self.state.interrupted() {
+            return None;
+        }
+
+        self.matching.get().table_current = Some(index);
+        self.state.increment_comparisons();
+        self.state.sleep(None);
+        Some(first == second)
+    }
+
+    fn set_table(&self, index: usize, value: i64) {
+        let mut matching = self.matching.get();
+        matching.table[index] = Some(value);
+        matching.table_current = Some(index);
+    }
+
+    fn start_table(&self, name: &'static str, labels: Vec<char>) {
+        let mut matching = self.matching.get();
+        matching.table_name = name;
+        matching.table = vec![None; labels.len()];
+        matching.table_labels = labels;
+    }
+
+    fn found(&self, shift: usize) {
+        self.matching.get().matches.push(shift);
+    }
+
+    /// Last alignment that still fits the pattern inside the text.
+    fn last_shift(&self) -> Option<usize> {
+        self.text.len().checked_sub(self.pattern.len())
+    }
+
+    fn naive(&self) -> Option<()> {
+        let Some(last) = self.last_shift() else {
+            return Some(());
+        };
+
+        for shift in 0..=last {
+            self.align(shift, String::new());
+
+            let mut index = 0;
+            while index < self.pattern.len() && self.compare(index)? {
+                index += 1;
+            }
+
+            if index == self.pattern.len() {
+                self.found(shift);
+            }
+        }
+
+        Some(())
+    }
+
+    /// Builds the failure table, the longest proper prefix of the pattern
+    /// that is also a suffix of each of its prefixes, then slides the pattern
+    /// along without ever stepping back in the text.
+    fn kmp(&self) -> Option<()> {
+        let pattern = &self.pattern;
+        self.start_table("failure", pattern.clone());
+        self.set_table(0, 0);
+
+        let mut failure = vec![0; pattern.len()];
+        let mut length = 0;
+
+        for index in 1..pattern.len() {
+            // Fall back through shorter borders until one extends or none are left.
+            loop {
+                if self.compare_within(index, pattern[index], pattern[length])? {
+                    length += 1;
+                    break;
+                }
+
+                match length {
+                    0 => break,
+                    _ => length = failure[length - 1],
+                }
+            }
+
+            failure[index] = length;
+            self.set_table(index, length as i64);
+        }
+
+        self.matching.get().table_current = None;
+
+        let Some(last) = self.last_shift() else {
+            return Some(());
+        };
+
+        let (mut shift, mut index) = (0, 0);
+        self.align(0, String::new());
+
+        while shift <= last {
+            if self.compare(index)? {
+                index += 1;
+
+                if index < pattern.len() {
+                    continue;
+                }
+
+                self.found(shift);
+            } else if index == 0 {
+                shift += 1;
+                self.align(shift, String::new());
+                continue;
+            }
+
+            // Whatever of the pattern already matched lines up with its own prefix.
+            let keep = failure[index - 1];
+            self.matching.get().table_current = Some(index - 1);
+            let note = format!("failure[{}] = {}, {} stay matched", index - 1, keep, keep);
+
+            shift += index - keep;
+            index = keep;
+            self.align(shift, note);
+        }
+
+        Some(())
+    }
+
+    /// Compares right to left, then jumps by whichever is larger: the bad
+    /// character rule or the good suffix rule.
+    fn boyer_moore(&self) -> Option<()> {
+        let pattern = &self.pattern;
+        let length = pattern.len();
+
+        // How far the last occurrence of each character is from the pattern's end.
+        let bad_character: HashMap<char, usize> = pattern[..length - 1]
+            .iter()
+            .enumerate()
+            .map(|(index, &character)| (character, length - 1 - index))
+            .collect();
+
+        let good_suffix = good_suffix_shifts(pattern);
+        self.start_table("good suffix", pattern.clone());
+        for (index, &shift) in good_suffix.iter().enumerate() {
+            self.set_table(index, shift as i64);
+        }
+        self.matching.get().table_current = None;
+
+        let Some(last) = self.last_shift() else {
+            return Some(());
+        };
+
+        let mut shift = 0;
+        self.align(0, String::new());
+
+        while shift <= last {
+            let mut index = length;
+
+            while index > 0 && self.compare(index - 1)? {
+                index -= 1;
+            }
+
+            let jump = match index {
+                0 => {
+                    self.found(shift);
+
+                    let mut matching = self.matching.get();
+                    matching.table_current = Some(0);
+                    matching.note = format!("match, good suffix → {}", good_suffix[0]);
+                    good_suffix[0]
+                }
+                _ => {
+                    let mismatched = index - 1;
+                    let character = self.text[shift + mismatched];
+                    let bad = bad_character.get(&character).copied().unwrap_or(length);
+                    let bad = bad as isize - (length - 1 - mismatched) as isize;
+                    let good = good_suffix[mismatched];
+
+                    self.matching.get().table_current = Some(mismatched);
+                    self.matching.get().note = format!(
+                        "bad character '{}' → {}, good suffix → {}",
+                        character, bad, good
+                    );
+
+                    good.max(bad.max(1) as usize)
+                }
+            };
+
+            let note = self.matching.get().note.clone();
+            shift += jump;
+            self.align(shift, note);
+        }
+
+        Some(())
+    }
+
+    /// Slides a rolling hash of the window along and only compares characters
+    /// when it equals the pattern's.
+    fn rabin_karp(&self) -> Option<()> {
+        let Some(last) = self.last_shift() else {
+            return Some(());
+        };
+
+        let length = self.pattern.len();
+        let digit = |character: char| u64::from(character) % MODULUS;
+        let hash = |characters: &[char]| {
+            characters.iter().fold(0, |hash, &character| {
+                (hash * BASE + digit(character)) % MODULUS
+            })
+        };
+
+        // Weight of the character leaving the window.
+        let high = (1..length).fold(1, |high, _| high * BASE % MODULUS);
+        let target = hash(&self.pattern);
+        let mut window = hash(&self.text[..length]);
+        let mut spurious = 0;
+
+        for shift in 0..=last {
+            if shift > 0 {
+                let leaving = digit(self.text[shift - 1]) * high % MODULUS;
+                window = ((window + MODULUS - leaving) * BASE
+                    + digit(self.text[shift + length - 1]))
+                    % MODULUS;
+            }
+
+            self.align(
+                shift,
+                format!(
+                    "window hash {}, pattern hash {}, {} spurious hits",
+                    window, target, spurious
+                ),
+            );
+
+            if window != target {
+                if self.state.interrupted() {
+                    return None;
+                }
+
+                self.state.sleep(None);
+                continue;
+            }
+
+            let mut index = 0;
+            while index < length && self.compare(index)? {
+                index += 1;
+            }
+
+            match index == length {
+                true => self.found(shift),
+                false => spurious += 1,
+            }
+        }
+
+        self.matching.get().note = format!("{} spurious hits", spurious);
+        Some(())
+    }
+
+    /// Computes the Z-array of pattern, separator and text, the longest
+    /// common prefix of every suffix with the whole, reusing the rightmost
+    /// box found so far. Wherever it reaches the pattern's length, it matched.
+    fn z(&self) -> Option<()> {
+        let length = self.pattern.len();
+        // `None` separates the two, equal to no character that can be typed.
+        let joined: Vec<Option<char>> = self
+            .pattern
+            .iter()
+            .copied()
+            .map(Some)
+            .chain(std::iter::once(None))
+            .chain(self.text.iter().copied().map(Some))
+            .collect();
+
+        self.start_table(
+            "Z",
+            joined.iter().map(|&label| label.unwrap_or('·')).collect(),
+        );
+        self.set_table(0, joined.len() as i64);
+
+        let mut z = vec![0; joined.len()];
+        let (mut left, mut right) = (0, 0);
+
+        for index in 1..joined.len() {
+            // Text positions line the pattern up under themselves.
+            let shift = index.checked_sub(length + 1);
+
+            if let Some(shift) = shift {
+                self.align(shift, format!("Z box [{}, {})", left, right));
+            }
+
+            if index < right {
+                z[index] = z[index - left].min(right - index);
+            }
+
+            while index + z[index] < joined.len() {
+                // A match never runs past the pattern's length, as the separator
+                // after the pattern ends it.
+                let equal = match (shift, joined[z[index]], joined[index + z[index]]) {
+                    (Some(_), Some(_), _) => self.compare(z[index])?,
+                    (None, Some(first), Some(second)) => {
+                        self.compare_within(index, first, second)?
+                    }
+                    _ => false,
+                };
+
+                if !equal {
+                    break;
+                }
+
+                z[index] += 1;
+            }
+
+            if index + z[index] > right {
+                (left, right) = (index, index + z[index]);
+            }
+
+            self.set_table(index, z[index] as i64);
+
+            if let Some(shift) = shift.filter(|_| z[index] == length) {
+                self.found(shift);
+            }
+        }
+
+        Some(())
+    }
+}
+
+/// How far the pattern may move after a mismatch at each index, so that the
+/// suffix already matched lines up with another occurrence of itself, or the
+/// longest prefix that is also a suffix of it.
+fn good_suffix_shifts(pattern: &[char]) -> Vec<usize> {
+    let length = pattern.len();
+
+    // Length of the longest substring ending at each index that is also a suffix.
+    let mut suffixes = vec![0; length];
+    suffixes[length - 1] = length;
+
+    for index in (0..length - 1).rev() {
+        let mut matched = 0;
+
+        while matched <= index && pattern[index - matched] == pattern[length - 1 - matched] {
+            matched += 1;
+        }
+
+        suffixes[index] = matched;
+    }
+
+    let mut shifts = vec![length; length];
+
+    // Suffixes of the pattern that are also its prefixes.
+    let mut next = 0;
+    for index in (0..length).rev() {
+        if suffixes[index] == index + 1 {
+            while next < length - 1 - index {
+                if shifts[next] == length {
+                    shifts[next] = length - 1 - index;
+                }
+
+                next += 1;
+            }
+        }
+    }
+
+    // Other occurrences of a matched suffix, the rightmost winning.
+    for index in 0..length - 1 {
+        shifts[length - 1 - suffixes[index]] = length - 1 - index;
+    }
+
+    shifts
+}
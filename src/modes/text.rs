@@ -0,0 +1,351 @@
+use std::{
+    fs,
+    thread::{self, JoinHandle},
+};
+
+use crossterm::{event::KeyCode, style::Color};
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+    Frame,
+};
+
+use super::{
+    string_matching::{Matcher, SharedMatching},
+    Mode,
+};
+use crate::state::{SharedState, State};
+
+pub const TEXT_FILE: &str = "text.txt";
+/// Characters kept from an imported file, which is otherwise searched for ages.
+const MAX_TEXT: usize = 4000;
+/// Stands in for line breaks, so a file searches as one line.
+const NEWLINE: char = '↵';
+/// Width of the labels in front of every row.
+const LABEL: usize = 10;
+/// Searched until a file is imported.
+const TEXT: &str = "\
+2024-05-01 12:00:01 INFO server started on port 8080↵\
+2024-05-01 12:00:07 WARN slow request GET /api/users took 1203ms↵\
+2024-05-01 12:00:09 ERROR connection reset by peer↵\
+2024-05-01 12:01:13 INFO GET /api/users 200↵\
+2024-05-01 12:02:44 ERROR timeout while reading from db↵\
+2024-05-01 12:02:45 INFO retrying in 5s";
+const PATTERN: &str = "ERROR";
+
+pub struct TextMode {
+    matching: SharedMatching,
+    matcher_index: usize,
+    text: String,
+    pattern: String,
+    /// Pattern typed so far, replacing it once confirmed.
+    editing: Option<String>,
+    /// Outcome of the last import, if any.
+    file: Option<String>,
+}
+
+impl Default for TextMode {
+    fn default() -> Self {
+        Self {
+            matching: SharedMatching::default(),
+            matcher_index: 0,
+            text: TEXT.to_string(),
+            pattern: PATTERN.to_string(),
+            editing: None,
+            file: None,
+        }
+    }
+}
+
+impl TextMode {
+    fn matcher(&self) -> Matcher {
+        Matcher::ALL[self.matcher_index % Matcher::ALL.len()]
+    }
+
+    fn switch_matcher(&mut self, increment: usize) -> bool {
+        self.matcher_index = (self.matcher_index + increment) % Matcher::ALL.len();
+        true
+    }
+
+    fn import(&mut self) -> bool {
+        match fs::read_to_string(TEXT_FILE) {
+            Ok(text) => {
+                let text: String = text
+                    .trim_end()
+                    .chars()
+                    .filter(|&character| character != '\r')
+                    .map(|character| match character {
+                        '\n' => NEWLINE,
+                        other => other,
+                    })
+                    .collect();
+
+                let length = text.chars().count();
+                self.text = text.chars().take(MAX_TEXT).collect();
+                self.file = Some(match length > MAX_TEXT {
+                    true => format!("first {} characters of {}", MAX_TEXT, TEXT_FILE),
+                    false => format!("imported from {}", TEXT_FILE),
+                });
+                true
+            }
+            Err(error) => {
+                self.file = Some(format!("import failed: {}", error));
+                false
+            }
+        }
+    }
+
+    fn handle_editing(&mut self, key: KeyCode, mut pattern: String) -> bool {
+        match key {
+            KeyCode::Enter => {
+                self.pattern = pattern;
+                return true;
+            }
+            KeyCode::Esc => return false,
+            KeyCode::Backspace => {
+                pattern.pop();
+            }
+            KeyCode::Char(character) => pattern.push(character),
+            _ => {}
+        }
+
+        self.editing = Some(pattern);
+        false
+    }
+}
+
+/// First of `visible` positions out of `length`, keeping `focus` a third of
+/// the way in where possible.
+fn window(focus: usize, visible: usize, length: usize) -> usize {
+    focus
+        .saturating_sub(visible / 3)
+        .min(length.saturating_sub(visible))
+}
+
+fn label(name: &str) -> Span<'static> {
+    Span::styled(format!("{:<LABEL$}", name), Style::new().dark_gray())
+}
+
+impl Mode for TextMode {
+    fn name(&self) -> &'static str {
+        "String Matching"
+    }
+
+    fn speed(&self) -> u32 {
+        90
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        let (matcher, matching) = (self.matcher(), self.matching.clone());
+        let (text, pattern) = (self.text.clone(), self.pattern.clone());
+
+        thread::spawn(move || matcher.run(&state, &matching, &text, &pattern))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        if let Some(pattern) = self.editing.take() {
+            return self.handle_editing(key, pattern);
+        }
+
+        match key {
+            KeyCode::Char('l') => self.switch_matcher(1),
+            KeyCode::Char('h') => self.switch_matcher(Matcher::ALL.len() - 1),
+            KeyCode::Char('e') => {
+                self.editing = Some(self.pattern.clone());
+                false
+            }
+            KeyCode::Char('i') => self.import(),
+            KeyCode::Char('d') => {
+                (self.text, self.pattern, self.file) =
+                    (TEXT.to_string(), PATTERN.to_string(), None);
+                true
+            }
+            KeyCode::Char('r') => true,
+            _ => false,
+        }
+    }
+
+    fn editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    fn overview(&self, state: &State) -> Vec<Line<'static>> {
+        let matching = self.matching.get();
+
+        let pattern: Line = match &self.editing {
+            Some(pattern) => format!("Pattern: {}_", pattern).fg(Color::Yellow).into(),
+            None => format!("Pattern: {}", self.pattern).into(),
+        };
+
+        let mut overview = vec![
+            pattern,
+            format!("Text: {} characters", self.text.chars().count()).into(),
+            format!("Character Comparisons: {}", state.comparisons).into(),
+            format!("Alignments: {}", matching.alignments).into(),
+            format!("Matches: {}", matching.matches.len()).into(),
+        ];
+
+        if let Some(file) = &self.file {
+            overview.push(format!("File: {}", file).into());
+        }
+
+        overview
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        match self.editing {
+            Some(_) => vec!["Confirm Pattern: <Enter>".into(), "Cancel: <Esc>".into()],
+            None => vec![
+                "Edit Pattern: <E>".into(),
+                format!("Import Text: <I> ({})", TEXT_FILE).into(),
+                "Default Text: <D>".into(),
+                "Replay: <R>".into(),
+                "Next Algorithm: <L>".into(),
+                "Previous Algorithm: <H>".into(),
+            ],
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, _state: &State) {
+        let matching = self.matching.get();
+        let block = Block::new()
+            .title(Line::raw(format!(" {} ", self.matcher().name())).centered())
+            .title_bottom(
+                Line::from(vec![
+                    " equal ".fg(Color::Yellow),
+                    " different ".fg(Color::Red),
+                    " match ".fg(Color::Green),
+                ])
+                .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick)
+            .padding(Padding::horizontal(1));
+
+        let visible = usize::from(block.inner(area).width).saturating_sub(LABEL);
+        let shift = matching.shift.unwrap_or(0);
+        let start = window(shift, visible, matching.text.len());
+        let length = matching.pattern.len();
+
+        let compared = matching
+            .compared
+            .map(|(index, equal)| (shift + index, index, equal));
+        let highlight = |equal: bool| match equal {
+            true => Style::new().black().on_yellow(),
+            false => Style::new().black().on_red(),
+        };
+
+        let text = std::iter::once(label("Text"))
+            .chain(
+                matching
+                    .text
+                    .iter()
+                    .enumerate()
+                    .skip(start)
+                    .take(visible)
+                    .map(|(position, &character)| {
+                        let style = match compared {
+                            Some((at, _, equal)) if at == position => highlight(equal),
+                            _ if matching
+                                .matches
+                                .iter()
+                                .any(|&found| (found..found + length).contains(&position)) =>
+                            {
+                                Style::new().green().bold()
+                            }
+                            _ if matching.shift.is_some()
+                                && (shift..shift + length).contains(&position) =>
+                            {
+                                Style::new().white()
+                            }
+                            _ => Style::new().dark_gray(),
+                        };
+
+                        Span::styled(character.to_string(), style)
+                    }),
+            )
+            .collect::<Vec<_>>();
+
+        let mut pattern = vec![label("Pattern")];
+        if let Some(shift) = matching.shift.filter(|&shift| shift >= start) {
+            pattern.push(Span::raw(" ".repeat(shift - start)));
+            pattern.extend(
+                matching
+                    .pattern
+                    .iter()
+                    .enumerate()
+                    .map(|(index, &character)| {
+                        let style = match compared {
+                            Some((_, at, equal)) if at == index => highlight(equal),
+                            _ => Style::new().cyan().bold(),
+                        };
+
+                        Span::styled(character.to_string(), style)
+                    }),
+            );
+        }
+
+        let mut lines = vec![Line::raw(""), Line::from(text), Line::from(pattern)];
+
+        if !matching.table.is_empty() {
+            let width = matching
+                .table
+                .iter()
+                .flatten()
+                .map(|value| value.to_string().len())
+                .max()
+                .unwrap_or(1)
+                + 1;
+            let cells = visible / width;
+            let first = window(
+                matching.table_current.unwrap_or(0),
+                cells,
+                matching.table.len(),
+            );
+
+            let style = |index: usize| match Some(index) == matching.table_current {
+                true => Style::new().black().on_yellow(),
+                false => Style::new().white(),
+            };
+
+            let labels = matching
+                .table_labels
+                .iter()
+                .enumerate()
+                .skip(first)
+                .take(cells)
+                .map(|(index, character)| {
+                    Span::styled(format!("{:>width$}", character), style(index))
+                });
+            let values = matching
+                .table
+                .iter()
+                .enumerate()
+                .skip(first)
+                .take(cells)
+                .map(|(index, value)| {
+                    let value = value.map_or("·".to_string(), |value| value.to_string());
+                    Span::styled(format!("{:>width$}", value), style(index))
+                });
+
+            lines.extend([
+                Line::raw(""),
+                Line::from(std::iter::once(label("")).chain(labels).collect::<Vec<_>>()),
+                Line::from(
+                    std::iter::once(label(matching.table_name))
+                        .chain(values)
+                        .collect::<Vec<_>>(),
+                ),
+            ]);
+        }
+
+        lines.extend([
+            Line::raw(""),
+            Line::from(vec![label(""), Span::raw(matching.note.clone())]),
+        ]);
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
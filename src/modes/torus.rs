@@ -0,0 +1,309 @@
+use std::{
+    fs,
+    thread::{self, JoinHandle},
+};
+
+use crossterm::{event::KeyCode, style::Color};
+use rand::Rng;
+use ratatui::{
+    layout::Rect,
+    style::Stylize,
+    symbols,
+    text::Line,
+    widgets::{
+        canvas::{Canvas, Points},
+        Block, BorderType, Borders,
+    },
+    Frame,
+};
+
+use super::{
+    cellular_automata::{self, Automaton, SharedWorld, World},
+    Mode,
+};
+use crate::state::{SharedState, State};
+
+/// Life pattern read by the import key, relative to the working directory.
+pub const PATTERN_FILE: &str = "pattern.rle";
+/// Life's starting pattern until another one is imported.
+const GLIDER_GUN: &str = "
+    #N Gosper glider gun
+    x = 36, y = 9, rule = B3/S23
+    24bo$22bobo$12b2o6b2o12b2o$11bo3bo4b2o12b2o$2o8bo5bo3b2o$2o8bo3bob2o4bobo$
+    10bo5bo7bo$11bo3bo$12b2o!
+";
+const RULE: u8 = 30;
+
+pub struct TorusMode {
+    world: SharedWorld,
+    automaton_index: usize,
+    /// Live cells Life starts from, and where they came from.
+    pattern: (Vec<(usize, usize)>, String),
+    /// Seed of a random soup or first row, or `None` for the pattern or a
+    /// single cell.
+    seed: Option<u64>,
+    rule: u8,
+    /// Rule number typed so far, replacing it once confirmed.
+    editing: Option<String>,
+    /// Outcome of the last import, if any.
+    file: Option<String>,
+}
+
+impl Default for TorusMode {
+    fn default() -> Self {
+        let pattern = cellular_automata::parse_rle(GLIDER_GUN).unwrap();
+
+        Self {
+            world: SharedWorld::new(World::life(&pattern)),
+            automaton_index: 0,
+            pattern: (pattern, "Gosper glider gun".to_string()),
+            seed: None,
+            rule: RULE,
+            editing: None,
+            file: None,
+        }
+    }
+}
+
+impl TorusMode {
+    fn automaton(&self) -> Automaton {
+        Automaton::ALL[self.automaton_index % Automaton::ALL.len()]
+    }
+
+    fn switch_automaton(&mut self, increment: usize) -> bool {
+        self.automaton_index = (self.automaton_index + increment) % Automaton::ALL.len();
+        true
+    }
+
+    fn import(&mut self) -> bool {
+        let pattern = fs::read_to_string(PATTERN_FILE)
+            .map_err(|error| error.to_string())
+            .and_then(|text| cellular_automata::parse_rle(&text));
+
+        match pattern {
+            Ok(pattern) => {
+                self.pattern = (pattern, PATTERN_FILE.to_string());
+                self.seed = None;
+                self.automaton_index = Automaton::ALL
+                    .iter()
+                    .position(|&automaton| automaton == Automaton::Life)
+                    .unwrap_or(0);
+                self.file = Some(format!("imported from {}", PATTERN_FILE));
+                true
+            }
+            Err(error) => {
+                self.file = Some(format!("import failed: {}", error));
+                false
+            }
+        }
+    }
+
+    fn handle_editing(&mut self, key: KeyCode, mut rule: String) -> bool {
+        match key {
+            KeyCode::Enter => match rule.parse() {
+                Ok(rule) => {
+                    self.rule = rule;
+                    return true;
+                }
+                Err(_) => return false,
+            },
+            KeyCode::Esc => return false,
+            KeyCode::Backspace => {
+                rule.pop();
+            }
+            // Only digits that keep it a rule from 0 to 255.
+            KeyCode::Char(digit @ '0'..='9')
+                if format!("{}{}", rule, digit).parse::<u8>().is_ok() =>
+            {
+                rule.push(digit);
+            }
+            _ => {}
+        }
+
+        self.editing = Some(rule);
+        false
+    }
+
+    fn initial_world(&self) -> World {
+        match self.automaton() {
+            Automaton::Life => match self.seed {
+                Some(seed) => World::soup(seed),
+                None => World::life(&self.pattern.0),
+            },
+            Automaton::Elementary => World::elementary(self.rule, self.seed),
+            Automaton::LangtonsAnt => World::langtons_ant(),
+        }
+    }
+
+    /// What the selected automaton starts from.
+    fn start_description(&self) -> String {
+        match (self.automaton(), self.seed) {
+            (Automaton::Life, Some(seed)) => format!("random soup (seed {})", seed),
+            (Automaton::Life, None) => self.pattern.1.clone(),
+            (Automaton::Elementary, Some(seed)) => format!("random row (seed {})", seed),
+            (Automaton::Elementary, None) => "single cell".to_string(),
+            (Automaton::LangtonsAnt, _) => "empty grid".to_string(),
+        }
+    }
+}
+
+impl Mode for TorusMode {
+    fn name(&self) -> &'static str {
+        "Cellular Automata"
+    }
+
+    fn speed(&self) -> u32 {
+        self.automaton().speed()
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        let (automaton, world, initial) =
+            (self.automaton(), self.world.clone(), self.initial_world());
+
+        thread::spawn(move || automaton.run(&state, &world, initial))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        if let Some(rule) = self.editing.take() {
+            return self.handle_editing(key, rule);
+        }
+
+        match key {
+            KeyCode::Char('l') => self.switch_automaton(1),
+            KeyCode::Char('h') => self.switch_automaton(Automaton::ALL.len() - 1),
+            KeyCode::Char('e') if self.automaton() == Automaton::Elementary => {
+                self.editing = Some(String::new());
+                false
+            }
+            KeyCode::Char('g') if self.automaton() != Automaton::LangtonsAnt => {
+                self.seed = Some(u64::from(rand::rng().random::<u32>()));
+                true
+            }
+            KeyCode::Char('d') => {
+                self.seed = None;
+                true
+            }
+            KeyCode::Char('i') => self.import(),
+            KeyCode::Char('r') => true,
+            _ => false,
+        }
+    }
+
+    fn editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    fn overview(&self, _state: &State) -> Vec<Line<'static>> {
+        let world = self.world.get();
+
+        let mut overview: Vec<Line> = vec![];
+
+        if self.automaton() == Automaton::Elementary {
+            overview.push(match &self.editing {
+                Some(rule) => format!("Rule: {}_", rule).fg(Color::Yellow).into(),
+                None => format!("Rule: {}", self.rule).into(),
+            });
+        }
+
+        overview.extend([
+            format!("Start: {}", self.start_description()).into(),
+            format!("Grid: {}x{} (wrapping)", world.width, world.height).into(),
+            format!("Generation: {}", world.generation).into(),
+            format!("Population: {}", world.population).into(),
+        ]);
+
+        if let Some(file) = &self.file {
+            overview.push(format!("File: {}", file).into());
+        }
+
+        overview
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        if self.editing.is_some() {
+            return vec!["Confirm Rule: <Enter>".into(), "Cancel: <Esc>".into()];
+        }
+
+        let mut help: Vec<Line> = vec![
+            "Restart: <R>".into(),
+            "Next Automaton: <L>".into(),
+            "Previous Automaton: <H>".into(),
+        ];
+
+        match self.automaton() {
+            Automaton::Life => help.extend(["Random Soup: <G>".into(), "Pattern: <D>".into()]),
+            Automaton::Elementary => help.extend([
+                "Edit Rule: <E>".into(),
+                "Random Row: <G>".into(),
+                "Single Cell: <D>".into(),
+            ]),
+            Automaton::LangtonsAnt => {}
+        }
+
+        help.push(format!("Import: <I> ({})", PATTERN_FILE).into());
+        help
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, _state: &State) {
+        let world = self.world.get();
+
+        let (alive, legend) = match world.automaton {
+            Automaton::Life => (Color::Green, vec![" alive ".fg(Color::Green)]),
+            Automaton::Elementary => (Color::Cyan, vec![" on ".fg(Color::Cyan)]),
+            Automaton::LangtonsAnt => (
+                Color::White,
+                vec![" black ".fg(Color::White), " ant ".fg(Color::Red)],
+            ),
+        };
+
+        let title = match world.automaton {
+            Automaton::Elementary => format!(" {} (Rule {}) ", world.automaton.name(), world.rule),
+            _ => format!(" {} ", world.automaton.name()),
+        };
+
+        let block = Block::new()
+            .title(Line::raw(title).centered())
+            .title_bottom(Line::from(legend).centered())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        // Half blocks make two roughly square pixels out of every terminal cell.
+        let inner = block.inner(area);
+        let (columns, rows) = (usize::from(inner.width), usize::from(inner.height) * 2);
+
+        let (mut cells, mut ant) = (vec![], vec![]);
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let x = column * world.width / columns;
+                let y = row * world.height / rows;
+                let index = y * world.width + x;
+                let point = (column as f64, (rows - 1 - row) as f64);
+
+                if world.automaton == Automaton::LangtonsAnt && index == world.ant.0 {
+                    ant.push(point);
+                } else if world.cells[index] {
+                    cells.push(point);
+                }
+            }
+        }
+
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(symbols::Marker::HalfBlock)
+            .x_bounds([0.0, columns.saturating_sub(1) as f64])
+            .y_bounds([0.0, rows.saturating_sub(1) as f64])
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &cells,
+                    color: alive.into(),
+                });
+                ctx.draw(&Points {
+                    coords: &ant,
+                    color: Color::Red.into(),
+                });
+            });
+
+        frame.render_widget(canvas, area);
+    }
+}
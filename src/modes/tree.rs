@@ -0,0 +1,215 @@
+use std::thread::{self, JoinHandle};
+
+use crossterm::{event::KeyCode, style::Color};
+use ratatui::{
+    layout::Rect,
+    style::Stylize,
+    symbols,
+    text::Line,
+    widgets::{
+        canvas::{self, Canvas, Points},
+        Block, BorderType, Borders,
+    },
+    Frame,
+};
+
+use super::{
+    key_input::KeyInput,
+    search_tree::{self, Operation, SharedTree, Tree, TreeKind},
+    Mode,
+};
+use crate::{
+    app::App,
+    state::{SharedState, State},
+};
+
+pub struct TreeMode {
+    tree: SharedTree,
+    kind_index: usize,
+    keys: KeyInput<Operation>,
+}
+
+impl Default for TreeMode {
+    fn default() -> Self {
+        Self {
+            tree: SharedTree::new(Tree::new(TreeKind::ALL[0])),
+            kind_index: 0,
+            keys: KeyInput::default(),
+        }
+    }
+}
+
+impl TreeMode {
+    fn kind(&self) -> TreeKind {
+        TreeKind::ALL[self.kind_index % TreeKind::ALL.len()]
+    }
+
+    fn switch_kind(&mut self, increment: usize) -> bool {
+        self.kind_index = (self.kind_index + increment) % TreeKind::ALL.len();
+        self.keys.pending = vec![Operation::Rebuild(self.kind())];
+        true
+    }
+}
+
+impl Mode for TreeMode {
+    fn name(&self) -> &'static str {
+        "Search Trees"
+    }
+
+    fn speed(&self) -> u32 {
+        95
+    }
+
+    fn start(&mut self, state: SharedState) -> JoinHandle<()> {
+        let (operations, tree) = (std::mem::take(&mut self.keys.pending), self.tree.clone());
+
+        thread::spawn(move || search_tree::run(&state, &tree, operations))
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        let tree = self.tree.clone();
+
+        if let Some(restart) = self.keys.handle_key(key, || tree.get().keys.clone()) {
+            return restart;
+        }
+
+        match key {
+            KeyCode::Char('x') => self.keys.with_input(Operation::Delete),
+            KeyCode::Char('s') => self.keys.with_input(Operation::Search),
+            KeyCode::Char('a') | KeyCode::Char('o') => {
+                let mut keys = App::generate_array();
+
+                if key == KeyCode::Char('o') {
+                    keys.sort();
+                }
+
+                self.keys.insert_all(keys)
+            }
+            KeyCode::Char('l') => self.switch_kind(1),
+            KeyCode::Char('h') => self.switch_kind(TreeKind::ALL.len() - 1),
+            _ => false,
+        }
+    }
+
+    fn overview(&self, state: &State) -> Vec<Line<'static>> {
+        let tree = self.tree.get();
+        let height = tree.layout().iter().map(|&(_, _, depth)| depth + 1).max();
+
+        vec![
+            format!("Nodes: {}", tree.len).into(),
+            format!("Height: {}", height.unwrap_or(0)).into(),
+            format!("Rotations: {}", tree.rotations).into(),
+            format!("Comparisons: {}", state.comparisons).into(),
+            self.keys.overview(),
+        ]
+    }
+
+    fn help(&self) -> Vec<Line<'static>> {
+        vec![
+            "Type Key: <0-9>".into(),
+            "Insert: <I>".into(),
+            "Delete: <X>".into(),
+            "Search: <S>".into(),
+            "Insert Random Input: <A>".into(),
+            "Insert Sorted Input: <O>".into(),
+            "Replay: <R>".into(),
+            "Clear: <C>".into(),
+            "Next Tree: <L>".into(),
+            "Previous Tree: <H>".into(),
+        ]
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, _state: &State) {
+        let tree = self.tree.get();
+        let layout = tree.layout();
+        let kind = tree.kind;
+
+        let mut position = vec![(0.0, 0.0); tree.nodes.len()];
+        for &(node, x, depth) in &layout {
+            position[node] = (x as f64, -(depth as f64));
+        }
+
+        let depth = layout.iter().map(|&(_, _, depth)| depth).max().unwrap_or(0);
+
+        let color = |node: usize| {
+            if Some(node) == tree.found {
+                Color::Green
+            } else if Some(node) == tree.current {
+                Color::Yellow
+            } else if tree.path.contains(&node) {
+                Color::Cyan
+            } else if kind == TreeKind::RedBlack && tree.nodes[node].red {
+                Color::Red
+            } else {
+                Color::White
+            }
+        };
+
+        let label = |node: usize| match kind {
+            TreeKind::Avl => format!("{}{:+}", tree.nodes[node].key, tree.balance(node)),
+            _ => tree.nodes[node].key.to_string(),
+        };
+
+        let mut legend = vec![
+            " current ".fg(Color::Yellow),
+            " compared ".fg(Color::Cyan),
+            " found ".fg(Color::Green),
+        ];
+        legend.push(match kind {
+            TreeKind::Avl => " key+balance ".white(),
+            TreeKind::RedBlack => " red ".fg(Color::Red),
+            TreeKind::Plain => "".into(),
+        });
+
+        let block = Block::new()
+            .title(Line::raw(format!(" {} ", kind.name())).centered())
+            .title_bottom(Line::from(legend).centered())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick);
+
+        // Keys only fit while every node gets a few columns to itself.
+        let labelled = layout.len() * 5 <= usize::from(block.inner(area).width);
+
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(symbols::Marker::Braille)
+            .x_bounds([-1.0, layout.len() as f64])
+            .y_bounds([-(depth as f64) - 0.5, 0.5])
+            .paint(|ctx| {
+                for &(node, _, _) in &layout {
+                    let (x, y) = position[node];
+
+                    for child in [tree.nodes[node].left, tree.nodes[node].right]
+                        .into_iter()
+                        .flatten()
+                    {
+                        let (child_x, child_y) = position[child];
+                        ctx.draw(&canvas::Line::new(
+                            x,
+                            y,
+                            child_x,
+                            child_y,
+                            Color::DarkGrey.into(),
+                        ));
+                    }
+                }
+
+                ctx.layer();
+
+                for &(node, _, _) in &layout {
+                    let (x, y) = position[node];
+
+                    if labelled {
+                        ctx.print(x, y, label(node).fg(color(node)).bold());
+                    } else {
+                        ctx.draw(&Points {
+                            coords: &[(x, y)],
+                            color: color(node).into(),
+                        });
+                    }
+                }
+            });
+
+        frame.render_widget(canvas, area);
+    }
+}
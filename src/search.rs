@@ -0,0 +1,316 @@
+use std::cmp::Ordering;
+
+use crate::{
+    array::Array,
+    state::{Band, SharedState, Status},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Search {
+    Linear,
+    Binary,
+    Ternary,
+    Jump,
+    Exponential,
+    Interpolation,
+    Fibonacci,
+}
+
+impl Search {
+    pub const ALL: [Search; 7] = [
+        Search::Linear,
+        Search::Binary,
+        Search::Ternary,
+        Search::Jump,
+        Search::Exponential,
+        Search::Interpolation,
+        Search::Fibonacci,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Search::Linear => "Linear",
+            Search::Binary => "Binary",
+            Search::Ternary => "Ternary",
+            Search::Jump => "Jump",
+            Search::Exponential => "Exponential",
+            Search::Interpolation => "Interpolation",
+            Search::Fibonacci => "Fibonacci",
+        }
+    }
+
+    /// Searches the sorted `array` for `target`, then reports where it was
+    /// found and how many probes that took.
+    pub fn run(&self, state: SharedState, array: Array, target: u32) {
+        state.reset_counters();
+        state.set_status(Status::Running);
+        state.log(format!("{} Search for {}", self.name(), target));
+
+        let prober = Prober {
+            state: &state,
+            array: &array,
+            target,
+        };
+        let len = array.len();
+
+        let found = match self {
+            Search::Linear => prober.linear(),
+            Search::Binary => prober.binary(0, len),
+            Search::Ternary => prober.ternary(),
+            Search::Jump => prober.jump(),
+            Search::Exponential => prober.exponential(),
+            Search::Interpolation => prober.interpolation(),
+            Search::Fibonacci => prober.fibonacci(),
+        };
+
+        if state.interrupted() {
+            return;
+        }
+
+        state.log(match found {
+            Some(index) => format!(
+                "{} Search found {} at index {} after {} probes",
+                self.name(),
+                target,
+                index,
+                state.get_probes()
+            ),
+            None => format!(
+                "{} Search did not find {} after {} probes",
+                self.name(),
+                target,
+                state.get_probes()
+            ),
+        });
+        state.set_bands(vec![]);
+        state.set_status(Status::Completed);
+    }
+}
+
+struct Prober<'a> {
+    state: &'a SharedState,
+    array: &'a Array,
+    target: u32,
+}
+
+impl Prober<'_> {
+    /// Reads the value at `index` within the window `low..=high`, counting one probe.
+    fn probe(&self, index: usize, low: usize, high: usize) -> u32 {
+        self.state.set_bands(vec![Band::new(low, high, "window")]);
+        self.state.set_comparison([index as u32; 2]);
+        self.state.increment_probes();
+
+        let value = self.array.get(index);
+
+        self.state.sleep(None);
+
+        value
+    }
+
+    fn linear(&self) -> Option<usize> {
+        let len = self.array.len();
+
+        for i in 0..len {
+            if self.state.interrupted() {
+                return None;
+            }
+
+            match self.probe(i, i, len - 1).cmp(&self.target) {
+                Ordering::Equal => return Some(i),
+                // Everything after a larger value is larger too.
+                Ordering::Greater => return None,
+                Ordering::Less => {}
+            }
+        }
+
+        None
+    }
+
+    /// Binary search of `start..end`.
+    fn binary(&self, mut start: usize, mut end: usize) -> Option<usize> {
+        while start < end {
+            if self.state.interrupted() {
+                return None;
+            }
+
+            let mid = start + (end - start) / 2;
+
+            match self.probe(mid, start, end - 1).cmp(&self.target) {
+                Ordering::Equal => return Some(mid),
+                Ordering::Greater => end = mid,
+                Ordering::Less => start = mid + 1,
+            }
+        }
+
+        None
+    }
+
+    /// Splits the window in three with two probes per step.
+    fn ternary(&self) -> Option<usize> {
+        let (mut start, mut end) = (0, self.array.len());
+
+        while start < end {
+            if self.state.interrupted() {
+                return None;
+            }
+
+            let third = (end - start - 1) / 3;
+            let (mid1, mid2) = (start + third, end - 1 - third);
+
+            let value1 = self.probe(mid1, start, end - 1);
+
+            if value1 == self.target {
+                return Some(mid1);
+            }
+
+            if self.target < value1 {
+                end = mid1;
+                continue;
+            }
+
+            let value2 = self.probe(mid2, start, end - 1);
+
+            if value2 == self.target {
+                return Some(mid2);
+            }
+
+            if self.target > value2 {
+                start = mid2 + 1;
+            } else {
+                (start, end) = (mid1 + 1, mid2);
+            }
+        }
+
+        None
+    }
+
+    /// Jumps ahead by √n until passing the target, then scans the last block.
+    fn jump(&self) -> Option<usize> {
+        let len = self.array.len();
+        let step = len.isqrt().max(1);
+        let mut start = 0;
+
+        loop {
+            if self.state.interrupted() {
+                return None;
+            }
+
+            let end = (start + step).min(len);
+
+            if self.probe(end - 1, start, len - 1) >= self.target {
+                self.state.log(format!(
+                    "Jump Search: scanning block [{}, {}]",
+                    start,
+                    end - 1
+                ));
+
+                return (start..end).find(|&i| {
+                    !self.state.interrupted() && self.probe(i, i, end - 1) == self.target
+                });
+            }
+
+            if end == len {
+                return None;
+            }
+
+            start = end;
+        }
+    }
+
+    /// Doubles the bound until passing the target, then binary searches the last doubling.
+    fn exponential(&self) -> Option<usize> {
+        let len = self.array.len();
+        let mut bound = 1;
+
+        while bound < len && self.probe(bound - 1, bound - 1, len - 1) < self.target {
+            if self.state.interrupted() {
+                return None;
+            }
+
+            bound *= 2;
+        }
+
+        self.state.log(format!(
+            "Exponential Search: binary search of [{}, {}]",
+            bound / 2,
+            bound.min(len) - 1
+        ));
+
+        self.binary(bound / 2, bound.min(len))
+    }
+
+    /// Probes where the target would be if the values were evenly spread.
+    fn interpolation(&self) -> Option<usize> {
+        let (mut low, mut high) = (0, self.array.len() - 1);
+
+        while low <= high {
+            if self.state.interrupted() {
+                return None;
+            }
+
+            let (low_value, high_value) = (self.probe(low, low, high), self.probe(high, low, high));
+
+            if self.target < low_value || self.target > high_value {
+                return None;
+            }
+
+            if low_value == high_value {
+                return Some(low);
+            }
+
+            let offset = u64::from(self.target - low_value) * (high - low) as u64
+                / u64::from(high_value - low_value);
+            let position = low + offset as usize;
+
+            match self.probe(position, low, high).cmp(&self.target) {
+                Ordering::Equal => return Some(position),
+                Ordering::Greater => high = position.checked_sub(1)?,
+                Ordering::Less => low = position + 1,
+            }
+        }
+
+        None
+    }
+
+    /// Narrows the window by consecutive Fibonacci numbers instead of halves.
+    fn fibonacci(&self) -> Option<usize> {
+        let len = self.array.len();
+        let (mut fib2, mut fib1) = (0, 1);
+        let mut fib = fib2 + fib1;
+
+        while fib < len {
+            (fib2, fib1) = (fib1, fib);
+            fib = fib2 + fib1;
+        }
+
+        // Everything before `offset + 1` is known to be smaller than the target.
+        let mut offset: Option<usize> = None;
+
+        while fib > 1 {
+            if self.state.interrupted() {
+                return None;
+            }
+
+            let start = offset.map_or(0, |offset| offset + 1);
+            let i = (start + fib2).min(len) - 1;
+
+            match self.probe(i, start.min(i), len - 1).cmp(&self.target) {
+                Ordering::Equal => return Some(i),
+                Ordering::Less => {
+                    (fib, fib1) = (fib1, fib2);
+                    fib2 = fib - fib1;
+                    offset = Some(i);
+                }
+                Ordering::Greater => {
+                    fib = fib2;
+                    fib1 -= fib2;
+                    fib2 = fib - fib1;
+                }
+            }
+        }
+
+        let last = offset.map_or(0, |offset| offset + 1);
+
+        (fib1 == 1 && last < len && self.probe(last, last, last) == self.target).then_some(last)
+    }
+}
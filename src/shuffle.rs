@@ -0,0 +1,188 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use rand::Rng;
+
+use crate::{
+    array::Array,
+    state::{Band, SharedState, State, Status},
+};
+
+/// Length of the arrays shuffled by the bias analysis.
+pub const BIAS_LEN: u32 = 8;
+/// Shuffles run by the bias analysis.
+pub const BIAS_TRIALS: u32 = 100_000;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Shuffle {
+    FisherYates,
+    Sattolo,
+    Naive,
+    RandomKey,
+}
+
+impl Shuffle {
+    pub const ALL: [Shuffle; 4] = [
+        Shuffle::FisherYates,
+        Shuffle::Sattolo,
+        Shuffle::Naive,
+        Shuffle::RandomKey,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Shuffle::FisherYates => "Fisher-Yates",
+            Shuffle::Sattolo => "Sattolo",
+            Shuffle::Naive => "Naive",
+            Shuffle::RandomKey => "Sort by Random Key",
+        }
+    }
+
+    /// Shuffles `array` in place, one swap per step.
+    pub fn run(&self, state: &SharedState, array: &Array, rng: &mut impl Rng) {
+        let len = array.len();
+
+        state.set_status(Status::Running);
+
+        match self {
+            // Each position draws from the values not placed yet, which makes
+            // every permutation equally likely.
+            Shuffle::FisherYates => {
+                for i in (1..len).rev() {
+                    let band = Band::new(0, i, "candidates");
+                    swap(state, array, i, rng.random_range(0..=i), band);
+                }
+            }
+            // Never letting a value stay put yields only single-cycle permutations.
+            Shuffle::Sattolo => {
+                for i in (1..len).rev() {
+                    let band = Band::new(0, i - 1, "candidates");
+                    swap(state, array, i, rng.random_range(0..i), band);
+                }
+            }
+            // Drawing from the whole array gives n^n equally likely swap
+            // sequences, which cannot spread evenly over the n! permutations.
+            Shuffle::Naive => {
+                for i in 0..len {
+                    let band = Band::new(0, len - 1, "candidates");
+                    swap(state, array, i, rng.random_range(0..len), band);
+                }
+            }
+            Shuffle::RandomKey => {
+                let mut keys: Vec<u32> = (0..len).map(|_| rng.random()).collect();
+
+                state.log(format!("{}: sorting by {} random keys", self.name(), len));
+
+                for i in 1..len {
+                    let mut left = i;
+
+                    while left > 0 && keys[left - 1] > keys[left] {
+                        if state.interrupted() {
+                            return;
+                        }
+
+                        keys.swap(left - 1, left);
+                        state.set_bands(vec![Band::new(0, i, "sorted by key")]);
+                        state.set_last(u32::try_from(left - 1).unwrap());
+                        array.swap(left - 1, left);
+                        left -= 1;
+
+                        state.sleep(None);
+                    }
+                }
+            }
+        }
+
+        state.set_bands(vec![]);
+    }
+}
+
+/// Swaps `i` with `j`, highlighting the `candidates` `j` was drawn from.
+fn swap(state: &SharedState, array: &Array, i: usize, j: usize, candidates: Band) {
+    if state.interrupted() {
+        return;
+    }
+
+    state.set_bands(vec![candidates]);
+    state.set_comparison([i as u32, j as u32]);
+    state.set_last(u32::try_from(j).unwrap());
+    array.swap(i, j);
+
+    state.sleep(None);
+}
+
+#[derive(Default)]
+pub struct Bias {
+    pub shuffle: Option<Shuffle>,
+    /// How often each value (column) ended up at each position (row).
+    pub counts: Vec<Vec<u32>>,
+    pub trials: u32,
+    pub done: bool,
+    pub cancelled: bool,
+}
+
+#[derive(Clone, Default)]
+pub struct SharedBias(Arc<Mutex<Bias>>);
+
+impl SharedBias {
+    pub fn get(&self) -> MutexGuard<'_, Bias> {
+        self.0.lock().unwrap()
+    }
+
+    pub fn cancel(&self) {
+        self.get().cancelled = true;
+    }
+
+    /// Shuffles `1..=BIAS_LEN` headlessly [`BIAS_TRIALS`] times, publishing
+    /// the counts of where every value ended up as they accumulate.
+    pub fn run(&self, shuffle: Shuffle) {
+        let mut rng = rand::rng();
+        let len = BIAS_LEN as usize;
+
+        {
+            let mut bias = self.get();
+            bias.shuffle = Some(shuffle);
+            bias.counts = vec![vec![0; len]; len];
+        }
+
+        let mut state = State::new(vec![0; len]);
+        state.headless = true;
+
+        let state = SharedState::new(state);
+        let array = Array::new(state.clone());
+
+        while self.get().trials < BIAS_TRIALS {
+            let mut counts = vec![vec![0; len]; len];
+
+            for _ in 0..1000 {
+                for i in 0..len {
+                    state.store(i, i as u32);
+                }
+
+                shuffle.run(&state, &array, &mut rng);
+
+                for (position, value) in state.get_all().into_iter().enumerate() {
+                    counts[position][value as usize] += 1;
+                }
+            }
+
+            let mut bias = self.get();
+
+            if bias.cancelled {
+                return;
+            }
+
+            for (total, count) in bias
+                .counts
+                .iter_mut()
+                .flatten()
+                .zip(counts.iter().flatten())
+            {
+                *total += count;
+            }
+
+            bias.trials += 1000;
+        }
+
+        self.get().done = true;
+    }
+}
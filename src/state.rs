@@ -1,19 +1,142 @@
 use std::{
-    sync::{Arc, Mutex, MutexGuard},
+    sync::{
+        atomic::{fence, AtomicU32, AtomicU8, AtomicUsize, Ordering},
+        Arc, Condvar, Mutex, MutexGuard,
+    },
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-use crate::app::App;
+use crate::{
+    adversary::Adversary,
+    algorithms::sorting_network::{Comparator, Network},
+    app::App,
+};
 
-#[derive(Clone)]
+#[derive(Clone, Copy)]
+#[repr(u8)]
 pub enum Status {
-    Running,
-    Completed,
-    Paused,
-    Interrupted,
-    Checking,
-    Failed,
+    Running = 0,
+    Completed = 1,
+    Paused = 2,
+    Interrupted = 3,
+    Checking = 4,
+    Failed = 5,
+    GaveUp = 6,
+}
+
+impl Status {
+    const ALL: [Status; 7] = [
+        Status::Running,
+        Status::Completed,
+        Status::Paused,
+        Status::Interrupted,
+        Status::Checking,
+        Status::Failed,
+        Status::GaveUp,
+    ];
+}
+
+impl TryFrom<u8> for Status {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Status::ALL
+            .into_iter()
+            .find(|&status| status as u8 == value)
+            .ok_or(value)
+    }
+}
+
+/// A highlighted range of indexes, e.g. the two runs being merged, or every
+/// `stride`-th index of it, e.g. the subsequence a Shell sort pass is sorting.
+#[derive(Clone)]
+pub struct Band {
+    pub start: u32,
+    pub end: u32,
+    pub stride: u32,
+    pub label: String,
+}
+
+impl Band {
+    pub fn new(start: usize, end: usize, label: &str) -> Self {
+        Self::strided(start, end, 1, label)
+    }
+
+    pub fn strided(start: usize, end: usize, stride: usize, label: &str) -> Self {
+        Band {
+            start: u32::try_from(start).unwrap(),
+            end: u32::try_from(end).unwrap(),
+            stride: u32::try_from(stride.max(1)).unwrap(),
+            label: label.to_string(),
+        }
+    }
+
+    pub fn contains(&self, index: u32) -> bool {
+        (self.start..=self.end).contains(&index) && (index - self.start).is_multiple_of(self.stride)
+    }
+}
+
+/// Limits for algorithms that may never finish. Once either the operations
+/// (comparisons and writes) or the running time, not counting pauses, run
+/// out, the run ends with [`Status::GaveUp`].
+pub struct Budget {
+    operations: u32,
+    time: Duration,
+    elapsed: Duration,
+    resumed: Instant,
+}
+
+impl Default for Budget {
+    /// Enough for the slow sorts to finish on small inputs, but not on the TUI's.
+    fn default() -> Self {
+        Self::new(5_000_000, Duration::from_secs(60))
+    }
+}
+
+impl Budget {
+    pub fn new(operations: u32, time: Duration) -> Self {
+        Self {
+            operations,
+            time,
+            elapsed: Duration::ZERO,
+            resumed: Instant::now(),
+        }
+    }
+
+    /// Like [`SharedState::interrupted`], but also gives up once the budget is spent.
+    pub fn interrupted(&mut self, state: &SharedState) -> bool {
+        self.elapsed += self.resumed.elapsed();
+
+        if state.interrupted() {
+            return true;
+        }
+
+        self.resumed = Instant::now();
+
+        let operations = state.get_comparisons().saturating_add(state.get_writes());
+
+        if operations > self.operations || self.elapsed > self.time {
+            state.log(format!(
+                "Gave up after {} operations in {:.1}s",
+                operations,
+                self.elapsed.as_secs_f64()
+            ));
+            state.set_status(Status::GaveUp);
+
+            return true;
+        }
+
+        false
+    }
+}
+
+/// Counters of one thread of a parallel algorithm.
+#[derive(Clone, Default)]
+pub struct Worker {
+    pub comparison: Option<[u32; 2]>,
+    pub comparisons: u32,
+    pub writes: u32,
 }
 
 #[derive(Clone)]
@@ -21,13 +144,25 @@ pub struct State {
     pub array: Vec<u32>,
     pub last_swapped: u32,
     pub comparison: [u32; 2],
+    pub comparators: Vec<Comparator>,
+    pub network: Option<Arc<Network>>,
+    pub depth: u32,
     pub checked: Vec<u32>,
     pub comparisons: u32,
     pub array_accesses: u32,
+    pub writes: u32,
+    pub probes: u32,
+    /// States a backtracking search has tried.
+    pub nodes: u32,
+    pub bands: Vec<Band>,
+    pub workers: Vec<Worker>,
     pub status: Status,
     pub algorithm: String,
     pub log: Option<String>,
     pub speed: u32,
+    pub headless: bool,
+    /// Decides every comparison instead of the values, see [`Adversary`].
+    pub adversary: Option<Arc<Adversary>>,
 }
 
 impl State {
@@ -36,91 +171,315 @@ impl State {
             array,
             array_accesses: 0,
             comparisons: 0,
+            writes: 0,
+            probes: 0,
+            nodes: 0,
+            bands: vec![],
+            workers: vec![],
             last_swapped: 999,
             checked: vec![],
             comparison: [999; 2],
+            comparators: vec![],
+            network: None,
+            depth: 0,
             status: Status::Paused,
             algorithm: String::from("None"),
             log: None,
             speed: 100,
+            headless: false,
+            adversary: None,
         }
     }
 }
 
+/// Per-thread counters of a parallel algorithm, written without locking.
+#[derive(Default)]
+struct AtomicWorker {
+    comparison: [AtomicU32; 2],
+    comparisons: AtomicU32,
+    writes: AtomicU32,
+}
+
+/// Marks a worker that is not comparing anything.
+const NO_INDEX: u32 = u32::MAX;
+
+/// Upper bound on the threads a parallel algorithm may report counters for.
+pub const MAX_WORKERS: usize = 8;
+
+/// State that changes at most once per step rather than on every access.
+struct Details {
+    checked: Vec<u32>,
+    comparators: Vec<Comparator>,
+    network: Option<Arc<Network>>,
+    bands: Vec<Band>,
+    algorithm: String,
+    log: Option<String>,
+}
+
+/// Everything touched on each array operation lives in atomics, so neither the
+/// workers of an algorithm nor the renderer ever wait on each other for it.
+/// The rest sits behind a mutex that is only held to copy it in or out.
+struct Inner {
+    array: Box<[AtomicU32]>,
+    /// Changes to the array made of several stores that are under way, and
+    /// how many have finished, so a copy can tell if one overlapped it.
+    writing: AtomicU32,
+    version: AtomicU32,
+    last_swapped: AtomicU32,
+    comparison: [AtomicU32; 2],
+    depth: AtomicU32,
+    comparisons: AtomicU32,
+    array_accesses: AtomicU32,
+    writes: AtomicU32,
+    probes: AtomicU32,
+    nodes: AtomicU32,
+    speed: AtomicU32,
+    status: AtomicU8,
+    headless: bool,
+    adversary: Option<Arc<Adversary>>,
+    workers: [AtomicWorker; MAX_WORKERS],
+    worker_count: AtomicUsize,
+    details: Mutex<Details>,
+    pause: Mutex<()>,
+    resumed: Condvar,
+}
+
 #[derive(Clone)]
-pub struct SharedState(Arc<Mutex<State>>);
+pub struct SharedState(Arc<Inner>);
 
 impl SharedState {
     pub fn new(state: State) -> Self {
-        Self(Arc::new(Mutex::new(state)))
+        let worker_count = state.workers.len().min(MAX_WORKERS);
+
+        Self(Arc::new(Inner {
+            array: state.array.into_iter().map(AtomicU32::new).collect(),
+            writing: AtomicU32::new(0),
+            version: AtomicU32::new(0),
+            last_swapped: AtomicU32::new(state.last_swapped),
+            comparison: state.comparison.map(AtomicU32::new),
+            depth: AtomicU32::new(state.depth),
+            comparisons: AtomicU32::new(state.comparisons),
+            array_accesses: AtomicU32::new(state.array_accesses),
+            writes: AtomicU32::new(state.writes),
+            probes: AtomicU32::new(state.probes),
+            nodes: AtomicU32::new(state.nodes),
+            speed: AtomicU32::new(state.speed),
+            status: AtomicU8::new(state.status as u8),
+            headless: state.headless,
+            adversary: state.adversary,
+            workers: Default::default(),
+            worker_count: AtomicUsize::new(worker_count),
+            details: Mutex::new(Details {
+                checked: state.checked,
+                comparators: state.comparators,
+                network: state.network,
+                bands: state.bands,
+                algorithm: state.algorithm,
+                log: state.log,
+            }),
+            pause: Mutex::new(()),
+            resumed: Condvar::new(),
+        }))
+    }
+
+    fn details(&self) -> MutexGuard<'_, Details> {
+        self.0.details.lock().unwrap()
+    }
+
+    /// Copies the current state for the renderer without blocking the algorithm.
+    pub fn snapshot(&self) -> State {
+        let array = self.get_all();
+        let details = self.details();
+
+        State {
+            array,
+            last_swapped: self.get_last(),
+            comparison: self.get_comparison(),
+            comparators: details.comparators.clone(),
+            network: details.network.clone(),
+            depth: self.get_depth(),
+            checked: details.checked.clone(),
+            comparisons: self.get_comparisons(),
+            array_accesses: self.get_accesses(),
+            writes: self.get_writes(),
+            probes: self.get_probes(),
+            nodes: self.get_nodes(),
+            bands: details.bands.clone(),
+            workers: self.get_workers(),
+            status: self.get_status(),
+            algorithm: details.algorithm.clone(),
+            log: details.log.clone(),
+            speed: self.get_speed(),
+            headless: self.0.headless,
+            adversary: self.0.adversary.clone(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.array.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.array.is_empty()
+    }
+
+    pub fn load(&self, index: usize) -> u32 {
+        self.0.array[index].load(Ordering::Relaxed)
+    }
+
+    pub fn store(&self, index: usize, value: u32) {
+        self.0.array[index].store(value, Ordering::Relaxed);
+    }
+
+    pub fn get_adversary(&self) -> Option<&Adversary> {
+        self.0.adversary.as_deref()
+    }
+
+    /// Makes the stores in `write` one change, which [`SharedState::get_all`]
+    /// sees either not at all or in full.
+    pub fn write(&self, write: impl FnOnce()) {
+        self.0.writing.fetch_add(1, Ordering::SeqCst);
+        // Anyone who sees one of the stores also sees that a write is under way.
+        fence(Ordering::Release);
+        write();
+        self.0.version.fetch_add(1, Ordering::SeqCst);
+        self.0.writing.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Copies the array, retrying until no change made with
+    /// [`SharedState::write`] overlapped the copy.
+    pub fn get_all(&self) -> Vec<u32> {
+        loop {
+            let version = self.0.version.load(Ordering::SeqCst);
+
+            if self.0.writing.load(Ordering::SeqCst) == 0 {
+                let array = self
+                    .0
+                    .array
+                    .iter()
+                    .map(|value| value.load(Ordering::SeqCst))
+                    .collect();
+
+                fence(Ordering::Acquire);
+
+                if self.0.writing.load(Ordering::SeqCst) == 0
+                    && self.0.version.load(Ordering::SeqCst) == version
+                {
+                    return array;
+                }
+            }
+
+            thread::yield_now();
+        }
     }
 
     pub fn reset_array(&self) {
-        let array = App::generate_array();
-        self.get().array = array;
+        for (i, value) in App::generate_array()
+            .into_iter()
+            .enumerate()
+            .take(self.len())
+        {
+            self.store(i, value);
+        }
     }
 
     pub fn sleep(&self, ms: Option<u64>) {
+        if self.0.headless {
+            return;
+        }
+
         thread::sleep(Duration::from_millis(
             ms.unwrap_or((101 - self.get_speed()).into()),
         ));
     }
 
+    /// Blocks the calling thread, which may be any worker of the algorithm,
+    /// until the status is no longer [`Status::Paused`].
     pub fn park(&self) {
-        thread::park();
+        let _pause = self
+            .0
+            .resumed
+            .wait_while(self.0.pause.lock().unwrap(), |_| {
+                matches!(self.get_status(), Status::Paused)
+            })
+            .unwrap();
     }
 
-    pub fn get(&self) -> MutexGuard<'_, State> {
-        self.0.lock().unwrap()
+    /// Parks the calling thread while paused and reports whether it has
+    /// been interrupted or has given up, in which case the algorithm should return.
+    pub fn interrupted(&self) -> bool {
+        if let Status::Paused = self.get_status() {
+            self.park();
+        }
+
+        matches!(self.get_status(), Status::Interrupted | Status::GaveUp)
     }
 
     pub fn get_speed(&self) -> u32 {
-        self.get().speed
+        self.0.speed.load(Ordering::Relaxed)
     }
 
     pub fn increment_speed(&self) -> u32 {
-        let mut state = self.get();
-        if state.speed < 100 {
-            state.speed += 5;
+        let speed = self.get_speed();
+
+        if speed < 100 {
+            self.0.speed.store(speed + 5, Ordering::Relaxed);
         }
-        state.speed
+
+        self.get_speed()
     }
 
     pub fn set_speed(&self, value: u32) -> u32 {
-        self.get().speed = value;
+        self.0.speed.store(value, Ordering::Relaxed);
         self.get_speed()
     }
 
     pub fn decrement_speed(&self) -> u32 {
-        let mut state = self.get();
-        if state.speed > 5 {
-            state.speed -= 5;
+        let speed = self.get_speed();
+
+        if speed > 5 {
+            self.0.speed.store(speed - 5, Ordering::Relaxed);
         }
-        state.speed
+
+        self.get_speed()
     }
 
     pub fn get_last(&self) -> u32 {
-        self.get().last_swapped
+        self.0.last_swapped.load(Ordering::Relaxed)
     }
 
     pub fn set_last(&self, index: u32) -> u32 {
-        self.get().last_swapped = index;
+        self.0.last_swapped.store(index, Ordering::Relaxed);
         index
     }
 
     pub fn check(&self) {
+        if let Status::Interrupted | Status::GaveUp = self.get_status() {
+            return;
+        }
+
         self.set_status(Status::Checking);
+        self.set_bands(vec![]);
         self.set_last(0);
 
-        let array = self.get().array.clone();
+        let array = self.get_all();
         let len = array.len();
 
+        // Nothing to compare, and nothing out of order.
+        if len < 2 {
+            (0..len).for_each(|i| {
+                self.set_checked(u32::try_from(i).unwrap());
+            });
+            self.set_status(Status::Completed);
+            return;
+        }
+
         for i in 0..len - 1 {
             if i + 1 == len - 1 {
                 self.set_checked(u32::try_from(i + 1).unwrap());
             }
 
-            if array[i] < array[i + 1] {
+            if array[i] <= array[i + 1] {
                 self.set_checked(u32::try_from(i).unwrap());
                 self.set_comparison([u32::try_from(i).unwrap(), u32::try_from(i + 1).unwrap()]);
                 self.sleep(None);
@@ -134,75 +493,297 @@ impl SharedState {
         }
     }
 
+    /// Checks that the value at `k` is the k-th smallest, with nothing larger
+    /// before it and nothing smaller after it, instead of full sortedness.
+    pub fn check_selected(&self, k: usize) {
+        if let Status::Interrupted | Status::GaveUp = self.get_status() {
+            return;
+        }
+
+        self.set_status(Status::Checking);
+        self.set_bands(vec![]);
+        self.set_last(u32::try_from(k).unwrap());
+
+        let array = self.get_all();
+        let selected = array[k];
+
+        for (i, &value) in array.iter().enumerate() {
+            if (i >= k || value <= selected) && (i <= k || value >= selected) {
+                self.set_checked(u32::try_from(i).unwrap());
+                self.set_comparison([u32::try_from(i).unwrap(), u32::try_from(k).unwrap()]);
+                self.sleep(None);
+            }
+        }
+
+        if array.len() == self.get_checked().len() {
+            self.set_status(Status::Completed);
+        } else {
+            self.set_status(Status::Failed);
+        }
+    }
+
+    /// Checks that the `k` smallest values lead the array in order, with
+    /// nothing after them smaller than the last of them.
+    pub fn check_partial(&self, k: usize) {
+        if let Status::Interrupted | Status::GaveUp = self.get_status() {
+            return;
+        }
+
+        self.set_status(Status::Checking);
+        self.set_bands(vec![]);
+        self.set_last(0);
+
+        let array = self.get_all();
+        let largest = array[k - 1];
+
+        for (i, &value) in array.iter().enumerate() {
+            let in_place = match i {
+                0 => true,
+                i if i < k => array[i - 1] <= value,
+                _ => value >= largest,
+            };
+
+            if in_place {
+                self.set_checked(u32::try_from(i).unwrap());
+                self.set_comparison([
+                    u32::try_from(i.saturating_sub(1).min(k - 1)).unwrap(),
+                    u32::try_from(i).unwrap(),
+                ]);
+                self.sleep(None);
+            }
+        }
+
+        if array.len() == self.get_checked().len() {
+            self.set_status(Status::Completed);
+        } else {
+            self.set_status(Status::Failed);
+        }
+    }
+
     pub fn get_checked(&self) -> Vec<u32> {
-        self.get().checked.clone()
+        self.details().checked.clone()
     }
 
     pub fn set_checked(&self, index: u32) -> u32 {
-        self.get().checked.push(index);
+        self.details().checked.push(index);
         index
     }
 
+    pub fn clear_checked(&self) {
+        self.details().checked.clear();
+    }
+
     pub fn get_comparison(&self) -> [u32; 2] {
-        self.get().comparison
+        self.0
+            .comparison
+            .each_ref()
+            .map(|index| index.load(Ordering::Relaxed))
     }
 
     pub fn set_comparison(&self, indexes: [u32; 2]) -> [u32; 2] {
-        let mut state = self.get();
-        state.comparison = indexes;
+        for (index, value) in self.0.comparison.iter().zip(indexes) {
+            index.store(value, Ordering::Relaxed);
+        }
+
         indexes
     }
 
+    pub fn get_comparators(&self) -> Vec<Comparator> {
+        self.details().comparators.clone()
+    }
+
+    pub fn set_comparators(&self, comparators: Vec<Comparator>) {
+        self.details().comparators = comparators;
+    }
+
+    pub fn set_network(&self, network: Option<Arc<Network>>) {
+        self.details().network = network;
+    }
+
+    pub fn get_depth(&self) -> u32 {
+        self.0.depth.load(Ordering::Relaxed)
+    }
+
+    pub fn set_depth(&self, depth: u32) -> u32 {
+        self.0.depth.store(depth, Ordering::Relaxed);
+        depth
+    }
+
     pub fn get_accesses(&self) -> u32 {
-        self.get().array_accesses
+        self.0.array_accesses.load(Ordering::Relaxed)
     }
 
     pub fn increment_accesses(&self, value: u32) -> u32 {
-        let mut state = self.get();
-        state.array_accesses += value;
-        state.array_accesses + value
+        self.0.array_accesses.fetch_add(value, Ordering::Relaxed) + value
+    }
+
+    pub fn get_writes(&self) -> u32 {
+        self.0.writes.load(Ordering::Relaxed)
+    }
+
+    pub fn increment_writes(&self, value: u32) -> u32 {
+        self.0.writes.fetch_add(value, Ordering::Relaxed) + value
+    }
+
+    /// Starts the counters over, e.g. once the input has been shuffled.
+    pub fn reset_counters(&self) {
+        for counter in [
+            &self.0.comparisons,
+            &self.0.array_accesses,
+            &self.0.writes,
+            &self.0.probes,
+            &self.0.nodes,
+            &self.0.depth,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+
+        self.clear_checked();
+    }
+
+    pub fn get_probes(&self) -> u32 {
+        self.0.probes.load(Ordering::Relaxed)
+    }
+
+    pub fn increment_probes(&self) -> u32 {
+        self.0.probes.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn get_nodes(&self) -> u32 {
+        self.0.nodes.load(Ordering::Relaxed)
+    }
+
+    pub fn increment_nodes(&self) -> u32 {
+        self.0.nodes.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn get_bands(&self) -> Vec<Band> {
+        self.details().bands.clone()
+    }
+
+    pub fn set_bands(&self, bands: Vec<Band>) {
+        self.details().bands = bands;
     }
 
     pub fn get_comparisons(&self) -> u32 {
-        self.get().comparisons
+        self.0.comparisons.load(Ordering::Relaxed)
     }
 
     pub fn increment_comparisons(&self) -> u32 {
-        let mut state = self.get();
-        state.comparisons += 1;
-        state.comparisons
+        self.0.comparisons.fetch_add(1, Ordering::Relaxed) + 1
     }
 
     pub fn get_status(&self) -> Status {
-        self.get().status.clone()
+        Status::try_from(self.0.status.load(Ordering::Acquire))
+            .expect("only set_status stores a status")
     }
 
+    /// Publishes `status` and wakes every parked thread so it can re-check it.
     pub fn set_status(&self, status: Status) -> Status {
-        let mut state = self.get();
-        state.status = status.clone();
+        self.0.status.store(status as u8, Ordering::Release);
+
+        // Taking the lock orders this store before any thread that is about to wait.
+        drop(self.0.pause.lock().unwrap());
+        self.0.resumed.notify_all();
+
         status
     }
 
+    pub fn get_workers(&self) -> Vec<Worker> {
+        self.0.workers[..self.0.worker_count.load(Ordering::Relaxed)]
+            .iter()
+            .map(|worker| {
+                let comparison = worker
+                    .comparison
+                    .each_ref()
+                    .map(|index| index.load(Ordering::Relaxed));
+
+                Worker {
+                    comparison: (comparison != [NO_INDEX; 2]).then_some(comparison),
+                    comparisons: worker.comparisons.load(Ordering::Relaxed),
+                    writes: worker.writes.load(Ordering::Relaxed),
+                }
+            })
+            .collect()
+    }
+
+    pub fn set_workers(&self, count: usize) {
+        let count = count.min(MAX_WORKERS);
+
+        for worker in &self.0.workers[..count] {
+            for index in &worker.comparison {
+                index.store(NO_INDEX, Ordering::Relaxed);
+            }
+
+            worker.comparisons.store(0, Ordering::Relaxed);
+            worker.writes.store(0, Ordering::Relaxed);
+        }
+
+        self.0.worker_count.store(count, Ordering::Relaxed);
+    }
+
+    fn worker(&self, worker: usize) -> Option<&AtomicWorker> {
+        self.0.workers[..self.0.worker_count.load(Ordering::Relaxed)].get(worker)
+    }
+
+    pub fn worker_compared(&self, worker: usize, indexes: Option<[u32; 2]>) {
+        if let Some(worker) = self.worker(worker) {
+            if let Some(indexes) = indexes {
+                for (index, value) in worker.comparison.iter().zip(indexes) {
+                    index.store(value, Ordering::Relaxed);
+                }
+            }
+
+            worker.comparisons.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn worker_wrote(&self, worker: usize, value: u32) {
+        if let Some(worker) = self.worker(worker) {
+            worker.writes.fetch_add(value, Ordering::Relaxed);
+        }
+    }
+
+    pub fn worker_finished(&self, worker: usize) {
+        if let Some(worker) = self.worker(worker) {
+            for index in &worker.comparison {
+                index.store(NO_INDEX, Ordering::Relaxed);
+            }
+        }
+    }
+
     pub fn get_algorithm(&self) -> String {
-        self.get().algorithm.clone()
+        self.details().algorithm.clone()
     }
 
     pub fn log(&self, text: String) -> String {
-        self.get().log = Some(text.clone());
+        self.details().log = Some(text.clone());
         text
     }
 
     pub fn get_log(&self) -> Option<String> {
-        self.get().log.clone()
+        self.details().log.clone()
     }
 
     pub fn set_algorithm(&self, name: String) -> String {
-        self.get().algorithm = name.clone();
+        self.details().algorithm = name.clone();
         name
     }
 
+    /// Names the run and waits for it to be resumed, unless it is already
+    /// running, as a sort taking over from its shuffle is.
     pub fn init_algorithm(&self, name: String) {
         self.set_algorithm(name);
+
+        if self.0.headless {
+            self.set_status(Status::Running);
+            return;
+        }
+
+        if let Status::Running = self.get_status() {
+            return;
+        }
+
         self.set_status(Status::Paused);
         self.park();
     }